@@ -0,0 +1,47 @@
+use std::{path::PathBuf, sync::Arc};
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct NotReproducible {
+    pbo: PathBuf,
+    entries: Vec<String>,
+}
+
+impl Code for NotReproducible {
+    fn ident(&self) -> &'static str {
+        "BCBE1"
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` is not reproducible, it differs between builds",
+            self.pbo.display()
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        if self.entries.is_empty() {
+            None
+        } else {
+            Some(format!("differing entries: {}", self.entries.join(", ")))
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "Compare them with `hemtt utils pbo diff .hemttout/build/{0} .hemttout/build-reproducible/{0}`",
+            self.pbo.display()
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl NotReproducible {
+    #[must_use]
+    pub fn code(pbo: PathBuf, entries: Vec<String>) -> Arc<dyn Code> {
+        Arc::new(Self { pbo, entries })
+    }
+}
@@ -0,0 +1 @@
+pub mod bcbe1_not_reproducible;
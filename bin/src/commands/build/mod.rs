@@ -0,0 +1,258 @@
+use std::{
+    io::Cursor,
+    path::{Path, PathBuf},
+};
+
+use hemtt_pbo::ReadablePbo;
+
+use crate::{
+    context::{self, Context},
+    error::Error,
+    executor::Executor,
+    modules::{
+        Binarize, Files, Rapifier, Textures, meta::Meta, pbo::Collapse, summary::Summary,
+        tex_headers::TexHeaders,
+    },
+    report::Report,
+    utils::pbo::diff::diff,
+};
+
+use self::error::bcbe1_not_reproducible::NotReproducible;
+
+use super::global_modules;
+
+pub mod error;
+
+#[derive(clap::Parser)]
+#[command(verbatim_doc_comment)]
+/// Build the project for final testing
+///
+/// `hemtt build` will build your mod into `.hemttout/build`.
+/// It will binarize all applicable files, and will not
+/// create folder links like [`hemtt dev`](./dev.md).
+///
+/// It is intended to be used for testing your mod locally before release.
+///
+/// ## Binarization
+///
+/// By default, supported file types are converted to
+/// their binary formats for optimal game performance. This process is slower but
+/// produces smaller, faster-loading files similar to BI's official addons.
+///
+/// ## Configuration
+///
+/// ```toml,fp=.hemtt/project.toml
+/// [hemtt.build]
+/// optional_mod_folders = false # Default: true
+/// ```
+///
+/// ### `optional_mod_folders`
+///
+/// By default, `hemtt build` will create separate mods for each optional mod folder.
+///
+/// ## Reproducible Builds
+///
+/// `hemtt build --reproducible-check` will build the project twice, into
+/// `.hemttout/build` and `.hemttout/build-reproducible`, and report any PBO
+/// that is not byte-identical between the two builds.
+pub struct Command {
+    #[clap(flatten)]
+    build: BuildArgs,
+
+    #[arg(long, action = clap::ArgAction::SetTrue, conflicts_with = "just")]
+    /// Build twice and check that every PBO is byte-identical
+    ///
+    /// The second build is kept in `.hemttout/build-reproducible` for inspection.
+    reproducible_check: bool,
+
+    #[clap(flatten)]
+    just: super::JustArgs,
+
+    #[clap(flatten)]
+    global: crate::GlobalArgs,
+}
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct BuildArgs {
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Do not binarize the project
+    ///
+    /// Files will be copied directly into the PBO without binarization. `config.cpp`, `*.rvmat`, `*.ext`, `*.sqm`,
+    /// `*.bikb`, `*.bisurf` will still be rapified.
+    /// This can be configured per addon in [`addon.toml`](../configuration/addon#binarize).
+    ///
+    /// Useful for faster builds during testing when you don't need optimized file formats.
+    no_bin: bool,
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Do not rapify (cpp, rvmat, ext, sqm, bikb, bisurf)
+    ///
+    /// They will be copied directly into the PBO.
+    /// This can be configured per addon in [`addon.toml`](../configuration/addon#rapify).
+    no_rap: bool,
+}
+
+/// Execute the build command, build a new executor
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
+    let (report, first) = build(cmd, "build")?;
+    if !cmd.reproducible_check || report.failed() {
+        return Ok(report);
+    }
+    info!("Building again to check the build is reproducible");
+    let (mut report, second) = build(cmd, "build-reproducible")?;
+    if report.failed() {
+        return Ok(report);
+    }
+    report.merge(compare(&first, &second)?);
+    Ok(report)
+}
+
+fn build(cmd: &Command, folder: &str) -> Result<(Report, PathBuf), Error> {
+    let just = cmd
+        .just
+        .just
+        .iter()
+        .map(|s| s.to_lowercase())
+        .collect::<Vec<_>>();
+    let mut ctx = Context::new(
+        Some(folder),
+        if just.is_empty() {
+            context::PreservePrevious::Remove
+        } else {
+            warn!("keeping previous build artifacts");
+            context::PreservePrevious::Keep
+        },
+        true,
+    )?;
+    if !just.is_empty() {
+        ctx = ctx.filter(|a, _| just.contains(&a.name().to_lowercase()));
+        let runtime = ctx.config().runtime().clone().with_just(true);
+        let config = ctx.config().clone().with_runtime(runtime);
+        ctx = ctx.with_config(config);
+    }
+    let build_folder = ctx.build_folder().expect("build folder exists").clone();
+    let mut executor = executor(ctx, &cmd.build);
+
+    if !just.is_empty() {
+        warn!("Use of `--just` is not recommended, only use it if you know what you're doing");
+    }
+
+    info!("Creating `build` version");
+
+    Ok((executor.run()?, build_folder))
+}
+
+/// Compare the PBOs of two builds
+fn compare(first: &Path, second: &Path) -> Result<Report, Error> {
+    let mut report = Report::new();
+    let mut checked = 0;
+    for entry in walkdir::WalkDir::new(first).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file()
+            || !entry
+                .path()
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("pbo"))
+        {
+            continue;
+        }
+        checked += 1;
+        let relative = entry
+            .path()
+            .strip_prefix(first)
+            .expect("walked from first")
+            .to_path_buf();
+        let first_data = fs_err::read(entry.path())?;
+        let Ok(second_data) = fs_err::read(second.join(&relative)) else {
+            report.push(NotReproducible::code(relative, vec![]));
+            continue;
+        };
+        if first_data == second_data {
+            continue;
+        }
+        let entries = match (
+            ReadablePbo::from(Cursor::new(first_data)),
+            ReadablePbo::from(Cursor::new(second_data)),
+        ) {
+            (Ok(mut first), Ok(mut second)) => diff(&mut first, &mut second)?.entries(),
+            _ => vec![],
+        };
+        report.push(NotReproducible::code(relative, entries));
+    }
+    info!("Compared {} PBOs", checked);
+    Ok(report)
+}
+
+#[must_use]
+pub fn executor(ctx: Context, args: &BuildArgs) -> Executor {
+    let mut executor = Executor::new(ctx);
+    global_modules(&mut executor);
+
+    executor.collapse(Collapse::No);
+
+    executor.add_module(Box::<Textures>::default());
+    if !args.no_rap {
+        executor.add_module(Box::<Rapifier>::default());
+    }
+    if !args.no_bin {
+        executor.add_module(Box::<Binarize>::default());
+    }
+    executor.add_module(Box::<TexHeaders>::default());
+    executor.add_module(Box::<Files>::default());
+    executor.add_module(Box::<Summary>::default());
+    executor.add_module(Box::<Meta>::default());
+
+    executor.init();
+    executor.check();
+    executor.build(true);
+
+    executor
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{io::Cursor, path::Path};
+
+    use hemtt_pbo::WritablePbo;
+
+    fn write(folder: &Path, script: &str) {
+        let addons = folder.join("addons");
+        fs_err::create_dir_all(&addons).unwrap();
+        for name in ["main", "other"] {
+            let mut pbo = WritablePbo::new();
+            pbo.add_property("prefix", format!("z\\test\\addons\\{name}"));
+            let data = if name == "main" {
+                script
+            } else {
+                "hint 'same';"
+            };
+            pbo.add_file("script.sqf", Cursor::new(data.as_bytes().to_vec()))
+                .unwrap();
+            pbo.write(
+                &mut fs_err::File::create(addons.join(format!("test_{name}.pbo"))).unwrap(),
+                true,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let folder = std::env::temp_dir().join("hemtt_build_compare");
+        write(&folder.join("first"), "hint 'a';");
+        write(&folder.join("second"), "hint 'b';");
+
+        let report = super::compare(&folder.join("first"), &folder.join("second")).unwrap();
+        let errors = report.errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message().contains("test_main.pbo"));
+        assert_eq!(errors[0].note().unwrap(), "differing entries: script.sqf");
+
+        fs_err::remove_dir_all(folder).unwrap();
+    }
+}
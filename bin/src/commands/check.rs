@@ -3,7 +3,7 @@ use crate::{
     context::Context,
     error::Error,
     executor::Executor,
-    modules::{Binarize, Rapifier, pbo::Collapse},
+    modules::{Assets, Binarize, Rapifier, pbo::Collapse},
     report::Report,
 };
 
@@ -76,6 +76,7 @@ pub fn execute(cmd: &Command) -> Result<Report, Error> {
 
     executor.add_module(Box::<Rapifier>::default());
     executor.add_module(Box::<Binarize>::new(Binarize::new(true)));
+    executor.add_module(Box::<Assets>::default());
 
     info!("Running checks");
 
@@ -6,7 +6,7 @@ use crate::{
     error::Error,
     executor::Executor,
     modules::{
-        Binarize, FilePatching, Files, Rapifier, pbo::Collapse, summary::Summary,
+        Binarize, FilePatching, Files, Rapifier, Textures, pbo::Collapse, summary::Summary,
         tex_headers::TexHeaders,
     },
     report::Report,
@@ -178,6 +178,7 @@ pub fn context(
 
     executor.collapse(Collapse::Yes);
 
+    executor.add_module(Box::<Textures>::new(Textures::new(true)));
     if rapify && !dev.no_rap {
         executor.add_module(Box::<Rapifier>::default());
     }
@@ -1,4 +1,7 @@
-use std::io::{Read, Write};
+use std::{
+    io::{Read, Write},
+    path::PathBuf,
+};
 
 use hemtt_signing::{BIPrivateKey, HEMTTPrivateKey, KDFParams};
 use rand::{Rng as _, distr::Alphanumeric};
@@ -26,6 +29,40 @@ pub struct Command {
 pub enum Subcommands {
     /// Generate a new HEMTT private key
     Generate,
+    /// Replace the current HEMTT private key with a new one
+    ///
+    /// The current key is kept in `signing.keys`, so PBOs are signed
+    /// with both keys while servers move to the new key.
+    Rotate {
+        /// Authority for the new key, must differ from the current authority
+        authority: String,
+    },
+    /// Import an existing `.biprivatekey` as a HEMTT private key
+    Import {
+        /// The `.biprivatekey` to import
+        key: String,
+    },
+    /// Export the public `.bikey` of a HEMTT private key
+    ExportPublic {
+        /// The `.hemttprivatekey` to export from
+        key: String,
+        /// Where to write the `.bikey`, defaults to `<authority>.bikey`
+        output: Option<String>,
+    },
+    /// Show information about a HEMTT private key
+    ///
+    /// The private key itself is never shown.
+    Info {
+        /// The `.hemttprivatekey` to inspect
+        key: String,
+    },
+    /// Change the password of a HEMTT private key
+    ///
+    /// The key is re-encrypted with the current KDF parameters.
+    ChangePassword {
+        /// The `.hemttprivatekey` to change the password of
+        key: String,
+    },
 }
 
 fn default_mem_cost_mib() -> u32 {
@@ -50,89 +87,249 @@ fn default_parallelism() -> u32 {
 pub fn execute(cmd: &Command) -> Result<Report, Error> {
     match &cmd.commands {
         Subcommands::Generate => {
-            let ctx = Context::new(None, crate::context::PreservePrevious::Keep, true)?;
-            let authority = ctx.config().signing().authority().map_or_else(
-                || ctx.config().prefix().to_string(),
-                std::string::ToString::to_string,
+            let (ctx, current) = context()?;
+            let Some(key) = create(cmd, &ctx, &current)? else {
+                return Ok(Report::new());
+            };
+            println!();
+            println!("Add the following to your .hemtt/project.toml to use the key:");
+            println!();
+            println!("[signing]");
+            println!(
+                "private_key_hash = \"{}\"",
+                key.validation_hash().expect("valid hash")
             );
-
-            let output = format!("{authority}.hemttprivatekey");
-            if std::path::Path::new(&output).exists() {
-                error!("Output file {output} already exists. Aborting to prevent overwrite.");
+        }
+        Subcommands::Rotate { authority } => {
+            let (ctx, current) = context()?;
+            let Some(previous_hash) = ctx.config().signing().private_key_hash() else {
+                error!("No private key is configured, use `hemtt keys generate` instead");
                 std::process::exit(1);
-            }
-
-            warn!("Generating HEMTT private keys is for specific use cases only.");
-            warn!("In nearly all cases, you should not use this command.");
-            dialoguer::Confirm::new()
-                .with_prompt("I fully understand the risks of using private keys")
-                .default(true)
-                .interact()?;
-
-            let git_hash = get_git_first_hash()?;
-            println!("Project:   {}", ctx.config().name());
-            println!("Prefix:    {}", ctx.config().prefix());
-            println!("Git:       {git_hash}");
-            println!("Authority: {authority}");
-            println!();
-            warn!("The generated key will be usable ONLY with this project");
-            if !dialoguer::Confirm::new()
-                .with_prompt("Confirm")
-                .default(false)
-                .interact()?
+            };
+            if authority == &current
+                || ctx
+                    .config()
+                    .signing()
+                    .keys()
+                    .iter()
+                    .any(|k| k.authority() == authority)
             {
+                error!("Authority `{authority}` is already in use");
+                std::process::exit(1);
+            }
+            let Some(key) = create(cmd, &ctx, authority)? else {
                 return Ok(Report::new());
+            };
+            println!();
+            println!("Update the [signing] section of your .hemtt/project.toml to:");
+            println!();
+            println!("[signing]");
+            println!("authority = \"{authority}\"");
+            println!(
+                "private_key_hash = \"{}\"",
+                key.validation_hash().expect("valid hash")
+            );
+            for previous in std::iter::once((current.as_str(), previous_hash)).chain(
+                ctx.config()
+                    .signing()
+                    .keys()
+                    .iter()
+                    .map(|k| (k.authority(), k.private_key_hash())),
+            ) {
+                println!();
+                println!("[[signing.keys]]");
+                println!("authority = \"{}\"", previous.0);
+                println!("private_key_hash = \"{}\"", previous.1);
             }
-
-            let hemtt_private_key = HEMTTPrivateKey {
-                bi: BIPrivateKey::generate(1024, &authority)?,
+            println!();
+            println!(
+                "Remove `{current}` from `signing.keys` once servers no longer need the old key"
+            );
+        }
+        Subcommands::Import { key } => {
+            let (ctx, _) = context()?;
+            let bi = BIPrivateKey::read(&mut fs_err::File::open(key)?)?;
+            let authority = bi.authority().to_string();
+            let Some(git_hash) = confirm(&ctx, &authority)? else {
+                return Ok(Report::new());
+            };
+            let key = HEMTTPrivateKey {
+                bi,
                 project: ctx.config().name().to_string(),
                 prefix: ctx.config().prefix().to_string(),
                 git_hash,
             };
-
-            let kdf_params = KDFParams {
-                mem_cost_kib: cmd.mem_cost_mib * 1024,
-                iterations: cmd.iterations,
-                parallelism: cmd.parallelism,
-            };
-            let password = generate_password();
-            let mut output = fs_err::File::create(output)?;
-            hemtt_private_key.write_encrypted(&mut output, &password, kdf_params)?;
-
-            // Add to .gitignore if not already present
-            let gitignore_path = std::path::Path::new(".gitignore");
-            let mut gitignore_contents = String::new();
-            if gitignore_path.exists() {
-                fs_err::File::open(gitignore_path)?.read_to_string(&mut gitignore_contents)?;
-            }
-            let gitignore_entry = "*.hemttprivatekey";
-            if !gitignore_contents
-                .lines()
-                .any(|line| line.trim() == gitignore_entry)
-            {
-                let mut gitignore_file = fs_err::OpenOptions::new()
-                    .append(true)
-                    .create(true)
-                    .open(gitignore_path)?;
-                gitignore_file.write_all(format!("\n{gitignore_entry}\n").as_bytes())?;
-                println!(".gitignore updated to exclude HEMTT private keys");
-            }
-
+            write(cmd, &key)?;
             println!();
             println!("Add the following to your .hemtt/project.toml to use the key:");
             println!();
             println!("[signing]");
+            println!("authority = \"{authority}\"");
             println!(
                 "private_key_hash = \"{}\"",
-                hemtt_private_key.validation_hash().expect("valid hash")
+                key.validation_hash().expect("valid hash")
+            );
+        }
+        Subcommands::ExportPublic { key, output } => {
+            let key = read(key)?;
+            let output = output.as_ref().map_or_else(
+                || PathBuf::from(format!("{}.bikey", key.bi.authority())),
+                PathBuf::from,
+            );
+            if output.exists() {
+                error!(
+                    "Output file {} already exists. Aborting to prevent overwrite.",
+                    output.display()
+                );
+                std::process::exit(1);
+            }
+            key.bi
+                .to_public_key()
+                .write(&mut fs_err::File::create(&output)?)?;
+            info!("Exported {}", output.display());
+        }
+        Subcommands::Info { key } => {
+            let kdf_params = HEMTTPrivateKey::read_kdf_params(&mut fs_err::File::open(key)?)?;
+            let key = read(key)?;
+            println!("Authority:       {}", key.bi.authority());
+            println!("Length:          {}", key.bi.length());
+            println!("Project:         {}", key.project);
+            println!("Prefix:          {}", key.prefix);
+            println!("Git:             {}", key.git_hash);
+            println!("Validation Hash: {}", key.validation_hash()?);
+            println!(
+                "KDF:             {} MiB, {} iterations, {} parallelism",
+                kdf_params.mem_cost_kib / 1024,
+                kdf_params.iterations,
+                kdf_params.parallelism
             );
+            if !kdf_params.is_secure() {
+                warn!(
+                    "The KDF parameters are below the recommended minimum, use `hemtt keys change-password` to upgrade them"
+                );
+            }
+        }
+        Subcommands::ChangePassword { key: path } => {
+            let key = read(path)?;
+            let password = generate_password();
+            let mut buffer = Vec::new();
+            key.write_encrypted(&mut buffer, &password, kdf_params(cmd))?;
+            fs_err::write(path, buffer)?;
+            info!("Password changed for {path}");
         }
     }
 
     Ok(Report::new())
 }
 
+/// Load the project context, and the authority currently used for signing
+fn context() -> Result<(Context, String), Error> {
+    let ctx = Context::new(None, crate::context::PreservePrevious::Keep, true)?;
+    let current = ctx.config().signing().authority().map_or_else(
+        || ctx.config().prefix().to_string(),
+        std::string::ToString::to_string,
+    );
+    Ok((ctx, current))
+}
+
+/// Read a HEMTT private key, prompting for its password
+fn read(path: &str) -> Result<HEMTTPrivateKey, Error> {
+    let password = dialoguer::Password::new()
+        .with_prompt(format!("Enter password to decrypt `{path}`"))
+        .interact()?;
+    Ok(HEMTTPrivateKey::read_encrypted(
+        &mut fs_err::File::open(path)?,
+        &password,
+    )?)
+}
+
+const fn kdf_params(cmd: &Command) -> KDFParams {
+    KDFParams {
+        mem_cost_kib: cmd.mem_cost_mib * 1024,
+        iterations: cmd.iterations,
+        parallelism: cmd.parallelism,
+    }
+}
+
+/// Create and write a new HEMTT private key
+///
+/// Returns `None` if the user does not confirm
+fn create(cmd: &Command, ctx: &Context, authority: &str) -> Result<Option<HEMTTPrivateKey>, Error> {
+    let Some(git_hash) = confirm(ctx, authority)? else {
+        return Ok(None);
+    };
+    let hemtt_private_key = HEMTTPrivateKey {
+        bi: BIPrivateKey::generate(1024, authority)?,
+        project: ctx.config().name().to_string(),
+        prefix: ctx.config().prefix().to_string(),
+        git_hash,
+    };
+    write(cmd, &hemtt_private_key)?;
+    Ok(Some(hemtt_private_key))
+}
+
+/// Confirm the user wants to create a key for this project
+///
+/// Returns the git hash the key will be bound to, or `None` if the user does not confirm
+fn confirm(ctx: &Context, authority: &str) -> Result<Option<String>, Error> {
+    let output = format!("{authority}.hemttprivatekey");
+    if std::path::Path::new(&output).exists() {
+        error!("Output file {output} already exists. Aborting to prevent overwrite.");
+        std::process::exit(1);
+    }
+
+    warn!("Generating HEMTT private keys is for specific use cases only.");
+    warn!("In nearly all cases, you should not use this command.");
+    dialoguer::Confirm::new()
+        .with_prompt("I fully understand the risks of using private keys")
+        .default(true)
+        .interact()?;
+
+    let git_hash = get_git_first_hash()?;
+    println!("Project:   {}", ctx.config().name());
+    println!("Prefix:    {}", ctx.config().prefix());
+    println!("Git:       {git_hash}");
+    println!("Authority: {authority}");
+    println!();
+    warn!("The generated key will be usable ONLY with this project");
+    if !dialoguer::Confirm::new()
+        .with_prompt("Confirm")
+        .default(false)
+        .interact()?
+    {
+        return Ok(None);
+    }
+    Ok(Some(git_hash))
+}
+
+/// Write a HEMTT private key encrypted with a new password
+fn write(cmd: &Command, key: &HEMTTPrivateKey) -> Result<(), Error> {
+    let password = generate_password();
+    let mut output = fs_err::File::create(format!("{}.hemttprivatekey", key.bi.authority()))?;
+    key.write_encrypted(&mut output, &password, kdf_params(cmd))?;
+
+    // Add to .gitignore if not already present
+    let gitignore_path = std::path::Path::new(".gitignore");
+    let mut gitignore_contents = String::new();
+    if gitignore_path.exists() {
+        fs_err::File::open(gitignore_path)?.read_to_string(&mut gitignore_contents)?;
+    }
+    let gitignore_entry = "*.hemttprivatekey";
+    if !gitignore_contents
+        .lines()
+        .any(|line| line.trim() == gitignore_entry)
+    {
+        let mut gitignore_file = fs_err::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(gitignore_path)?;
+        gitignore_file.write_all(format!("\n{gitignore_entry}\n").as_bytes())?;
+        println!(".gitignore updated to exclude HEMTT private keys");
+    }
+
+    Ok(())
+}
+
 fn generate_password() -> String {
     const LENGTH: usize = 32;
     let password: String = rand::rng()
@@ -30,6 +30,9 @@ pub fn global_modules(executor: &mut crate::executor::Executor) {
     executor.add_module(Box::<crate::modules::git::Git>::default());
     executor.add_module(Box::<crate::modules::Hooks>::default());
     executor.add_module(Box::<crate::modules::Stringtables>::default());
+    executor.add_module(Box::<crate::modules::TextureLints>::default());
+    executor.add_module(Box::<crate::modules::ModelLints>::default());
+    executor.add_module(Box::<crate::modules::AudioLints>::default());
     executor.add_module(Box::<crate::modules::SQFCompiler>::default());
     executor.add_module(Box::<crate::modules::PboPrefix>::default());
 }
@@ -55,7 +55,7 @@ pub fn execute(cmd: &Command) -> Result<Report, Error> {
             utils::paa::execute(cmd)?;
         }
         Subcommands::Pbo(cmd) => {
-            utils::pbo::execute(cmd)?;
+            report = utils::pbo::execute(cmd)?;
         }
         Subcommands::RemoveLinks(cmd) => {
             report = utils::remove_links::execute(cmd)?;
@@ -131,6 +131,10 @@ impl Context {
             if include.is_dir() {
                 builder = builder.physical(&include, LayerType::Include);
             }
+            for mount in config.mount().mods() {
+                trace!("mounting mod: {:?}", mount.display());
+                builder = builder.pbos(&root.join(mount))?;
+            }
             Some(build_folder)
         } else {
             None
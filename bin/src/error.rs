@@ -21,6 +21,8 @@ pub enum Error {
     Preprocessor(#[from] hemtt_preprocessor::Error),
     #[error("PBO error: {0}")]
     Pbo(#[from] hemtt_pbo::Error),
+    #[error("P3D error: {0}")]
+    P3d(#[from] hemtt_p3d::Error),
     #[error("Prefix error: {0}")]
     Prefix(#[from] hemtt_common::prefix::Error),
     #[error("Signing error: {0}")]
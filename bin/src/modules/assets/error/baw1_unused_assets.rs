@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic, Severity};
+
+pub struct UnusedAssets {
+    addon: String,
+    assets: Vec<String>,
+}
+impl Code for UnusedAssets {
+    fn ident(&self) -> &'static str {
+        "BAW1"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "{} has {} unused asset{}:\n  {}",
+            self.addon,
+            self.assets.len(),
+            if self.assets.len() == 1 { "" } else { "s" },
+            self.assets.join("\n  ")
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(String::from(
+            "remove the assets, or add them to `hemtt.check.allow_unused` if they are used in ways HEMTT can not follow, such as paths built with `format`",
+        ))
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(String::from(
+            "the references that were found are written to `.hemttout/assets.json`",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl UnusedAssets {
+    pub fn code(addon: String, assets: Vec<String>) -> Arc<dyn Code> {
+        Arc::new(Self { addon, assets })
+    }
+}
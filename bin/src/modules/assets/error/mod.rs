@@ -0,0 +1 @@
+pub mod baw1_unused_assets;
@@ -0,0 +1,371 @@
+//! Find assets in addons that are never referenced
+//!
+//! A graph of references is built from configs, rvmats, SQF string literals and models.
+//! Assets are used when they can be reached from a config or script, directly or through
+//! the models and materials that reference them. The graph is written to `.hemttout/assets.json`.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    io::Read,
+};
+
+use hemtt_config::{Class, Item, Property, Value};
+use hemtt_p3d::{Model, analyze::ModelFile};
+use hemtt_workspace::WorkspacePath;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::Serialize;
+
+use crate::{context::Context, error::Error, progress::progress_bar, report::Report};
+
+use self::error::baw1_unused_assets::UnusedAssets;
+
+use super::Module;
+
+mod error;
+
+/// Files that are packed as assets, and referenced by path
+const ASSET_EXTENSIONS: &[&str] = &["p3d", "paa", "pac", "rvmat", "rtm", "wss", "ogg", "wav"];
+
+/// Files in the config format, which reference assets in string values
+const CONFIG_EXTENSIONS: &[&str] = &["cpp", "rvmat", "ext", "sqm", "bikb", "bisurf"];
+
+#[derive(Debug, Default)]
+pub struct Assets;
+
+#[derive(Serialize)]
+struct Graph {
+    /// The assets referenced by each file
+    references: BTreeMap<String, BTreeSet<String>>,
+    unused: BTreeSet<String>,
+}
+
+#[derive(Default)]
+/// Assets by the path they are referenced with, lowercase and without a leading separator
+struct Index {
+    paths: HashMap<String, String>,
+    /// Assets by their path without an extension, sounds and models can be referenced without one
+    stems: HashMap<String, Vec<String>>,
+}
+
+impl Index {
+    fn insert(&mut self, key: String, path: String) {
+        if let Some((stem, _)) = key.rsplit_once('.') {
+            self.stems
+                .entry(stem.to_string())
+                .or_default()
+                .push(path.clone());
+        }
+        self.paths.insert(key, path);
+    }
+
+    /// The assets a string can refer to, from a file in `folder`
+    fn resolve(&self, reference: &str, folder: &str) -> Vec<String> {
+        let reference = normalize(reference.trim_start_matches('@'));
+        if reference.is_empty() {
+            return Vec::new();
+        }
+        for candidate in [reference.clone(), format!("{folder}\\{reference}")] {
+            if let Some(path) = self.paths.get(&candidate) {
+                return vec![path.clone()];
+            }
+            let filename = candidate.rsplit('\\').next().unwrap_or_default();
+            if !filename.contains('.')
+                && let Some(paths) = self.stems.get(&candidate)
+            {
+                return paths.clone();
+            }
+        }
+        Vec::new()
+    }
+}
+
+impl Module for Assets {
+    fn name(&self) -> &'static str {
+        "Assets"
+    }
+
+    fn check(&self, ctx: &Context) -> Result<Report, Error> {
+        let allowed = ctx
+            .config()
+            .hemtt()
+            .check()
+            .allow_unused()
+            .iter()
+            .map(|g| glob::Pattern::new(g))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut index = Index::default();
+        let mut assets = BTreeMap::new();
+        let mut sources = Vec::new();
+        for addon in ctx.addons() {
+            let convert = addon
+                .config()
+                .map(|config| {
+                    config
+                        .textures()
+                        .convert()
+                        .iter()
+                        .map(|g| glob::Pattern::new(g))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?
+                .unwrap_or_default();
+            for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+                if !entry.is_file()? {
+                    continue;
+                }
+                let Some(extension) = entry.extension().map(|ext| ext.to_lowercase()) else {
+                    continue;
+                };
+                let relative = entry
+                    .as_str()
+                    .trim_start_matches(&format!("/{}/", addon.folder()));
+                let key = normalize(&format!("{}\\{relative}", addon.prefix()));
+                if ASSET_EXTENSIONS.contains(&extension.as_str()) {
+                    index.insert(key.clone(), entry.as_str().to_string());
+                    assets.insert(entry.as_str().to_string(), addon.name().to_string());
+                } else if convert.iter().any(|g| g.matches(relative))
+                    && !entry.with_extension("paa")?.exists()?
+                {
+                    // converted images are referenced by the paa they become
+                    let stem = key.rsplit_once('.').map_or(key.as_str(), |(stem, _)| stem);
+                    index.insert(format!("{stem}.paa"), entry.as_str().to_string());
+                    assets.insert(entry.as_str().to_string(), addon.name().to_string());
+                }
+                if extension == "sqf"
+                    || extension == "p3d"
+                    || CONFIG_EXTENSIONS.contains(&extension.as_str())
+                {
+                    let folder = key
+                        .rsplit_once('\\')
+                        .map_or(String::new(), |(folder, _)| folder.to_string());
+                    sources.push((entry, extension, folder));
+                }
+            }
+        }
+
+        let progress = progress_bar(sources.len() as u64).with_message("Finding Asset References");
+        let references = sources
+            .par_iter()
+            .map(|(entry, extension, folder)| {
+                let strings = match extension.as_str() {
+                    "sqf" => sqf_strings(ctx, entry),
+                    "p3d" => model_paths(entry)?,
+                    _ => config_strings(ctx, entry)?,
+                };
+                let targets = strings
+                    .iter()
+                    .flat_map(|reference| index.resolve(reference, folder))
+                    .filter(|target| target != entry.as_str())
+                    .collect::<BTreeSet<_>>();
+                progress.inc(1);
+                Ok((entry.as_str().to_string(), targets))
+            })
+            .collect::<Result<BTreeMap<_, _>, Error>>()?;
+        progress.finish_and_clear();
+
+        let is_allowed = |path: &str| {
+            let path = path.trim_start_matches('/');
+            allowed.iter().any(|g| g.matches(path))
+        };
+        // configs, scripts and allowed assets are used, and so is everything they reach
+        let mut queue = references
+            .keys()
+            .filter(|path| !assets.contains_key(*path))
+            .chain(assets.keys().filter(|path| is_allowed(path)))
+            .cloned()
+            .collect::<VecDeque<_>>();
+        let mut used = queue.iter().cloned().collect::<HashSet<_>>();
+        while let Some(path) = queue.pop_front() {
+            for target in references.get(&path).into_iter().flatten() {
+                if used.insert(target.clone()) {
+                    queue.push_back(target.clone());
+                }
+            }
+        }
+
+        let mut report = Report::new();
+        let mut by_addon: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        let unused = assets
+            .iter()
+            .filter(|(path, _)| !used.contains(*path))
+            .map(|(path, addon)| {
+                by_addon
+                    .entry(addon.as_str())
+                    .or_default()
+                    .push(path.trim_start_matches('/').to_string());
+                path.clone()
+            })
+            .collect::<BTreeSet<_>>();
+        info!("Found {} unused assets", unused.len());
+        for (addon, paths) in by_addon {
+            report.push(UnusedAssets::code(addon.to_string(), paths));
+        }
+        let graph = Graph {
+            references: references
+                .into_iter()
+                .filter(|(_, targets)| !targets.is_empty())
+                .collect(),
+            unused,
+        };
+        fs_err::write(
+            ctx.out_folder().join("assets.json"),
+            serde_json::to_string_pretty(&graph)?,
+        )?;
+        Ok(report)
+    }
+}
+
+/// Lowercase with backslashes and no leading separator
+fn normalize(path: &str) -> String {
+    path.replace('/', "\\")
+        .trim_start_matches('\\')
+        .to_lowercase()
+}
+
+/// String values of a config, rvmat or other file in the config format
+fn config_strings(ctx: &Context, entry: &WorkspacePath) -> Result<Vec<String>, Error> {
+    let mut buffer = [0; 4];
+    if entry.open_file()?.read_exact(&mut buffer).is_ok() && &buffer == b"\0raP" {
+        debug!("Skipping rapified {}", entry);
+        return Ok(Vec::new());
+    }
+    // errors are reported by the rapifier
+    let Ok(processed) = hemtt_preprocessor::Processor::run_with_sources(
+        entry,
+        ctx.config().preprocessor(),
+        ctx.sources(),
+    ) else {
+        debug!("Failed to preprocess {}", entry);
+        return Ok(Vec::new());
+    };
+    let mut strings = Vec::new();
+    if let Ok(report) = hemtt_config::parse(None, &processed) {
+        collect_strings(&report.config().0, &mut strings);
+    }
+    Ok(strings)
+}
+
+fn collect_strings(properties: &[Property], strings: &mut Vec<String>) {
+    for property in properties {
+        match property {
+            Property::Class(Class::Local { properties, .. } | Class::Root { properties }) => {
+                collect_strings(properties, strings);
+            }
+            Property::Entry {
+                value: Value::Str(value),
+                ..
+            } => strings.push(value.value().to_string()),
+            Property::Entry {
+                value: Value::Array(array) | Value::UnexpectedArray(array),
+                ..
+            } => collect_items(array.items(), strings),
+            _ => {}
+        }
+    }
+}
+
+fn collect_items(items: &[Item], strings: &mut Vec<String>) {
+    for item in items {
+        match item {
+            Item::Str(value) => strings.push(value.value().to_string()),
+            Item::Array(items) => collect_items(items, strings),
+            _ => {}
+        }
+    }
+}
+
+/// String literals of a script, after preprocessing
+fn sqf_strings(ctx: &Context, entry: &WorkspacePath) -> Vec<String> {
+    // errors are reported by the SQF compiler
+    hemtt_preprocessor::Processor::run_with_sources(
+        entry,
+        ctx.config().preprocessor(),
+        ctx.sources(),
+    )
+    .map_or_else(
+        |_| {
+            debug!("Failed to preprocess {}", entry);
+            Vec::new()
+        },
+        |processed| string_literals(processed.as_str()),
+    )
+}
+
+/// Strings in double or single quotes, where a doubled quote is an escaped quote
+fn string_literals(text: &str) -> Vec<String> {
+    let mut literals = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(quote) = chars.next() {
+        if quote != '"' && quote != '\'' {
+            continue;
+        }
+        let mut literal = String::new();
+        while let Some(c) = chars.next() {
+            if c != quote {
+                literal.push(c);
+            } else if chars.peek() == Some(&quote) {
+                chars.next();
+                literal.push(quote);
+            } else {
+                break;
+            }
+        }
+        literals.push(literal);
+    }
+    literals
+}
+
+/// Textures, materials and proxies of a model
+fn model_paths(entry: &WorkspacePath) -> Result<Vec<String>, Error> {
+    let model = match Model::read(&mut entry.open_file()?) {
+        Ok(model) => model,
+        Err(e) => {
+            debug!("Skipping model {}: {}", entry, e);
+            return Ok(Vec::new());
+        }
+    };
+    let mut paths = model.dependencies();
+    if let Model::MLOD(p3d) = model {
+        paths.extend(ModelFile::new(entry.as_str().to_string(), p3d).proxies());
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Index, string_literals};
+
+    #[test]
+    fn literals() {
+        assert_eq!(
+            string_literals(r#"private _a = "\x\a.paa"; _b = 'it''s'; systemChat """q""";"#),
+            vec![r"\x\a.paa", "it's", r#""q""#]
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        let mut index = Index::default();
+        index.insert(
+            String::from(r"abe\addons\main\data\box_co.paa"),
+            String::from("/addons/main/data/box_co.paa"),
+        );
+        index.insert(
+            String::from(r"abe\addons\main\sounds\beep.ogg"),
+            String::from("/addons/main/sounds/beep.ogg"),
+        );
+        assert_eq!(
+            index.resolve(r"\ABE\addons\main\data\box_co.paa", ""),
+            vec!["/addons/main/data/box_co.paa"]
+        );
+        assert_eq!(
+            index.resolve(r"@abe\addons\main\sounds\beep", ""),
+            vec!["/addons/main/sounds/beep.ogg"]
+        );
+        assert_eq!(
+            index.resolve(r"data\box_co.paa", r"abe\addons\main"),
+            vec!["/addons/main/data/box_co.paa"]
+        );
+        assert!(index.resolve(r"abe\addons\main\data\box_co.rvmat", "").is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+use hemtt_wss::analyze::{
+    AudioFile, LintData, SUPPORTED_EXTENSIONS, lint_check, lint_file, lint_reference,
+    sound_references,
+};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    context::Context, error::Error, modules::Module, progress::progress_bar, report::Report,
+};
+
+#[derive(Debug, Default)]
+/// Lint every audio file in the addons, and the sounds named by their configs
+pub struct AudioLints;
+
+impl Module for AudioLints {
+    fn name(&self) -> &'static str {
+        "AudioLints"
+    }
+
+    fn priority(&self) -> i32 {
+        5000
+    }
+
+    fn check(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        report.extend(lint_check(
+            ctx.config().lints().audio().clone(),
+            ctx.config().runtime().clone(),
+        ));
+
+        let mut prefixes = vec![ctx.config().mainprefix().map_or_else(
+            || ctx.config().prefix().to_string(),
+            |mainprefix| format!("{mainprefix}\\{}", ctx.config().prefix()),
+        )];
+        let mut paths = Vec::new();
+        let mut configs = Vec::new();
+        for addon in ctx.addons() {
+            prefixes.push(addon.prefix().to_string());
+            let folder = ctx.workspace_path().join(addon.folder())?;
+            paths.extend(folder.walk_dir()?.into_iter().filter(|p| {
+                p.extension().is_some_and(|ext| {
+                    SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str())
+                })
+            }));
+            let config = folder.join("config.cpp")?;
+            if config.exists()? {
+                configs.push(config);
+            }
+        }
+        let data = LintData::new(Some(ctx.workspace_path().clone()), &prefixes);
+
+        let progress = progress_bar(paths.len() as u64).with_message("Linting Audio");
+        let files = paths
+            .into_par_iter()
+            .map(|path| {
+                let codes = match AudioFile::read(&path) {
+                    Ok(Some(file)) => Some(lint_file(&file, Some(ctx.config()), &data)),
+                    Ok(None) => None,
+                    Err(e) => {
+                        debug!("Failed to read audio {}: {}", path, e);
+                        None
+                    }
+                };
+                progress.inc(1);
+                codes
+            })
+            .collect::<Vec<_>>();
+        progress.finish_and_clear();
+        let mut checked = 0;
+        for codes in files.into_iter().flatten() {
+            checked += 1;
+            report.extend(codes);
+        }
+
+        for path in configs {
+            // errors are reported by the rapifier
+            let Ok(processed) = hemtt_preprocessor::Processor::run_with_sources(
+                &path,
+                ctx.config().preprocessor(),
+                ctx.sources(),
+            ) else {
+                continue;
+            };
+            let Ok(config) = hemtt_config::parse(None, &processed) else {
+                continue;
+            };
+            for reference in sound_references(config.config()) {
+                report.extend(lint_reference(
+                    &reference,
+                    Some(ctx.config()),
+                    &processed,
+                    &data,
+                ));
+            }
+        }
+        info!("Checked {} audio files", checked);
+        Ok(report)
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use hemtt_config::Str;
+use hemtt_workspace::reporting::{Code, Diagnostic, Processed, Severity};
+
+pub struct MissingSelection {
+    p3d: String,
+    selection: Str,
+    usage: String,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for MissingSelection {
+    fn ident(&self) -> &'static str {
+        "BBE10"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "{} has no selection `{}`, used by {}",
+            self.p3d,
+            self.selection.value(),
+            self.usage
+        )
+    }
+
+    fn label_message(&self) -> String {
+        String::from("not in the model")
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(String::from(
+            "add the named selection to the model, or fix its name in model.cfg",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl MissingSelection {
+    /// `usage` describes what needs the selection, such as "the bone" or "the animation `door`"
+    pub fn code(
+        p3d: String,
+        selection: Str,
+        usage: String,
+        processed: &Processed,
+    ) -> Arc<dyn Code> {
+        let mut code = Self {
+            p3d,
+            selection,
+            usage,
+            diagnostic: None,
+        };
+        code.diagnostic =
+            Diagnostic::from_code_processed(&code, code.selection.span().clone(), processed);
+        Arc::new(code)
+    }
+}
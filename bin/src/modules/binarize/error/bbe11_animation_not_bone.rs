@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use hemtt_config::Str;
+use hemtt_workspace::reporting::{Code, Diagnostic, Processed, Severity};
+
+pub struct AnimationNotBone {
+    animation: String,
+    selection: Str,
+    skeleton: Option<String>,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for AnimationNotBone {
+    fn ident(&self) -> &'static str {
+        "BBE11"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn message(&self) -> String {
+        self.skeleton.as_ref().map_or_else(
+            || {
+                format!(
+                    "animation `{}` uses the selection `{}`, but the model has no skeleton",
+                    self.animation,
+                    self.selection.value()
+                )
+            },
+            |skeleton| {
+                format!(
+                    "animation `{}` uses the selection `{}`, which is not a bone of skeleton `{}`",
+                    self.animation,
+                    self.selection.value(),
+                    skeleton
+                )
+            },
+        )
+    }
+
+    fn label_message(&self) -> String {
+        String::from("not a bone")
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(String::from(
+            "animations can only move bones, add the selection to `skeletonBones`",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl AnimationNotBone {
+    pub fn code(
+        animation: String,
+        selection: Str,
+        skeleton: Option<String>,
+        processed: &Processed,
+    ) -> Arc<dyn Code> {
+        let mut code = Self {
+            animation,
+            selection,
+            skeleton,
+            diagnostic: None,
+        };
+        code.diagnostic =
+            Diagnostic::from_code_processed(&code, code.selection.span().clone(), processed);
+        Arc::new(code)
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use hemtt_config::model_cfg::ResolveError;
+use hemtt_workspace::reporting::{Code, Diagnostic, Processed, Severity};
+
+pub struct UnknownSkeleton {
+    error: ResolveError,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for UnknownSkeleton {
+    fn ident(&self) -> &'static str {
+        "BBE8"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn message(&self) -> String {
+        match &self.error {
+            ResolveError::UnknownSkeleton(name) => {
+                format!("skeleton `{}` is not in `CfgSkeletons`", name.value())
+            }
+            ResolveError::CircularSkeleton(name) => {
+                format!("skeleton `{}` is inherited in a loop", name.value())
+            }
+        }
+    }
+
+    fn label_message(&self) -> String {
+        match &self.error {
+            ResolveError::UnknownSkeleton(_) => String::from("unknown skeleton"),
+            ResolveError::CircularSkeleton(_) => String::from("circular inheritance"),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        match &self.error {
+            ResolveError::UnknownSkeleton(_) => Some(String::from(
+                "add the skeleton to `CfgSkeletons` in this model.cfg, or fix its name",
+            )),
+            ResolveError::CircularSkeleton(_) => None,
+        }
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl UnknownSkeleton {
+    pub fn code(error: ResolveError, processed: &Processed) -> Arc<dyn Code> {
+        let mut code = Self {
+            error,
+            diagnostic: None,
+        };
+        code.diagnostic =
+            Diagnostic::from_code_processed(&code, code.error.name().span().clone(), processed);
+        Arc::new(code)
+    }
+}
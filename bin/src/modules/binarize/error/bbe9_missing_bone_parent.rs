@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use hemtt_config::model_cfg::Bone;
+use hemtt_workspace::reporting::{Code, Diagnostic, Processed, Severity};
+
+pub struct MissingBoneParent {
+    skeleton: String,
+    bone: Bone,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for MissingBoneParent {
+    fn ident(&self) -> &'static str {
+        "BBE9"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "bone `{}` has the parent `{}`, which is not a bone of skeleton `{}`",
+            self.bone.name.value(),
+            self.bone.parent.value(),
+            self.skeleton
+        )
+    }
+
+    fn label_message(&self) -> String {
+        String::from("unknown bone")
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(String::from(
+            "add the parent to `skeletonBones`, or use \"\" for a bone without a parent",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl MissingBoneParent {
+    pub fn code(skeleton: String, bone: Bone, processed: &Processed) -> Arc<dyn Code> {
+        let mut code = Self {
+            skeleton,
+            bone,
+            diagnostic: None,
+        };
+        code.diagnostic =
+            Diagnostic::from_code_processed(&code, code.bone.parent.span().clone(), processed);
+        Arc::new(code)
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use hemtt_config::Str;
+use hemtt_workspace::reporting::{Code, Diagnostic, Processed, Severity};
+
+pub struct UnknownAnimationSource {
+    animation: String,
+    source: Str,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for UnknownAnimationSource {
+    fn ident(&self) -> &'static str {
+        "BBW3"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "animation `{}` uses the unknown source `{}`",
+            self.animation,
+            self.source.value()
+        )
+    }
+
+    fn label_message(&self) -> String {
+        String::from("unknown source")
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(String::from(
+            "sources are provided by the engine, or by `AnimationSources` in the addon's config",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl UnknownAnimationSource {
+    pub fn code(animation: String, source: Str, processed: &Processed) -> Arc<dyn Code> {
+        let mut code = Self {
+            animation,
+            source,
+            diagnostic: None,
+        };
+        code.diagnostic =
+            Diagnostic::from_code_processed(&code, code.source.span().clone(), processed);
+        Arc::new(code)
+    }
+}
@@ -1,10 +1,15 @@
 // bbe1 - deprecated, previously tools_not_found
 // bbe2 - deprecated, previously platform_not_supported
+pub mod bbe10_missing_selection;
+pub mod bbe11_animation_not_bone;
 pub mod bbe3_binarize_failed;
 pub mod bbe4_missing_textures;
 pub mod bbe5_missing_material;
 pub mod bbe6_missing_pdrive;
 pub mod bbe7_wine_not_found;
+pub mod bbe8_unknown_skeleton;
+pub mod bbe9_missing_bone_parent;
 
 pub mod bbw1_tools_not_found;
 pub mod bbw2_platform_not_supported;
+pub mod bbw3_unknown_animation_source;
@@ -0,0 +1,80 @@
+use hemtt_p3d::{
+    P3D,
+    analyze::{LintData, ModelFile, lint_check, lint_one},
+};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    context::Context, error::Error, modules::Module, progress::progress_bar, report::Report,
+};
+
+#[derive(Debug, Default)]
+/// Lint every MLOD P3D in the addons
+///
+/// Binarized models are skipped, they can not be edited
+pub struct ModelLints;
+
+impl Module for ModelLints {
+    fn name(&self) -> &'static str {
+        "ModelLints"
+    }
+
+    fn priority(&self) -> i32 {
+        5000
+    }
+
+    fn check(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        report.extend(lint_check(
+            ctx.config().lints().models().clone(),
+            ctx.config().runtime().clone(),
+        ));
+
+        let mut prefixes = vec![ctx.config().mainprefix().map_or_else(
+            || ctx.config().prefix().to_string(),
+            |mainprefix| format!("{mainprefix}\\{}", ctx.config().prefix()),
+        )];
+        let mut paths = Vec::new();
+        for addon in ctx.addons() {
+            prefixes.push(addon.prefix().to_string());
+            paths.extend(
+                ctx.workspace_path()
+                    .join(addon.folder())?
+                    .walk_dir()?
+                    .into_iter()
+                    .filter(|p| {
+                        p.extension()
+                            .is_some_and(|ext| ext.eq_ignore_ascii_case("p3d"))
+                    }),
+            );
+        }
+        let data = LintData::new(Some(ctx.workspace_path().clone()), &prefixes);
+        let progress = progress_bar(paths.len() as u64).with_message("Linting Models");
+        let models = paths
+            .into_par_iter()
+            .map(|path| {
+                let codes = match P3D::read(&mut path.open_file()?) {
+                    Ok(p3d) => Some(lint_one(
+                        &ModelFile::new(path.as_str().to_string(), p3d),
+                        Some(ctx.config()),
+                        &data,
+                    )),
+                    Err(e) => {
+                        debug!("Skipping model {}: {}", path, e);
+                        None
+                    }
+                };
+                progress.inc(1);
+                Ok(codes)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        progress.finish_and_clear();
+        let mut checked = 0;
+        for codes in models.into_iter().flatten() {
+            checked += 1;
+            report.extend(codes);
+        }
+        info!("Checked {} models", checked);
+        Ok(report)
+    }
+}
@@ -19,6 +19,7 @@ use self::error::{
     bbw2_platform_not_supported::PlatformNotSupported,
 };
 use self::error::{bbe4_missing_textures::MissingTextures, bbe6_missing_pdrive::MissingPDrive};
+use self::model_cfg::ModelCfgCheck;
 use super::Module;
 use crate::{
     context::Context, error::Error, link::create_link,
@@ -27,6 +28,10 @@ use crate::{
 };
 
 mod error;
+mod lints;
+mod model_cfg;
+
+pub use lints::ModelLints;
 
 #[derive(Default)]
 pub struct Binarize {
@@ -160,6 +165,7 @@ impl Module for Binarize {
                 debug!("binarization disabled for {}", addon.name());
                 continue;
             }
+            let mut model_cfg = ModelCfgCheck::new(ctx, addon);
             for entry in ctx
                 .workspace_path()
                 .join(addon.folder())
@@ -238,6 +244,7 @@ impl Module for Binarize {
                             );
                             report.push(diag);
                         }
+                        report.extend(model_cfg.check(&entry, &p3d)?);
                     }
 
                     let tmp_sourced = ctx.tmp().join(addon.prefix().as_pathbuf()).join(
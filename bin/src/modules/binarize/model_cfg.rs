@@ -0,0 +1,396 @@
+//! Check `model.cfg` against the models it applies to
+//!
+//! Binarize only reports missing bones and selections while it is binarizing,
+//! with little context, so they are checked before it runs.
+
+use std::collections::{HashMap, HashSet};
+
+use hemtt_config::{
+    Class, Property, Value,
+    model_cfg::{CfgModel, ModelCfg, ResolveError},
+};
+use hemtt_p3d::{LOD, P3D};
+use hemtt_workspace::{
+    WorkspacePath,
+    addons::Addon,
+    reporting::{Codes, Processed},
+};
+
+use super::error::{
+    bbe8_unknown_skeleton::UnknownSkeleton, bbe9_missing_bone_parent::MissingBoneParent,
+    bbe10_missing_selection::MissingSelection, bbe11_animation_not_bone::AnimationNotBone,
+    bbw3_unknown_animation_source::UnknownAnimationSource,
+};
+use crate::{context::Context, error::Error};
+
+/// Animation sources provided by the engine
+const ENGINE_SOURCES: &[&str] = &[
+    "altbaro",
+    "altradar",
+    "ammo",
+    "ammorandom",
+    "brakes",
+    "clockhour",
+    "clockminute",
+    "clocksecond",
+    "collective",
+    "damage",
+    "direct",
+    "door",
+    "elevator",
+    "flag",
+    "fuel",
+    "gear",
+    "gmeter",
+    "gmeterx",
+    "gmetery",
+    "gmeterz",
+    "gunnerturret",
+    "hasoptics",
+    "hatchcommander",
+    "hatchdriver",
+    "hatchgunner",
+    "hit",
+    "horizonbank",
+    "horizondive",
+    "horn",
+    "isempty",
+    "isflipped",
+    "isselected",
+    "lever",
+    "maingun",
+    "mainturret",
+    "obsgun",
+    "obsturret",
+    "pedals",
+    "pedalsleft",
+    "pedalsright",
+    "propeller",
+    "reload",
+    "reloadmagazine",
+    "revolving",
+    "rotor",
+    "rotorh",
+    "rotorhdive",
+    "rotorv",
+    "rpm",
+    "rudder",
+    "selectedweapon",
+    "speed",
+    "support",
+    "time",
+    "turbine",
+    "user",
+    "vertspeed",
+    "weaponmode",
+    "wheel",
+    "wind",
+    "winddir",
+    "zeroing",
+    "zeroing1",
+    "zeroing2",
+];
+
+struct Parsed {
+    model_cfg: ModelCfg,
+    processed: Processed,
+}
+
+/// Checks the models of an addon, parsing each `model.cfg` once
+pub struct ModelCfgCheck<'a> {
+    ctx: &'a Context,
+    addon: &'a Addon,
+    parsed: HashMap<String, Option<Parsed>>,
+    sources: Option<HashSet<String>>,
+}
+
+impl<'a> ModelCfgCheck<'a> {
+    pub fn new(ctx: &'a Context, addon: &'a Addon) -> Self {
+        Self {
+            ctx,
+            addon,
+            parsed: HashMap::new(),
+            sources: None,
+        }
+    }
+
+    /// Check a model against the nearest `model.cfg`
+    ///
+    /// Problems with the `model.cfg` itself are reported with the first model that uses it
+    pub fn check(&mut self, entry: &WorkspacePath, p3d: &P3D) -> Result<Codes, Error> {
+        let mut codes = Vec::new();
+        let Some(path) = self.find(entry)? else {
+            return Ok(codes);
+        };
+        if !self.parsed.contains_key(path.as_str()) {
+            let parsed = self.parse(&path, &mut codes);
+            self.parsed.insert(path.as_str().to_string(), parsed);
+        }
+        let stem = entry.filename();
+        let stem = stem
+            .rsplit_once('.')
+            .map_or(stem.as_str(), |(stem, _)| stem);
+        let Some(Some(parsed)) = self.parsed.get(path.as_str()) else {
+            return Ok(codes);
+        };
+        let Some(model) = parsed.model_cfg.model(stem) else {
+            return Ok(codes);
+        };
+        if self.sources.is_none() && !parsed.model_cfg.animations(model).is_empty() {
+            self.sources = Some(self.config_sources());
+        }
+        codes.extend(check_model(
+            entry.as_str(),
+            p3d,
+            parsed,
+            model,
+            self.sources.as_ref(),
+        ));
+        Ok(codes)
+    }
+
+    /// The `model.cfg` in the folder of the model, or the nearest parent folder in the addon
+    fn find(&self, entry: &WorkspacePath) -> Result<Option<WorkspacePath>, Error> {
+        let root = self.ctx.workspace_path().join(self.addon.folder())?;
+        let mut folder = entry.parent();
+        loop {
+            let path = folder.join("model.cfg")?;
+            if path.exists()? {
+                return Ok(Some(path));
+            }
+            if folder.as_str().len() <= root.as_str().len() {
+                return Ok(None);
+            }
+            folder = folder.parent();
+        }
+    }
+
+    /// Parse a `model.cfg`, checking the skeletons and the names they use
+    fn parse(&self, path: &WorkspacePath, codes: &mut Codes) -> Option<Parsed> {
+        let processed = match hemtt_preprocessor::Processor::run_with_sources(
+            path,
+            self.ctx.config().preprocessor(),
+            self.ctx.sources(),
+        ) {
+            Ok(processed) => processed,
+            Err((_, hemtt_preprocessor::Error::Code(code))) => {
+                codes.push(code);
+                return None;
+            }
+            Err((_, e)) => {
+                debug!("Failed to preprocess {}: {}", path, e);
+                return None;
+            }
+        };
+        let report = match hemtt_config::parse(None, &processed) {
+            Ok(report) => report,
+            Err(errors) => {
+                codes.extend(errors);
+                return None;
+            }
+        };
+        codes.extend(report.errors().into_iter().cloned());
+        let model_cfg = ModelCfg::from_config(report.config());
+        let mut reported = HashSet::new();
+        for skeleton in model_cfg.skeletons() {
+            match model_cfg.bones(skeleton) {
+                Ok(bones) => {
+                    let names = bones
+                        .iter()
+                        .map(|bone| bone.name.value().to_lowercase())
+                        .collect::<HashSet<_>>();
+                    for bone in bones {
+                        if !bone.parent.value().is_empty()
+                            && !names.contains(&bone.parent.value().to_lowercase())
+                        {
+                            codes.push(MissingBoneParent::code(
+                                skeleton.name.as_str().to_string(),
+                                bone,
+                                &processed,
+                            ));
+                        }
+                    }
+                }
+                Err(error) => {
+                    // skeletons inheriting from the same one share the error
+                    if reported.insert(error.name().span().clone()) {
+                        codes.push(UnknownSkeleton::code(error, &processed));
+                    }
+                }
+            }
+        }
+        for model in model_cfg.models() {
+            if let Some(name) = &model.skeleton_name
+                && !name.value().is_empty()
+                && model_cfg.skeleton(name.value()).is_none()
+            {
+                codes.push(UnknownSkeleton::code(
+                    ResolveError::UnknownSkeleton(name.clone()),
+                    &processed,
+                ));
+            }
+        }
+        Some(Parsed {
+            model_cfg,
+            processed,
+        })
+    }
+
+    /// Sources declared in the addon's `config.cpp`, in lowercase
+    fn config_sources(&self) -> HashSet<String> {
+        let mut sources = HashSet::new();
+        let Ok(path) = self
+            .ctx
+            .workspace_path()
+            .join(self.addon.folder())
+            .and_then(|folder| folder.join("config.cpp"))
+        else {
+            return sources;
+        };
+        if !path.exists().unwrap_or_default() {
+            return sources;
+        }
+        let Ok(processed) = hemtt_preprocessor::Processor::run_with_sources(
+            &path,
+            self.ctx.config().preprocessor(),
+            self.ctx.sources(),
+        ) else {
+            return sources;
+        };
+        if let Ok(report) = hemtt_config::parse(None, &processed) {
+            collect_sources(&report.config().0, false, &mut sources);
+        }
+        sources
+    }
+}
+
+/// Collect classes of `AnimationSources`, and sources named by turrets such as `animationSourceGun`
+fn collect_sources(properties: &[Property], in_sources: bool, sources: &mut HashSet<String>) {
+    for property in properties {
+        match property {
+            Property::Class(Class::Local {
+                name, properties, ..
+            }) => {
+                if in_sources {
+                    sources.insert(name.as_str().to_lowercase());
+                }
+                collect_sources(
+                    properties,
+                    name.as_str().eq_ignore_ascii_case("animationsources"),
+                    sources,
+                );
+            }
+            Property::Entry {
+                name,
+                value: Value::Str(value),
+                ..
+            } if name.as_str().to_lowercase().starts_with("animationsource") => {
+                sources.insert(value.value().to_lowercase());
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_model(
+    p3d_path: &str,
+    p3d: &P3D,
+    parsed: &Parsed,
+    model: &CfgModel,
+    sources: Option<&HashSet<String>>,
+) -> Codes {
+    let mut codes = Vec::new();
+    let Parsed {
+        model_cfg,
+        processed,
+    } = parsed;
+    let selections = p3d
+        .lods
+        .iter()
+        .flat_map(LOD::selection_names)
+        .map(str::to_lowercase)
+        .collect::<HashSet<_>>();
+    let memory = p3d
+        .lods
+        .iter()
+        .find(|lod| lod.type_name == "Memory")
+        .map(|lod| {
+            lod.selection_names()
+                .map(str::to_lowercase)
+                .collect::<HashSet<_>>()
+        })
+        .unwrap_or_default();
+
+    let skeleton = model_cfg
+        .skeleton_name(model)
+        .filter(|name| !name.value().is_empty())
+        .and_then(|name| model_cfg.skeleton(name.value()));
+    let bones = skeleton
+        .and_then(|skeleton| model_cfg.bones(skeleton).ok())
+        .unwrap_or_default();
+    let skeleton_name = skeleton.map(|skeleton| skeleton.name.as_str().to_string());
+    for bone in &bones {
+        if !selections.contains(&bone.name.value().to_lowercase()) {
+            codes.push(MissingSelection::code(
+                p3d_path.to_string(),
+                bone.name.clone(),
+                format!(
+                    "a bone of skeleton `{}`",
+                    skeleton_name.as_deref().unwrap_or_default()
+                ),
+                processed,
+            ));
+        }
+    }
+    let bones = bones
+        .iter()
+        .map(|bone| bone.name.value().to_lowercase())
+        .collect::<HashSet<_>>();
+
+    for animation in model_cfg.animations(model) {
+        let name = animation.name.as_str();
+        if let Some(selection) = &animation.selection
+            && !selection.value().is_empty()
+            && !bones.contains(&selection.value().to_lowercase())
+        {
+            codes.push(AnimationNotBone::code(
+                name.to_string(),
+                selection.clone(),
+                skeleton_name.clone(),
+                processed,
+            ));
+        }
+        let points = if animation.memory == Some(false) {
+            &selections
+        } else {
+            &memory
+        };
+        for point in [&animation.axis, &animation.begin, &animation.end]
+            .into_iter()
+            .flatten()
+        {
+            if !point.value().is_empty() && !points.contains(&point.value().to_lowercase()) {
+                codes.push(MissingSelection::code(
+                    p3d_path.to_string(),
+                    point.clone(),
+                    format!("the animation `{name}`"),
+                    processed,
+                ));
+            }
+        }
+        if let (Some(source), Some(sources)) = (&animation.source, sources) {
+            let lower = source.value().to_lowercase();
+            if !lower.is_empty()
+                && !ENGINE_SOURCES.contains(&lower.as_str())
+                && !sources.contains(&lower)
+            {
+                codes.push(UnknownAnimationSource::code(
+                    name.to_string(),
+                    source.clone(),
+                    processed,
+                ));
+            }
+        }
+    }
+    codes
+}
@@ -1,13 +1,15 @@
 use crate::{context::Context, error::Error, report::Report};
 
+mod assets;
+mod audio;
 mod binarize;
 mod file_patching;
 mod files;
 mod new;
 mod pboprefix;
-mod rapifier;
 mod sqf;
 mod stringtables;
+mod textures;
 
 pub mod archive;
 pub mod bom;
@@ -16,11 +18,14 @@ pub mod git;
 pub mod hook;
 pub mod meta;
 pub mod pbo;
+pub(crate) mod rapifier;
 pub(crate) mod sign;
 pub mod summary;
 pub mod tex_headers;
 
-pub use binarize::Binarize;
+pub use assets::Assets;
+pub use audio::AudioLints;
+pub use binarize::{Binarize, ModelLints};
 pub use file_patching::FilePatching;
 pub use files::Files;
 pub use hook::Hooks;
@@ -30,6 +35,7 @@ pub use rapifier::{AddonConfigs, Rapifier};
 pub use sign::Sign;
 pub use sqf::SQFCompiler;
 pub use stringtables::Stringtables;
+pub use textures::{TextureLints, Textures};
 
 pub trait Module {
     fn name(&self) -> &'static str;
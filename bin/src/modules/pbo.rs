@@ -125,6 +125,17 @@ fn internal_build(
                 }
             }
             if let Some(config) = addon.config() {
+                // source images that were converted to a paa
+                for pattern in config.textures().convert() {
+                    if glob::Pattern::new(pattern)?.matches(
+                        entry
+                            .as_str()
+                            .trim_start_matches(&format!("/{}/", addon.folder())),
+                    ) && entry.with_extension("paa")?.exists()?
+                    {
+                        continue 'entries;
+                    }
+                }
                 for exclude in config.files().exclude() {
                     if glob::Pattern::new(exclude)?.matches(
                         entry
@@ -150,9 +161,31 @@ fn internal_build(
                 .as_str()
                 .trim_start_matches(&format!("/{}/", addon.folder()))
                 .replace('/', "\\");
-            trace!("adding file {:?}", file);
+            let mut compress = false;
+            for pattern in ctx.config().files().compress() {
+                if glob::Pattern::new(pattern)?.matches(entry.as_str().trim_start_matches('/')) {
+                    compress = true;
+                }
+            }
+            if let Some(config) = addon.config() {
+                for pattern in config.files().compress() {
+                    if glob::Pattern::new(pattern)?.matches(
+                        entry
+                            .as_str()
+                            .trim_start_matches(&format!("/{}/", addon.folder())),
+                    ) {
+                        compress = true;
+                    }
+                }
+            }
 
-            pbo.add_file(file, entry.open_file()?)?;
+            if compress {
+                trace!("adding compressed file {:?}", file);
+                pbo.add_file_compressed(file, entry.open_file()?)?;
+            } else {
+                trace!("adding file {:?}", file);
+                pbo.add_file(file, entry.open_file()?)?;
+            }
         }
     }
     for header in ctx.config().properties() {
@@ -0,0 +1,181 @@
+//! Signing with a private key held outside of HEMTT
+//!
+//! HEMTT sends a [`SigningRequest`] as a single line of JSON, and reads back a
+//! [`SigningResponse`] as a single line of JSON. Only the hashes of the PBO are
+//! sent, the signer never sees the PBO itself.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpStream,
+    path::Path,
+    process::Stdio,
+    time::Duration,
+};
+
+use fs_err::File;
+use hemtt_common::{BISignVersion, config::ExternalSigner};
+use hemtt_pbo::ReadablePbo;
+use hemtt_signing::{BIPublicKey, BISign, SigningRequest, SigningResponse};
+
+use crate::error::Error;
+
+/// How long to wait for a signing service to answer
+const TIMEOUT: Duration = Duration::from_mins(1);
+
+#[derive(Debug)]
+pub struct External {
+    config: ExternalSigner,
+    key: BIPublicKey,
+}
+
+impl External {
+    /// Load the public key of an external signer
+    ///
+    /// # Errors
+    /// [`Error::Config`] if neither or both of `command` and `socket` are set
+    /// [`Error::Signing`] if the public key can not be read
+    pub fn new(config: &ExternalSigner, project: &Path) -> Result<Self, Error> {
+        if config.command().is_empty() == config.socket().is_none() {
+            return Err(Error::Config(
+                "signing.external requires exactly one of `command` or `socket`".to_string(),
+            ));
+        }
+        let key = BIPublicKey::read(&mut File::open(project.join(config.public_key()))?)?;
+        Ok(Self {
+            config: config.clone(),
+            key,
+        })
+    }
+
+    pub const fn public_key(&self) -> &BIPublicKey {
+        &self.key
+    }
+
+    /// Sign a PBO, verifying the returned signature before accepting it
+    ///
+    /// # Errors
+    /// [`Error::Signing`] if the signer fails, or returns an invalid signature
+    pub fn sign(
+        &self,
+        pbo: &mut ReadablePbo<File>,
+        version: BISignVersion,
+    ) -> Result<BISign, Error> {
+        let request = SigningRequest::new(pbo, version, &self.key)?;
+        let response = if let Some(socket) = self.config.socket() {
+            request_socket(socket, &request)?
+        } else {
+            request_command(self.config.command(), &request)?
+        };
+        let signature = BISign::from_response(version, &self.key, &response)?;
+        self.key.verify(pbo, &signature)?;
+        Ok(signature)
+    }
+}
+
+fn request_socket(address: &str, request: &SigningRequest) -> Result<SigningResponse, Error> {
+    trace!("requesting signature from {address}");
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    writeln!(stream, "{}", serde_json::to_string(request)?)?;
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line)?;
+    Ok(serde_json::from_str(&line)?)
+}
+
+fn request_command(command: &[String], request: &SigningRequest) -> Result<SigningResponse, Error> {
+    trace!("requesting signature from {}", command[0]);
+    let mut child = std::process::Command::new(&command[0])
+        .args(&command[1..])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    {
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        writeln!(stdin, "{}", serde_json::to_string(request)?)?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(hemtt_signing::Error::InvalidResponse(format!(
+            "`{}` exited with {}: {}",
+            command[0],
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+        .into());
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(serde_json::from_str(
+        stdout.lines().next().unwrap_or_default(),
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{
+        io::{BufRead, BufReader, Cursor, Write},
+        net::TcpListener,
+    };
+
+    use hemtt_common::{BISignVersion, config::ExternalSigner};
+    use hemtt_pbo::{ReadablePbo, WritablePbo};
+    use hemtt_signing::{BIPrivateKey, SigningRequest};
+
+    /// A stand-in signing service, answering a single request
+    fn serve(key: BIPrivateKey) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let request: SigningRequest = serde_json::from_str(&line).unwrap();
+            let response = key.sign_request(&request).unwrap();
+            writeln!(&stream, "{}", serde_json::to_string(&response).unwrap()).unwrap();
+        });
+        address
+    }
+
+    #[test]
+    fn socket() {
+        let folder = std::env::temp_dir().join("hemtt_sign_external");
+        let _ = fs_err::remove_dir_all(&folder);
+        fs_err::create_dir_all(&folder).unwrap();
+
+        let key = BIPrivateKey::generate(1024, "external").unwrap();
+        key.to_public_key()
+            .write(&mut fs_err::File::create(folder.join("external.bikey")).unwrap())
+            .unwrap();
+        let mut pbo = WritablePbo::new();
+        pbo.add_property("prefix", "z\\test\\addons\\main");
+        pbo.add_file("script.sqf", Cursor::new(b"hint 'a';".to_vec()))
+            .unwrap();
+        pbo.write(
+            &mut fs_err::File::create(folder.join("main.pbo")).unwrap(),
+            true,
+        )
+        .unwrap();
+
+        let config = ExternalSigner::new(
+            "external.bikey".to_string(),
+            Vec::new(),
+            Some(serve(key.clone())),
+        );
+        let external = super::External::new(&config, &folder).unwrap();
+        let mut pbo =
+            ReadablePbo::from(fs_err::File::open(folder.join("main.pbo")).unwrap()).unwrap();
+        let signature = external.sign(&mut pbo, BISignVersion::V3).unwrap();
+        assert_eq!(signature.authority(), "external");
+        key.to_public_key().verify(&mut pbo, &signature).unwrap();
+        fs_err::remove_dir_all(&folder).unwrap();
+    }
+
+    #[test]
+    fn requires_one_transport() {
+        let config = ExternalSigner::new("external.bikey".to_string(), Vec::new(), None);
+        assert!(super::External::new(&config, &std::env::temp_dir()).is_err());
+    }
+}
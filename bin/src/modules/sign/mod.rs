@@ -0,0 +1,321 @@
+use std::sync::{Arc, RwLock};
+
+use fs_err::File;
+use hemtt_common::{BISignVersion, prefix::FILES};
+use hemtt_pbo::ReadablePbo;
+use hemtt_signing::{BIPrivateKey, BIPublicKey, BISign, HEMTTPrivateKey};
+use hemtt_workspace::{
+    addons::Location,
+    reporting::{Code, Diagnostic},
+};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{context::Context, error::Error, report::Report};
+
+use super::Module;
+
+mod external;
+
+use external::External;
+
+/// Something that can sign PBOs
+pub trait Signer: Send + Sync {
+    fn public_key(&self) -> BIPublicKey;
+
+    /// Sign a PBO
+    ///
+    /// # Errors
+    /// [`Error`] if the PBO can not be read or signed
+    fn sign(&self, pbo: &mut ReadablePbo<File>, version: BISignVersion) -> Result<BISign, Error>;
+}
+
+impl Signer for BIPrivateKey {
+    fn public_key(&self) -> BIPublicKey {
+        self.to_public_key()
+    }
+
+    fn sign(&self, pbo: &mut ReadablePbo<File>, version: BISignVersion) -> Result<BISign, Error> {
+        Ok(Self::sign(self, pbo, version)?)
+    }
+}
+
+impl Signer for External {
+    fn public_key(&self) -> BIPublicKey {
+        Self::public_key(self).clone()
+    }
+
+    fn sign(&self, pbo: &mut ReadablePbo<File>, version: BISignVersion) -> Result<BISign, Error> {
+        Self::sign(self, pbo, version)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Sign {
+    reused_key: RwLock<Option<BIPrivateKey>>,
+    /// Keys from `signing.keys`, used in addition to the main key
+    extra_keys: RwLock<Vec<BIPrivateKey>>,
+    /// A signer outside of HEMTT, used instead of the main key
+    external: RwLock<Option<Arc<External>>>,
+}
+
+impl Sign {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            reused_key: RwLock::new(None),
+            extra_keys: RwLock::new(Vec::new()),
+            external: RwLock::new(None),
+        }
+    }
+
+    /// The signer for the main key, or for an optional mod folder with `suffix`
+    ///
+    /// Uses the external signer or the reused key if configured,
+    /// otherwise generates a new key
+    fn signer(&self, ctx: &Context, suffix: Option<&str>) -> Result<Arc<dyn Signer>, Error> {
+        let external = self.external.read().expect("rwlock poisoned").clone();
+        if let Some(external) = external {
+            return Ok(external);
+        }
+        let reused_key = self.reused_key.read().expect("rwlock poisoned").clone();
+        if let Some(key) = reused_key {
+            return Ok(Arc::new(key));
+        }
+        let authority = get_authority(ctx, suffix)?;
+        Ok(Arc::new(BIPrivateKey::generate(1024, &authority)?))
+    }
+}
+
+impl Module for Sign {
+    fn name(&self) -> &'static str {
+        "Sign"
+    }
+
+    fn check(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+
+        if let Some(external) = ctx.config().signing().external() {
+            if ctx.config().signing().private_key_hash().is_some() {
+                return Err(Error::Config(
+                    "signing.external can not be used with signing.private_key_hash".to_string(),
+                ));
+            }
+            *self.external.write().expect("rwlock poisoned") =
+                Some(Arc::new(External::new(external, ctx.project_folder())?));
+        } else if let Some(hash) = ctx.config().signing().private_key_hash() {
+            let authority = get_authority(ctx, None)?;
+            *self.reused_key.write().expect("rwlock poisoned") =
+                Some(read_private_key(ctx, &authority, hash)?);
+        }
+        for key in ctx.config().signing().keys() {
+            let bi = read_private_key(ctx, key.authority(), key.private_key_hash())?;
+            self.extra_keys.write().expect("rwlock poisoned").push(bi);
+        }
+
+        ctx.addons().to_vec().iter().for_each(|addon| {
+            let entries = fs_err::read_dir(addon.folder())
+                .expect("valid read_dir")
+                .collect::<Result<Vec<_>, _>>()
+                .expect("files valid");
+            if entries.is_empty() {
+                report.push(EmptyAddon::code(addon.folder()));
+            } else if entries.len() == 1 {
+                // prefix files won't end up in the PBO, so we need to ignore them
+                if FILES.contains(
+                    &entries[0]
+                        .file_name()
+                        .into_string()
+                        .expect("valid file name")
+                        .to_lowercase()
+                        .as_str(),
+                ) {
+                    report.push(EmptyAddon::code(addon.folder()));
+                }
+            }
+        });
+
+        Ok(report)
+    }
+
+    fn pre_release(&self, ctx: &Context) -> Result<Report, Error> {
+        let addons_signer = self.signer(ctx, None)?;
+        let extra_keys = self.extra_keys.read().expect("rwlock poisoned").clone();
+        let extra_signers = extra_keys
+            .iter()
+            .map(|key| key as &dyn Signer)
+            .collect::<Vec<_>>();
+        let keys_folder = ctx
+            .build_folder()
+            .expect("build folder exists")
+            .join("keys");
+        fs_err::create_dir_all(&keys_folder)?;
+        for signer in std::iter::once(addons_signer.as_ref()).chain(extra_signers.iter().copied()) {
+            let key = signer.public_key();
+            key.write(&mut File::create(
+                keys_folder.join(format!("{}.bikey", key.authority())),
+            )?)?;
+        }
+        ctx.addons().to_vec().par_iter().try_for_each(|addon| {
+            let pbo_name = addon.pbo_name(ctx.config().prefix());
+            let (target_pbo, signer) = match addon.location() {
+                Location::Addons => (
+                    ctx.build_folder()
+                        .expect("build folder exists")
+                        .join("addons")
+                        .join(pbo_name),
+                    addons_signer.clone(),
+                ),
+                Location::Optionals => {
+                    if ctx.config().hemtt().build().optional_mod_folders() {
+                        let signer = self.signer(ctx, Some(&pbo_name))?;
+                        let mod_root = ctx
+                            .build_folder()
+                            .expect("build folder exists")
+                            .join("optionals")
+                            .join(format!(
+                                "@{}",
+                                addon.pbo_name(ctx.config().hemtt().release().folder())
+                            ));
+                        fs_err::create_dir_all(mod_root.join("keys"))?;
+                        for signer in
+                            std::iter::once(signer.as_ref()).chain(extra_signers.iter().copied())
+                        {
+                            let key = signer.public_key();
+                            key.write(&mut File::create(
+                                mod_root
+                                    .join("keys")
+                                    .join(format!("{}.bikey", key.authority())),
+                            )?)?;
+                        }
+                        (mod_root.join("addons").join(pbo_name), signer)
+                    } else {
+                        (
+                            ctx.build_folder()
+                                .expect("build folder exists")
+                                .join(addon.location().to_string())
+                                .join(pbo_name),
+                            addons_signer.clone(),
+                        )
+                    }
+                }
+            };
+            let target_pbo = target_pbo.with_extension("pbo");
+            let mut pbo = ReadablePbo::from(File::open(&target_pbo)?)?;
+            for signer in std::iter::once(signer.as_ref()).chain(extra_signers.iter().copied()) {
+                let sig = signer.sign(&mut pbo, ctx.config().signing().version())?;
+                let sig_location =
+                    target_pbo.with_extension(format!("pbo.{}.bisign", sig.authority()));
+                debug!("signing {:?}", sig_location.display());
+                sig.write(&mut File::create(sig_location)?)?;
+            }
+            Result::<(), Error>::Ok(())
+        })?;
+        Ok(Report::new())
+    }
+}
+
+/// Read and validate a HEMTT private key from the project folder
+///
+/// Exits if the key is missing, or does not belong to this project
+fn read_private_key(ctx: &Context, authority: &str, hash: &str) -> Result<BIPrivateKey, Error> {
+    let key_path = ctx
+        .project_folder()
+        .join(format!("{authority}.hemttprivatekey"));
+    if !key_path.exists() {
+        error!("Private key file `{}` does not exist", key_path.display());
+        std::process::exit(1);
+    }
+    if crate::is_ci() {
+        error!(
+            "Private key file `{}` should not be present in CI environments",
+            key_path.display()
+        );
+        std::process::exit(1);
+    }
+    let password = dialoguer::Password::new()
+        .with_prompt(format!(
+            "Enter password to decrypt private key `{authority}`"
+        ))
+        .interact()?;
+    let key = HEMTTPrivateKey::read_encrypted(&mut fs_err::File::open(&key_path)?, &password)?;
+    if key.prefix != ctx.config().prefix() {
+        error!("Private key prefix does not match the project prefix");
+        std::process::exit(1);
+    }
+    if key.project != ctx.config().name() {
+        error!("Private key project does not match the project name");
+        std::process::exit(1);
+    }
+    if key.git_hash != get_git_first_hash()? {
+        error!("Private key git hash does not match the project's first commit hash");
+        std::process::exit(1);
+    }
+    if key.validation_hash()? != hash {
+        error!("Private key validation hash does not match the expected value");
+        std::process::exit(1);
+    }
+    Ok(key.bi)
+}
+
+pub fn get_authority(ctx: &Context, suffix: Option<&str>) -> Result<String, Error> {
+    let config_authority = ctx.config().signing().authority().map_or_else(
+        || ctx.config().prefix().to_string(),
+        std::string::ToString::to_string,
+    );
+    let mut authority = if ctx.config().signing().private_key_hash().is_some() {
+        config_authority
+    } else {
+        format!(
+            "{}_{}",
+            config_authority,
+            ctx.config().version().get(ctx.workspace_path().vfs())?
+        )
+    };
+    if let Some(suffix) = suffix {
+        authority.push('_');
+        authority.push_str(suffix);
+    }
+    Ok(authority)
+}
+
+pub fn get_git_first_hash() -> Result<String, Error> {
+    let repo = git2::Repository::discover(".")?;
+    // get the hash of the first commit
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_head()?;
+    let first_commit = revwalk.last().ok_or_else(|| {
+        Error::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "repository has no commits",
+        ))
+    })??;
+    Ok(first_commit.to_string())
+}
+
+pub struct EmptyAddon {
+    file: String,
+}
+impl Code for EmptyAddon {
+    fn ident(&self) -> &'static str {
+        "BSE1"
+    }
+
+    fn message(&self) -> String {
+        format!("Addon `{}` has no files", self.file)
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("HEMTT will not be able to sign an empty PBO".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl EmptyAddon {
+    #[must_use]
+    pub fn code(file: String) -> Arc<dyn Code> {
+        Arc::new(Self { file })
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct ConversionFailed {
+    file: String,
+    reason: String,
+}
+impl Code for ConversionFailed {
+    fn ident(&self) -> &'static str {
+        "BTE1"
+    }
+
+    fn message(&self) -> String {
+        format!("Unable to convert {} to PAA: {}", self.file, self.reason)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(String::from(
+            "Textures must be a supported image format, with dimensions that are powers of two.",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl ConversionFailed {
+    pub fn code(file: String, reason: String) -> Arc<dyn Code> {
+        Arc::new(Self { file, reason })
+    }
+}
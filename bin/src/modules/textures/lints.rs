@@ -0,0 +1,76 @@
+use hemtt_paa::{
+    Paa,
+    analyze::{Texture, lint_check, lint_one},
+};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    context::Context, error::Error, modules::Module, progress::progress_bar, report::Report,
+};
+
+#[derive(Debug, Default)]
+/// Lint every PAA in the addons
+///
+/// Runs after [`super::Textures`], so converted textures are linted too
+pub struct TextureLints;
+
+impl Module for TextureLints {
+    fn name(&self) -> &'static str {
+        "TextureLints"
+    }
+
+    fn priority(&self) -> i32 {
+        5000
+    }
+
+    fn check(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        report.extend(lint_check(
+            ctx.config().lints().textures().clone(),
+            ctx.config().runtime().clone(),
+        ));
+        Ok(report)
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut paths = Vec::new();
+        for addon in ctx.addons() {
+            paths.extend(
+                ctx.workspace_path()
+                    .join(addon.folder())?
+                    .walk_dir()?
+                    .into_iter()
+                    .filter(|p| {
+                        p.extension()
+                            .is_some_and(|ext| ext.eq_ignore_ascii_case("paa"))
+                    }),
+            );
+        }
+        let length = paths.len();
+        let progress = progress_bar(length as u64).with_message("Linting Textures");
+        let codes = paths
+            .into_par_iter()
+            .map(|path| {
+                let codes = match Paa::read(path.open_file()?) {
+                    Ok(paa) => lint_one(
+                        &Texture::new(path.as_str().to_string(), paa),
+                        Some(ctx.config()),
+                    ),
+                    Err(e) => {
+                        debug!("Failed to read texture {}: {}", path, e);
+                        Vec::new()
+                    }
+                };
+                progress.inc(1);
+                Ok(codes)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        progress.finish_and_clear();
+        let mut report = Report::new();
+        for codes in codes {
+            report.extend(codes);
+        }
+        info!("Checked {} textures", length);
+        Ok(report)
+    }
+}
@@ -0,0 +1,251 @@
+//! Convert source images in addons to PAA
+//!
+//! Converted textures are cached in `.hemttout/texcache` by the hash of their
+//! source, so unchanged images are not compressed again on every build.
+
+use std::{
+    io::{Cursor, Read, Write},
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use hemtt_common::config::TextureQuality;
+use hemtt_paa::{PaXType, Paa, Quality, TextureSuffix};
+use image::GenericImageView;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use sha1::{Digest, Sha1};
+
+use crate::{context::Context, error::Error, progress::progress_bar, report::Report};
+
+use self::error::bte1_conversion_failed::ConversionFailed;
+
+use super::Module;
+
+mod error;
+mod lints;
+
+pub use lints::TextureLints;
+
+#[derive(Debug, Default)]
+pub struct Textures {
+    /// Use the `dev` quality from `addon.toml` instead of `release`
+    dev: bool,
+}
+
+impl Textures {
+    #[must_use]
+    pub const fn new(dev: bool) -> Self {
+        Self { dev }
+    }
+}
+
+impl Module for Textures {
+    fn name(&self) -> &'static str {
+        "Textures"
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut entries = Vec::new();
+        for addon in ctx.addons() {
+            let Some(config) = addon.config() else {
+                continue;
+            };
+            let globs = config
+                .textures()
+                .convert()
+                .iter()
+                .map(|g| glob::Pattern::new(g))
+                .collect::<Result<Vec<_>, _>>()?;
+            if globs.is_empty() {
+                continue;
+            }
+            let quality = quality(if self.dev {
+                config.textures().quality().dev()
+            } else {
+                config.textures().quality().release()
+            });
+            for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+                if !entry.is_file()? {
+                    continue;
+                }
+                let relative = entry
+                    .as_str()
+                    .trim_start_matches(&format!("/{}/", addon.folder()));
+                if !globs.iter().any(|g| g.matches(relative)) {
+                    continue;
+                }
+                if entry.with_extension("paa")?.exists()? {
+                    debug!("skipping {}, a paa already exists", entry);
+                    continue;
+                }
+                entries.push((entry, quality));
+            }
+        }
+        if entries.is_empty() {
+            return Ok(Report::new());
+        }
+
+        let cache = ctx.out_folder().join("texcache");
+        fs_err::create_dir_all(&cache)?;
+        let progress = progress_bar(entries.len() as u64).with_message("Converting Textures");
+        let hits = AtomicUsize::new(0);
+        let reports = entries
+            .par_iter()
+            .map(|(entry, quality)| {
+                let mut report = Report::new();
+                let mut source = Vec::new();
+                entry.open_file()?.read_to_end(&mut source)?;
+                let target = entry.with_extension("paa")?;
+                let cached = cache.join(format!(
+                    "{}.paa",
+                    cache_key(&source, &target.filename(), *quality)
+                ));
+                let paa = if cached.exists() {
+                    trace!("using cached texture for {}", entry);
+                    hits.fetch_add(1, Ordering::Relaxed);
+                    fs_err::read(&cached)?
+                } else {
+                    trace!("converting texture {}", entry);
+                    match convert(&source, Path::new(&target.filename()), *quality) {
+                        Ok(paa) => {
+                            fs_err::write(&cached, &paa)?;
+                            paa
+                        }
+                        Err(reason) => {
+                            report.push(ConversionFailed::code(entry.as_str().to_string(), reason));
+                            progress.inc(1);
+                            return Ok(report);
+                        }
+                    }
+                };
+                target.create_file()?.write_all(&paa)?;
+                progress.inc(1);
+                Ok(report)
+            })
+            .collect::<Result<Vec<Report>, Error>>()?;
+        progress.finish_and_clear();
+        let mut report = Report::new();
+        for new_report in reports {
+            report.merge(new_report);
+        }
+        info!(
+            "Converted {} textures, {} from cache",
+            entries.len(),
+            hits.load(Ordering::Relaxed)
+        );
+        Ok(report)
+    }
+}
+
+const fn quality(quality: TextureQuality) -> Quality {
+    match quality {
+        TextureQuality::Fast => Quality::Fast,
+        TextureQuality::Normal => Quality::Normal,
+        TextureQuality::Best => Quality::Best,
+    }
+}
+
+/// The cache key of a source image
+///
+/// Includes the target name, as the suffix decides how it is converted,
+/// the quality, and the HEMTT version, as the conversion may change between versions
+fn cache_key(source: &[u8], target: &str, quality: Quality) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(source);
+    hasher.update(target.to_lowercase().as_bytes());
+    hasher.update(quality.as_str().as_bytes());
+    hasher.update(env!("HEMTT_VERSION").as_bytes());
+    hemtt_pbo::Checksum::from_bytes(hasher.finalize().into()).hex()
+}
+
+/// Convert a source image to the bytes of a PAA
+///
+/// The format is chosen from the texture suffix of the target, such as `_co`,
+/// or from the image's alpha channel if there is no known suffix
+fn convert(source: &[u8], target: &Path, quality: Quality) -> Result<Vec<u8>, String> {
+    let image = image::load_from_memory(source).map_err(|e| e.to_string())?;
+    let (width, height) = image.dimensions();
+    if !width.is_power_of_two() || !height.is_power_of_two() {
+        return Err(format!("dimensions {width}x{height} are not powers of two"));
+    }
+    let paa = TextureSuffix::from_path(target)
+        .map_or_else(
+            || {
+                let format = if image.pixels().any(|p| p.2[3] < 255) {
+                    PaXType::DXT5
+                } else {
+                    PaXType::DXT1
+                };
+                Paa::from_dynamic(&image, format, quality)
+            },
+            |suffix| Paa::from_dynamic_with_suffix(&image, suffix, quality),
+        )
+        .map_err(|e| e.to_string())?;
+    let mut output = Cursor::new(Vec::new());
+    paa.write(&mut output).map_err(|e| e.to_string())?;
+    Ok(output.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{io::Cursor, path::Path};
+
+    use hemtt_paa::{PaXType, Paa, Quality};
+
+    fn png(width: u32, height: u32, alpha: u8) -> Vec<u8> {
+        let image = image::RgbaImage::from_pixel(width, height, image::Rgba([200, 100, 50, alpha]));
+        let mut output = Cursor::new(Vec::new());
+        image
+            .write_to(&mut output, image::ImageFormat::Png)
+            .unwrap();
+        output.into_inner()
+    }
+
+    #[test]
+    fn convert() {
+        let paa =
+            super::convert(&png(16, 16, 255), Path::new("rifle_co.paa"), Quality::Fast).unwrap();
+        assert_eq!(
+            Paa::read(Cursor::new(paa)).unwrap().format(),
+            &PaXType::DXT1
+        );
+        let paa =
+            super::convert(&png(16, 16, 128), Path::new("rifle_ca.paa"), Quality::Fast).unwrap();
+        assert_eq!(
+            Paa::read(Cursor::new(paa)).unwrap().format(),
+            &PaXType::DXT5
+        );
+        let paa = super::convert(
+            &png(16, 16, 255),
+            Path::new("rifle_nohq.paa"),
+            Quality::Fast,
+        )
+        .unwrap();
+        assert_eq!(
+            Paa::read(Cursor::new(paa)).unwrap().format(),
+            &PaXType::DXT5
+        );
+        assert!(
+            super::convert(&png(16, 12, 255), Path::new("rifle_co.paa"), Quality::Fast).is_err()
+        );
+    }
+
+    #[test]
+    fn cache_key() {
+        let source = png(16, 16, 255);
+        assert_eq!(
+            super::cache_key(&source, "rifle_co.paa", Quality::Best),
+            super::cache_key(&source, "Rifle_CO.paa", Quality::Best)
+        );
+        assert_ne!(
+            super::cache_key(&source, "rifle_co.paa", Quality::Best),
+            super::cache_key(&source, "rifle_nohq.paa", Quality::Best)
+        );
+        assert_ne!(
+            super::cache_key(&source, "rifle_co.paa", Quality::Best),
+            super::cache_key(&source, "rifle_co.paa", Quality::Fast)
+        );
+    }
+}
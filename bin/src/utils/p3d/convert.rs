@@ -0,0 +1,174 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use hemtt_p3d::{LOD, P3D, export};
+
+use crate::Error;
+
+#[derive(clap::Args)]
+/// Export an MLOD P3D to glTF or OBJ
+///
+/// The format is chosen from the extension of the output, `.gltf` or `.obj`.
+/// Textures referenced by the faces are converted to PNG next to the output,
+/// they are searched for relative to `--root`, dropping leading folders of the path
+/// until the file is found, so `\z\ace\addons\main\data\box_co.paa` is found as
+/// `addons\main\data\box_co.paa` in a project.
+pub struct ConvertArgs {
+    /// P3D to export
+    p3d: String,
+    /// Where to save the model, `.gltf` or `.obj`
+    output: String,
+    #[arg(long, conflicts_with = "all_lods")]
+    /// LOD to export, by resolution or name, such as `1`, `geometry` or `memory`
+    ///
+    /// Defaults to the first LOD
+    lod: Option<String>,
+    #[arg(long)]
+    /// Export every LOD
+    all_lods: bool,
+    #[arg(long, default_value = ".")]
+    /// Folder to search for textures
+    root: String,
+}
+
+/// Execute the convert command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(args: &ConvertArgs) -> Result<(), Error> {
+    let output = PathBuf::from(&args.output);
+    if output.exists() {
+        error!("Output file already exists");
+        return Ok(());
+    }
+    let extension = output
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    if extension != "gltf" && extension != "obj" {
+        error!("Output must be a `.gltf` or `.obj` file");
+        return Ok(());
+    }
+    let p3d = P3D::read(&mut fs_err::File::open(&args.p3d)?)?;
+    let Some(lods) = select_lods(&p3d, args) else {
+        error!(
+            "LOD `{}` not found, available: {}",
+            args.lod.as_deref().unwrap_or_default(),
+            p3d.lods
+                .iter()
+                .map(|lod| lod.type_name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return Ok(());
+    };
+    let meshes = lods.iter().map(|lod| lod.mesh()).collect::<Vec<_>>();
+
+    let folder = output.parent().unwrap_or_else(|| Path::new("."));
+    fs_err::create_dir_all(folder)?;
+    let stem = output
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("model")
+        .to_string();
+    let textures = convert_textures(&meshes, Path::new(&args.root), folder, &stem);
+    let texture_uri = |texture: &str| textures.get(texture).cloned();
+
+    if extension == "gltf" {
+        let buffer_uri = format!("{stem}.bin");
+        let (document, buffer) = export::to_gltf(&meshes, &buffer_uri, texture_uri);
+        fs_err::write(folder.join(buffer_uri), buffer)?;
+        fs_err::write(&output, document)?;
+    } else {
+        let mtl_uri = format!("{stem}.mtl");
+        let (obj, mtl) = export::to_obj(&meshes, &mtl_uri, texture_uri);
+        fs_err::write(folder.join(mtl_uri), mtl)?;
+        fs_err::write(&output, obj)?;
+    }
+    info!(
+        "Exported {} LOD(s) with {} texture(s) to {}",
+        meshes.len(),
+        textures.len(),
+        output.display()
+    );
+    Ok(())
+}
+
+fn select_lods<'a>(p3d: &'a P3D, args: &ConvertArgs) -> Option<Vec<&'a LOD>> {
+    if args.all_lods {
+        return Some(p3d.lods.iter().collect());
+    }
+    let Some(lod) = &args.lod else {
+        return p3d.lods.first().map(|lod| vec![lod]);
+    };
+    let resolution = lod.parse::<f32>().ok();
+    p3d.lods
+        .iter()
+        .find(|l| {
+            resolution.is_some_and(|r| (l.resolution - r).abs() < f32::EPSILON)
+                || l.type_name.eq_ignore_ascii_case(lod)
+        })
+        .map(|lod| vec![lod])
+}
+
+/// Convert the textures of the meshes to PNG, returning the URI of each converted texture
+fn convert_textures(
+    meshes: &[export::Mesh],
+    root: &Path,
+    folder: &Path,
+    stem: &str,
+) -> HashMap<String, String> {
+    let mut textures = HashMap::new();
+    let mut seen = HashSet::new();
+    for texture in meshes
+        .iter()
+        .flat_map(|mesh| &mesh.primitives)
+        .map(|primitive| &primitive.texture)
+    {
+        if texture.is_empty() || texture.starts_with('#') || !seen.insert(texture) {
+            continue;
+        }
+        let Some(path) = locate(root, texture) else {
+            warn!("Texture not found: {}", texture);
+            continue;
+        };
+        let name = Path::new(&texture.replace('\\', "/"))
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("texture")
+            .to_string();
+        let uri = format!("{stem}_{name}_{}.png", textures.len());
+        if let Err(e) = to_png(&path, &folder.join(&uri)) {
+            warn!("Failed to convert texture {}: {}", path.display(), e);
+            continue;
+        }
+        textures.insert(texture.clone(), uri);
+    }
+    textures
+}
+
+fn to_png(paa: &Path, png: &Path) -> Result<(), Error> {
+    let paa = hemtt_paa::Paa::read(fs_err::File::open(paa)?)?;
+    let Some((mipmap, _)) = paa.maps().first() else {
+        return Err(
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "PAA has no mipmaps").into(),
+        );
+    };
+    mipmap.get_image()?.save(png)?;
+    Ok(())
+}
+
+/// Find a texture relative to the root, dropping leading folders until it exists
+fn locate(root: &Path, texture: &str) -> Option<PathBuf> {
+    let parts = texture
+        .split(['\\', '/'])
+        .filter(|p| !p.is_empty())
+        .collect::<Vec<_>>();
+    (0..parts.len())
+        .map(|skip| parts[skip..].iter().collect::<PathBuf>())
+        .map(|relative| root.join(relative))
+        .find(|path| path.is_file())
+}
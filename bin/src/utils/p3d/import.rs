@@ -0,0 +1,152 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use hemtt_p3d::{LOD, P3D, import};
+
+use crate::Error;
+
+/// MLOD version written by Object Builder
+const MLOD_VERSION: u32 = 257;
+
+#[derive(clap::Args)]
+/// Import a glTF or OBJ mesh into an MLOD P3D
+///
+/// The format is chosen from the extension of the input, `.gltf`, `.glb` or `.obj`.
+/// Each object, or node with a mesh, becomes a named selection.
+/// Models exported by `hemtt utils p3d convert` keep their textures and rvmats,
+/// other materials can be mapped with `--material`.
+pub struct ImportArgs {
+    /// Mesh to import, `.gltf`, `.glb` or `.obj`
+    mesh: String,
+    /// Where to save the P3D
+    output: String,
+    #[arg(long, default_value = "1")]
+    /// LOD to create, by resolution or name, such as `1`, `geometry` or `memory`
+    lod: String,
+    #[arg(long)]
+    /// Map a material to a texture and rvmat, `name=texture.paa` or `name=texture.paa,material.rvmat`
+    material: Vec<String>,
+    #[arg(long)]
+    /// Add a Geometry LOD from the convex hull of the mesh, with this mass in kilograms
+    mass: Option<f32>,
+}
+
+/// Execute the import command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(args: &ImportArgs) -> Result<(), Error> {
+    let output = PathBuf::from(&args.output);
+    if output.exists() {
+        error!("Output file already exists");
+        return Ok(());
+    }
+    let Some(resolution) = args
+        .lod
+        .parse::<f32>()
+        .ok()
+        .or_else(|| LOD::resolution_from_name(&args.lod))
+    else {
+        error!("Unknown LOD `{}`", args.lod);
+        return Ok(());
+    };
+    let Some(materials) = parse_materials(&args.material) else {
+        return Ok(());
+    };
+    let input = Path::new(&args.mesh);
+    let folder = input.parent().unwrap_or_else(|| Path::new("."));
+    let extension = input
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    let scene = match extension.as_str() {
+        "gltf" | "glb" => import::from_gltf(&fs_err::read(input)?, |uri| {
+            fs_err::read(folder.join(uri)).ok()
+        })?,
+        "obj" => import::from_obj(&fs_err::read_to_string(input)?, |uri| {
+            fs_err::read_to_string(folder.join(uri)).ok()
+        })?,
+        _ => {
+            error!("Input must be a `.gltf`, `.glb` or `.obj` file");
+            return Ok(());
+        }
+    };
+
+    let lod = scene.to_lod(resolution, &materials)?;
+    if args.mass.is_some() && lod.type_name == "Geometry" {
+        error!("`--mass` creates a Geometry LOD, it can not be used when importing into one");
+        return Ok(());
+    }
+    let mut unmapped = scene
+        .objects
+        .iter()
+        .flat_map(|object| &object.triangles)
+        .map(|triangle| triangle.material.as_str())
+        .filter(|m| {
+            !m.is_empty() && !materials.contains_key(*m) && !scene.materials.contains_key(*m)
+        })
+        .collect::<Vec<_>>();
+    unmapped.sort_unstable();
+    unmapped.dedup();
+    for material in unmapped {
+        warn!(
+            "Material `{}` has no texture, map it with `--material`",
+            material
+        );
+    }
+    info!(
+        "Imported {} faces and {} points into the {} LOD",
+        lod.faces.len(),
+        lod.points.len(),
+        lod.type_name
+    );
+    let mut lods = Vec::with_capacity(2);
+    if let Some(mass) = args.mass {
+        let geometry = lod.convex_geometry(mass);
+        lods.push(lod);
+        if let Some(geometry) = geometry {
+            info!("Created a Geometry LOD with {} faces", geometry.faces.len());
+            lods.push(geometry);
+        } else {
+            warn!("The mesh is flat, no Geometry LOD was created");
+        }
+    } else {
+        lods.push(lod);
+    }
+
+    if let Some(parent) = output.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+    P3D {
+        version: MLOD_VERSION,
+        lods,
+    }
+    .write(&mut fs_err::File::create(&output)?)?;
+    Ok(())
+}
+
+/// Parse `name=texture[,rvmat]` mappings, reporting invalid ones
+fn parse_materials(mappings: &[String]) -> Option<HashMap<String, import::MaterialPaths>> {
+    let mut materials = HashMap::new();
+    for mapping in mappings {
+        let Some((name, paths)) = mapping.split_once('=') else {
+            error!(
+                "Invalid material mapping `{}`, expected `name=texture[,rvmat]`",
+                mapping
+            );
+            return None;
+        };
+        let (texture, material) = paths.split_once(',').unwrap_or((paths, ""));
+        materials.insert(
+            name.to_string(),
+            import::MaterialPaths {
+                texture: texture.to_string(),
+                material: material.to_string(),
+            },
+        );
+    }
+    Some(materials)
+}
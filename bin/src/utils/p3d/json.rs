@@ -21,7 +21,7 @@ pub fn execute(args: &JsonArgs) -> Result<(), Error> {
         error!("Output file already exists");
         return Ok(());
     }
-    let p3d = hemtt_p3d::P3D::read(&mut fs_err::File::open(p3d)?).expect("Failed to read P3D");
+    let p3d = hemtt_p3d::Model::read(&mut fs_err::File::open(p3d)?)?;
     let _ = fs_err::create_dir_all(
         output
             .parent()
@@ -1,5 +1,7 @@
 use crate::Error;
 
+mod convert;
+mod import;
 mod json;
 
 #[derive(clap::Parser)]
@@ -12,7 +14,20 @@ pub struct Command {
 
 #[derive(clap::Subcommand)]
 enum Subcommands {
+    /// Export P3D model to glTF or OBJ
+    ///
+    /// Useful for reviewing models without Object Builder,
+    /// the result can be opened in any glTF viewer or 3D editor.
+    Convert(convert::ConvertArgs),
+    /// Import glTF or OBJ mesh into an MLOD P3D
+    ///
+    /// Creates a single LOD, optionally with a convex Geometry LOD,
+    /// ready to be finished in Object Builder.
+    Import(import::ImportArgs),
     /// Export P3D model to JSON
+    ///
+    /// Both MLOD and binarized ODOL models can be exported.
+    /// Named properties, selections, masses and UV sets of MLOD LODs are decoded.
     Json(json::JsonArgs),
 }
 
@@ -25,6 +40,8 @@ enum Subcommands {
 /// If the args are not present from clap
 pub fn execute(cmd: &Command) -> Result<(), Error> {
     match &cmd.commands {
+        Subcommands::Convert(args) => convert::execute(args),
+        Subcommands::Import(args) => import::execute(args),
         Subcommands::Json(args) => json::execute(args),
     }
 }
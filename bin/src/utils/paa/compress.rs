@@ -1,6 +1,8 @@
 use std::io::{Cursor, Write};
 use std::path::{Path, PathBuf};
 
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
 use crate::Error;
 use crate::utils::bytes_to_human_readable;
 
@@ -20,6 +22,10 @@ pub struct Command {
     /// Only show what would be compressed, don't actually compress
     #[arg(short, long)]
     dry_run: bool,
+
+    /// DXT compression quality: fast, normal, or best
+    #[arg(long, default_value = "best")]
+    quality: hemtt_paa::Quality,
 }
 
 #[derive(Default, Debug)]
@@ -92,7 +98,7 @@ pub fn execute(cmd: &Command) -> Result<(), Error> {
 
     // Process each file
     for file_path in files {
-        if let Err(e) = process_paa_file(&file_path, cmd.dry_run, &mut stats) {
+        if let Err(e) = process_paa_file(&file_path, cmd, &mut stats) {
             warn!("Failed to process {}: {}", file_path.display(), e);
         }
     }
@@ -130,7 +136,7 @@ pub fn execute(cmd: &Command) -> Result<(), Error> {
 
 fn process_paa_file(
     file_path: &Path,
-    dry_run: bool,
+    cmd: &Command,
     stats: &mut CompressionStats,
 ) -> Result<(), Error> {
     stats.total_files += 1;
@@ -155,16 +161,32 @@ fn process_paa_file(
         }
     }
 
+    // Recompress the uncompressed DXT mipmaps in parallel
+    let recompressed = original_paa
+        .maps()
+        .par_iter()
+        .map(|(mipmap, _)| {
+            (!mipmap.is_compressed() && mipmap.format().is_dxt()).then(|| {
+                mipmap.get_image().and_then(|image| {
+                    hemtt_paa::MipMap::from_rgba_image(
+                        &image.to_rgba8(),
+                        *mipmap.format(),
+                        cmd.quality,
+                    )
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
     // Process each mipmap
-    for (mipmap, _) in original_paa.maps() {
+    for ((mipmap, _), recompressed) in original_paa.maps().iter().zip(recompressed) {
         let width = mipmap.width();
         let height = mipmap.height();
         let format = *mipmap.format();
-        let is_already_compressed = mipmap.is_compressed();
 
         stats.total_mipmaps += 1;
 
-        if !is_already_compressed && format.is_dxt() {
+        if let Some(recompressed) = recompressed {
             debug!(
                 "  Trying to compress mipmap: {}x{} (format: {:?})",
                 width, height, format
@@ -175,8 +197,7 @@ fn process_paa_file(
             mipmap.write(&mut original_buffer)?;
             let original_mipmap_size = original_buffer.get_ref().len();
 
-            // Try recompressing
-            match hemtt_paa::MipMap::from_rgba_image(&mipmap.get_image().to_rgba8(), format) {
+            match recompressed {
                 Ok(new_mipmap) => {
                     // Get compressed mipmap size when serialized
                     let mut compressed_buffer = Cursor::new(Vec::new());
@@ -216,7 +237,7 @@ fn process_paa_file(
     }
 
     // If we compressed something and not in dry run mode, write it back
-    if mipmaps_compressed > 0 && !dry_run {
+    if mipmaps_compressed > 0 && !cmd.dry_run {
         debug!("  Writing compressed PAA: {}", file_path.display());
         let mut output = fs_err::File::create(file_path)?;
         new_paa.write(&mut output)?;
@@ -235,7 +256,7 @@ fn process_paa_file(
                 bytes_to_human_readable(saved)
             );
         }
-    } else if mipmaps_compressed > 0 && dry_run {
+    } else if mipmaps_compressed > 0 && cmd.dry_run {
         println!(
             "  [DRY RUN] Would compress {} mipmap(s) in: {}",
             mipmaps_compressed,
@@ -6,11 +6,17 @@ use crate::Error;
 
 #[derive(clap::Args)]
 /// Convert images to and from PAA format
+///
+/// When converting to PAA, the format, mipmaps and TAGGs are chosen
+/// from the texture suffix of the destination, such as `_co` or `_nohq`
 pub struct PaaConvertArgs {
     /// Source file (PAA or image)
     src: String,
     /// Destination file (PAA or image)
     dest: String,
+    #[arg(long, default_value = "best")]
+    /// DXT compression quality: fast, normal, or best
+    quality: hemtt_paa::Quality,
 }
 
 /// Execute the convert command
@@ -33,13 +39,21 @@ pub fn execute(args: &PaaConvertArgs) -> Result<(), Error> {
             .as_str(),
     ) {
         let paa = hemtt_paa::Paa::read(fs_err::File::open(from)?)?;
-        if let Err(e) = paa.maps()[0].0.get_image().save(output) {
+        let image = match paa.maps()[0].0.get_image() {
+            Ok(image) => image,
+            Err(e) => {
+                error!("Failed to decode PAA: {}", e);
+                return Ok(());
+            }
+        };
+        if let Err(e) = image.save(output) {
             error!("Failed to save image: {}", e);
         } else {
             info!("PAA converted");
         }
     } else {
         let image = image::open(from)?;
+        let suffix = hemtt_paa::TextureSuffix::from_path(&output);
         let (width, height) = image.dimensions();
         let format = if !height.is_power_of_two() || !width.is_power_of_two() {
             warn!(
@@ -56,6 +70,15 @@ pub fn execute(args: &PaaConvertArgs) -> Result<(), Error> {
                 return Ok(());
             }
             hemtt_paa::PaXType::ARGB8
+        } else if let Some(suffix) = suffix {
+            let paa = hemtt_paa::Paa::from_dynamic_with_suffix(&image, suffix, args.quality)?;
+            let mut file = fs_err::File::create(output)?;
+            paa.write(&mut file)?;
+            info!(
+                "Image converted to {} PAA for {suffix} texture",
+                paa.format().to_string()
+            );
+            return Ok(());
         } else {
             let has_transparency = image.pixels().any(|p| p.2[3] < 255);
             if has_transparency {
@@ -64,7 +87,7 @@ pub fn execute(args: &PaaConvertArgs) -> Result<(), Error> {
                 hemtt_paa::PaXType::DXT1
             }
         };
-        let paa = hemtt_paa::Paa::from_dynamic(&image, format)?;
+        let paa = hemtt_paa::Paa::from_dynamic(&image, format, args.quality)?;
         let mut file = fs_err::File::create(output)?;
         paa.write(&mut file)?;
         info!("Image converted to {} PAA", format.to_string());
@@ -6,6 +6,7 @@ mod compress;
 mod convert;
 mod cxam_fix;
 mod inspect;
+mod tags;
 
 pub use inspect::inspect;
 
@@ -35,6 +36,11 @@ enum Subcommands {
     Convert(convert::PaaConvertArgs),
     /// Inspect a PAA file
     Inspect(inspect::PaaInspectArgs),
+    /// List or edit the TAGGs of a PAA
+    ///
+    /// TAGGs hold metadata used by the engine, such as the average color,
+    /// the alpha flag and the channel swizzle.
+    Tags(tags::PaaTagsArgs),
     /// Fix PAAs with incorrect CXAM (color ambient max) tagg values
     CxamFix(cxam_fix::Command),
 }
@@ -53,6 +59,7 @@ pub fn execute(cmd: &Command) -> Result<(), Error> {
         Subcommands::Inspect(args) => {
             inspect::inspect(File::open(PathBuf::from(&args.paa))?, &args.format)
         }
+        Subcommands::Tags(args) => tags::execute(args),
         Subcommands::CxamFix(args) => cxam_fix::execute(args),
     }
 }
@@ -0,0 +1,102 @@
+use std::io::{Seek, SeekFrom};
+
+use hemtt_paa::{Paa, Tagg, key_from_name};
+use serde::Serialize;
+use tabled::{
+    Table, Tabled,
+    settings::{Alignment, Style},
+};
+
+use crate::{Error, TableFormat};
+
+#[derive(clap::Args)]
+/// List or edit the TAGGs of a PAA
+///
+/// Values use the same format as the listing:
+/// colors as `#rrggbbaa`, the alpha flag as `interpolated` or `not-interpolated`,
+/// and swizzles as four sources for A,R,G,B, such as `1-A,R,G,B`.
+pub struct PaaTagsArgs {
+    /// PAA to list or edit
+    pub(crate) paa: String,
+    #[arg(long = "set", value_name = "NAME=VALUE")]
+    /// Set a TAGG, such as `--set avgc=#808080ff` or `--set flag=interpolated`
+    pub(crate) set: Vec<String>,
+    #[arg(long = "remove", value_name = "NAME")]
+    /// Remove a TAGG, such as `--remove swiz`
+    pub(crate) remove: Vec<String>,
+    #[clap(long, default_value = "ascii")]
+    /// Output format
+    pub(crate) format: TableFormat,
+}
+
+#[derive(Tabled, Serialize)]
+pub struct TaggInfo {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Value")]
+    value: String,
+}
+
+/// Execute the tags command
+///
+/// # Errors
+/// [`Error::Io`] if the file is not a valid [`hemtt_paa::Paa`], or a TAGG is invalid
+pub fn execute(args: &PaaTagsArgs) -> Result<(), Error> {
+    let edit = !args.set.is_empty() || !args.remove.is_empty();
+    let mut file = fs_err::OpenOptions::new()
+        .read(true)
+        .write(edit)
+        .open(&args.paa)?;
+    let mut paa = Paa::read(&mut file)?;
+
+    if edit {
+        for name in &args.remove {
+            if !paa.remove_tagg(&key_from_name(name)?) {
+                warn!("{} has no {} tagg", args.paa, name);
+            }
+        }
+        for set in &args.set {
+            let Some((name, value)) = set.split_once('=') else {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("invalid `--set {set}`, expected NAME=VALUE"),
+                )
+                .into());
+            };
+            paa.set_tagg(&Tagg::parse(name.trim(), value.trim())?);
+        }
+        file.seek(SeekFrom::Start(0))?;
+        paa.write(&mut file)?;
+        let length = file.stream_position()?;
+        file.set_len(length)?;
+        info!("Updated taggs in {}", args.paa);
+    }
+
+    let data = paa
+        .typed_taggs()?
+        .into_iter()
+        .map(|tagg| TaggInfo {
+            name: tagg.name(),
+            value: tagg.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    match args.format {
+        TableFormat::Ascii => println!(
+            "{}",
+            Table::new(data)
+                .with(Style::modern())
+                .with(Alignment::left())
+        ),
+        TableFormat::Json => println!("{}", serde_json::to_string(&data)?),
+        TableFormat::PrettyJson => println!("{}", serde_json::to_string_pretty(&data)?),
+        TableFormat::Markdown => println!(
+            "{}",
+            Table::new(data)
+                .with(Style::markdown())
+                .with(Alignment::left())
+        ),
+    }
+
+    Ok(())
+}
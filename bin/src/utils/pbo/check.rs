@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{Read, Seek},
+    path::{Path, PathBuf},
+};
+
+use hemtt_common::prefix::Prefix;
+use hemtt_pbo::ReadablePbo;
+
+use crate::{Error, report::Report};
+
+use super::error::{
+    bupe1_unreadable::Unreadable, bupe2_checksum_mismatch::ChecksumMismatch,
+    bupe3_unsorted::Unsorted, bupe4_duplicate_entry::DuplicateEntry,
+    bupe5_missing_prefix::MissingPrefix, bupe6_trailing_data::TrailingData,
+    bupe7_decompression_failed::DecompressionFailed, bupw1_empty_entry::EmptyEntry,
+    bupw2_odd_prefix::OddPrefix,
+};
+
+#[derive(clap::Args)]
+pub struct PboCheckArgs {
+    /// PBO, or folder of PBOs, to check
+    ///
+    /// Folders are searched recursively, so an `@mod` folder can be checked at once.
+    path: String,
+}
+
+/// Execute the check command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(args: &PboCheckArgs) -> Result<Report, Error> {
+    let path = PathBuf::from(&args.path);
+    let pbos = if path.is_dir() {
+        let mut pbos = Vec::new();
+        for entry in walkdir::WalkDir::new(&path) {
+            let entry = entry?;
+            if entry.file_type().is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("pbo"))
+            {
+                pbos.push(entry.into_path());
+            }
+        }
+        pbos.sort();
+        pbos
+    } else {
+        vec![path]
+    };
+    let mut report = Report::new();
+    for pbo in &pbos {
+        report.merge(check_file(pbo)?);
+    }
+    info!("Checked {} PBOs", pbos.len());
+    Ok(report)
+}
+
+/// Check a PBO file for structural problems
+///
+/// # Errors
+/// [`Error::Io`] if the file can not be opened
+pub fn check_file(path: &Path) -> Result<Report, Error> {
+    let name = path.display().to_string();
+    let mut report = Report::new();
+    let pbo = match ReadablePbo::from_lenient(File::open(path)?) {
+        Ok(pbo) => pbo,
+        Err(e) => {
+            report.push(Unreadable::code(name, e.to_string()));
+            return Ok(report);
+        }
+    };
+    report.merge(check(&name, pbo)?);
+    Ok(report)
+}
+
+/// Check a PBO for structural problems
+///
+/// # Errors
+/// [`Error::Pbo`] if the PBO can not be read
+pub fn check<I: Read + Seek>(name: &str, mut pbo: ReadablePbo<I>) -> Result<Report, Error> {
+    let mut report = Report::new();
+
+    if pbo.trailing_data() > 0 {
+        report.push(TrailingData::code(name.to_string(), pbo.trailing_data()));
+    }
+
+    match pbo.properties().get("prefix") {
+        // missions are loaded by their file name, not a prefix
+        None if pbo.header("mission.sqm").is_some() => {}
+        None => report.push(MissingPrefix::code(name.to_string())),
+        Some(prefix) => {
+            if let Some(reason) = odd_prefix(prefix) {
+                report.push(OddPrefix::code(name.to_string(), prefix.clone(), reason));
+            }
+        }
+    }
+
+    let stored = *pbo.checksum();
+    let actual = pbo.gen_checksum()?;
+    if stored != actual {
+        report.push(ChecksumMismatch::code(
+            name.to_string(),
+            stored.hex(),
+            actual.hex(),
+        ));
+    }
+
+    if pbo.is_sorted().is_err() {
+        report.push(Unsorted::code(name.to_string()));
+    }
+
+    let mut seen: HashMap<String, Vec<String>> = HashMap::new();
+    for header in pbo.files() {
+        seen.entry(header.filename().replace('/', "\\").to_lowercase())
+            .or_default()
+            .push(header.filename().to_string());
+    }
+    let mut duplicates = seen
+        .into_values()
+        .filter(|names| names.len() > 1)
+        .collect::<Vec<_>>();
+    duplicates.sort();
+    for names in duplicates {
+        report.push(DuplicateEntry::code(name.to_string(), names));
+    }
+
+    for header in pbo.files() {
+        if header.size() == 0 {
+            report.push(EmptyEntry::code(
+                name.to_string(),
+                header.filename().to_string(),
+            ));
+            continue;
+        }
+        if header.mime().is_compressed()
+            && let Err(e) = pbo.file_checked(header.filename())
+        {
+            report.push(DecompressionFailed::code(
+                name.to_string(),
+                header.filename().to_string(),
+                e.to_string(),
+            ));
+        }
+    }
+
+    Ok(report)
+}
+
+/// Find the reason a prefix is unusual, if it is
+fn odd_prefix(prefix: &str) -> Option<String> {
+    if prefix.trim() != prefix {
+        return Some("The prefix has leading or trailing whitespace".to_string());
+    }
+    if prefix.ends_with('\\') {
+        return Some("The prefix should not end with `\\`".to_string());
+    }
+    if let Err(e) = Prefix::new(prefix) {
+        return Some(e.to_string());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::Cursor;
+
+    use crate::report::WithIncludes;
+    use hemtt_pbo::{ReadablePbo, WritablePbo};
+
+    /// Check a PBO, returning the error and warning codes
+    fn codes(prefix: Option<&str>, trailing: &[u8]) -> (Vec<&'static str>, Vec<&'static str>) {
+        let mut pbo = WritablePbo::new();
+        if let Some(prefix) = prefix {
+            pbo.add_property("prefix", prefix);
+        }
+        pbo.add_file("script.sqf", Cursor::new(b"hint 'a';".to_vec()))
+            .unwrap();
+        pbo.add_file("empty.sqf", Cursor::new(Vec::new())).unwrap();
+        let mut output = Cursor::new(Vec::new());
+        pbo.write(&mut output, true).unwrap();
+        output.get_mut().extend_from_slice(trailing);
+        output.set_position(0);
+        let report = super::check("test.pbo", ReadablePbo::from_lenient(output).unwrap()).unwrap();
+        (
+            report.errors().iter().map(|c| c.ident()).collect(),
+            report
+                .warnings(WithIncludes::Yes)
+                .iter()
+                .map(|c| c.ident())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn valid() {
+        let (errors, warnings) = codes(Some("z\\test\\addons\\main"), &[]);
+        assert!(errors.is_empty());
+        assert_eq!(warnings, vec!["BUPW1"]);
+    }
+
+    #[test]
+    fn problems() {
+        let (errors, _) = codes(None, b"extra");
+        assert_eq!(errors, vec!["BUPE6", "BUPE5"]);
+        let (errors, warnings) = codes(Some("z/test/addons/main"), &[]);
+        assert!(errors.is_empty());
+        assert_eq!(warnings, vec!["BUPW2", "BUPW1"]);
+    }
+}
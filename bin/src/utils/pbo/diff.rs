@@ -0,0 +1,375 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{Cursor, Read, Seek},
+};
+
+use hemtt_config::{Class, Config, Property, rapify::Derapify};
+use hemtt_pbo::{Checksum, ReadablePbo};
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+
+use crate::{Error, TableFormat};
+
+#[derive(clap::Args)]
+pub struct PboDiffArgs {
+    /// The original PBO
+    old: String,
+    /// The PBO to compare against
+    new: String,
+    #[clap(long, default_value = "ascii")]
+    /// Output format
+    format: TableFormat,
+}
+
+#[derive(Debug, Default, Serialize, PartialEq, Eq)]
+pub struct PboDiff {
+    properties: Vec<Change>,
+    added: Vec<String>,
+    removed: Vec<String>,
+    changed: Vec<FileChange>,
+}
+
+impl PboDiff {
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+    }
+
+    #[must_use]
+    /// Names of every property and file that differs
+    pub fn entries(&self) -> Vec<String> {
+        self.properties
+            .iter()
+            .map(|c| format!("property {}", c.key))
+            .chain(self.added.iter().cloned())
+            .chain(self.removed.iter().cloned())
+            .chain(self.changed.iter().map(|c| c.filename.clone()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+/// A property or config value that was added, removed or changed
+pub struct Change {
+    key: String,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct FileChange {
+    filename: String,
+    old_hash: String,
+    new_hash: String,
+    /// Class and property level changes, if both sides are rapified configs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: Option<Vec<Change>>,
+}
+
+/// Execute the diff command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(args: &PboDiffArgs) -> Result<(), Error> {
+    let mut old = ReadablePbo::from(File::open(&args.old)?)?;
+    let mut new = ReadablePbo::from(File::open(&args.new)?)?;
+    let diff = diff(&mut old, &mut new)?;
+    match args.format {
+        TableFormat::Json => println!("{}", serde_json::to_string(&diff)?),
+        TableFormat::PrettyJson => println!("{}", serde_json::to_string_pretty(&diff)?),
+        TableFormat::Ascii | TableFormat::Markdown => print(&diff),
+    }
+    Ok(())
+}
+
+/// Compare two PBOs
+///
+/// Files are compared by the SHA1 of their contents,
+/// rapified files are derapified and compared by class and property
+///
+/// # Errors
+/// [`Error`] if a PBO can not be read
+pub fn diff<A: Read + Seek, B: Read + Seek>(
+    old: &mut ReadablePbo<A>,
+    new: &mut ReadablePbo<B>,
+) -> Result<PboDiff, Error> {
+    let mut diff = PboDiff {
+        properties: changes(
+            &old.properties()
+                .iter()
+                .map(|(k, v)| (k.to_lowercase(), (k.clone(), v.clone())))
+                .collect(),
+            &new.properties()
+                .iter()
+                .map(|(k, v)| (k.to_lowercase(), (k.clone(), v.clone())))
+                .collect(),
+        ),
+        ..Default::default()
+    };
+
+    let old_files = old
+        .files()
+        .iter()
+        .map(|h| h.filename().to_string())
+        .collect::<Vec<_>>();
+    let new_files = new
+        .files()
+        .iter()
+        .map(|h| h.filename().to_string())
+        .collect::<Vec<_>>();
+    for name in &old_files {
+        if new.header(name).is_none() {
+            diff.removed.push(name.clone());
+        }
+    }
+    for name in &new_files {
+        if old.header(name).is_none() {
+            diff.added.push(name.clone());
+            continue;
+        }
+        let old_data = read(old, name)?;
+        let new_data = read(new, name)?;
+        let old_hash = hash(&old_data);
+        let new_hash = hash(&new_data);
+        if old_hash == new_hash {
+            continue;
+        }
+        let config = if old_data.starts_with(b"\0raP") && new_data.starts_with(b"\0raP") {
+            Some(changes(
+                &flatten(&Config::derapify(&mut Cursor::new(old_data))?),
+                &flatten(&Config::derapify(&mut Cursor::new(new_data))?),
+            ))
+        } else {
+            None
+        };
+        diff.changed.push(FileChange {
+            filename: name.clone(),
+            old_hash,
+            new_hash,
+            config,
+        });
+    }
+    Ok(diff)
+}
+
+fn print(diff: &PboDiff) {
+    if diff.is_empty() {
+        println!("No differences");
+        return;
+    }
+    if !diff.properties.is_empty() {
+        println!("Properties");
+        for change in &diff.properties {
+            print_change(change, "  ");
+        }
+    }
+    if !diff.added.is_empty() || !diff.removed.is_empty() || !diff.changed.is_empty() {
+        println!("Files");
+    }
+    for name in &diff.added {
+        println!("  + {name}");
+    }
+    for name in &diff.removed {
+        println!("  - {name}");
+    }
+    for change in &diff.changed {
+        println!("  ~ {}", change.filename);
+        if let Some(config) = &change.config {
+            if config.is_empty() {
+                println!("      no class or property differences");
+            }
+            for change in config {
+                print_change(change, "      ");
+            }
+        }
+    }
+}
+
+fn print_change(change: &Change, indent: &str) {
+    match (&change.old, &change.new) {
+        (None, Some(new)) => println!("{indent}+ {}: {new}", change.key),
+        (Some(old), None) => println!("{indent}- {}: {old}", change.key),
+        (Some(old), Some(new)) => println!("{indent}~ {}: {old} -> {new}", change.key),
+        (None, None) => {}
+    }
+}
+
+fn read<I: Read + Seek>(pbo: &mut ReadablePbo<I>, name: &str) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::new();
+    if let Some(mut file) = pbo.file(name)? {
+        file.read_to_end(&mut data)?;
+    }
+    Ok(data)
+}
+
+fn hash(data: &[u8]) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(data);
+    Checksum::from_bytes(hasher.finalize().into()).hex()
+}
+
+/// Compare two maps of lowercase key => (display key, value)
+fn changes(
+    old: &BTreeMap<String, (String, String)>,
+    new: &BTreeMap<String, (String, String)>,
+) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for (lower, (key, value)) in old {
+        match new.get(lower) {
+            None => changes.push(Change {
+                key: key.clone(),
+                old: Some(value.clone()),
+                new: None,
+            }),
+            Some((_, new_value)) if new_value != value => changes.push(Change {
+                key: key.clone(),
+                old: Some(value.clone()),
+                new: Some(new_value.clone()),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (lower, (key, value)) in new {
+        if !old.contains_key(lower) {
+            changes.push(Change {
+                key: key.clone(),
+                old: None,
+                new: Some(value.clone()),
+            });
+        }
+    }
+    changes.sort_by_key(|c| c.key.to_lowercase());
+    changes
+}
+
+/// Flatten a config into paths like `CfgPatches/my_addon/units`
+///
+/// Classes are included with their parent, so inheritance changes are reported
+fn flatten(config: &Config) -> BTreeMap<String, (String, String)> {
+    fn walk(properties: &[Property], path: &str, out: &mut BTreeMap<String, (String, String)>) {
+        for property in properties {
+            let key = format!("{path}{}", property.name().as_str());
+            let value = match property {
+                Property::Entry { value, .. } => value.to_string(),
+                Property::Class(Class::Local {
+                    parent, properties, ..
+                }) => {
+                    walk(properties, &format!("{key}/"), out);
+                    parent.as_ref().map_or_else(
+                        || "class".to_string(),
+                        |parent| format!("class : {}", parent.as_str()),
+                    )
+                }
+                Property::Class(_) => "class;".to_string(),
+                Property::Delete(_) => "delete".to_string(),
+                Property::MissingSemicolon(..) | Property::ExtraSemicolon(..) => continue,
+            };
+            out.insert(key.to_lowercase(), (key, value));
+        }
+    }
+    let mut out = BTreeMap::new();
+    walk(&config.0, "", &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::Cursor;
+
+    use hemtt_config::{Class, Config, Ident, Number, Property, Value, rapify::Rapify};
+    use hemtt_pbo::{ReadablePbo, WritablePbo};
+
+    fn pbo(version: &str, files: &[(&str, Vec<u8>)]) -> ReadablePbo<Cursor<Vec<u8>>> {
+        let mut pbo = WritablePbo::new();
+        pbo.add_property("prefix", "z\\test\\addons\\main");
+        pbo.add_property("version", version);
+        for (name, data) in files {
+            pbo.add_file(*name, Cursor::new(data.clone())).unwrap();
+        }
+        let mut output = Cursor::new(Vec::new());
+        pbo.write(&mut output, true).unwrap();
+        output.set_position(0);
+        ReadablePbo::from(output).unwrap()
+    }
+
+    fn config(entries: &[(&str, i32)]) -> Vec<u8> {
+        let config = Config(vec![Property::Class(Class::Local {
+            name: Ident::new("CfgTest".to_string(), 0..0),
+            parent: None,
+            properties: entries
+                .iter()
+                .map(|(name, value)| Property::Entry {
+                    name: Ident::new((*name).to_string(), 0..0),
+                    value: Value::Number(Number::Int32 {
+                        value: *value,
+                        span: 0..0,
+                    }),
+                    expected_array: false,
+                })
+                .collect(),
+            err_missing_braces: false,
+        })]);
+        let mut out = Vec::new();
+        config.rapify(&mut out, 0).unwrap();
+        out
+    }
+
+    #[test]
+    fn identical() {
+        let files = [("script.sqf", b"hint 'a';".to_vec())];
+        let diff = super::diff(&mut pbo("1.0", &files), &mut pbo("1.0", &files)).unwrap();
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn changes() {
+        let diff = super::diff(
+            &mut pbo(
+                "1.0",
+                &[
+                    ("removed.sqf", b"hint 'a';".to_vec()),
+                    ("script.sqf", b"hint 'a';".to_vec()),
+                    ("config.bin", config(&[("a", 1), ("b", 2)])),
+                ],
+            ),
+            &mut pbo(
+                "1.1",
+                &[
+                    ("added.sqf", b"hint 'a';".to_vec()),
+                    ("script.sqf", b"hint 'b';".to_vec()),
+                    ("config.bin", config(&[("a", 1), ("b", 3), ("c", 4)])),
+                ],
+            ),
+        )
+        .unwrap();
+        assert_eq!(diff.properties.len(), 1);
+        assert_eq!(diff.properties[0].key, "version");
+        assert_eq!(diff.added, vec!["added.sqf"]);
+        assert_eq!(diff.removed, vec!["removed.sqf"]);
+        assert_eq!(diff.changed.len(), 2);
+        let config = diff
+            .changed
+            .iter()
+            .find(|c| c.filename == "config.bin")
+            .unwrap();
+        let changes = config.config.as_ref().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].key, "CfgTest/b");
+        assert_eq!(changes[0].old.as_deref(), Some("2"));
+        assert_eq!(changes[0].new.as_deref(), Some("3"));
+        assert_eq!(changes[1].key, "CfgTest/c");
+        assert!(changes[1].old.is_none());
+        let script = diff
+            .changed
+            .iter()
+            .find(|c| c.filename == "script.sqf")
+            .unwrap();
+        assert!(script.config.is_none());
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct Unreadable {
+    pbo: String,
+    error: String,
+}
+
+impl Code for Unreadable {
+    fn ident(&self) -> &'static str {
+        "BUPE1"
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` could not be read: {}", self.pbo, self.error)
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl Unreadable {
+    pub fn code(pbo: String, error: String) -> Arc<dyn Code> {
+        Arc::new(Self { pbo, error })
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct ChecksumMismatch {
+    pbo: String,
+    stored: String,
+    actual: String,
+}
+
+impl Code for ChecksumMismatch {
+    fn ident(&self) -> &'static str {
+        "BUPE2"
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` has an invalid checksum", self.pbo)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "stored `{}`, but the contents hash to `{}`",
+            self.stored, self.actual
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl ChecksumMismatch {
+    pub fn code(pbo: String, stored: String, actual: String) -> Arc<dyn Code> {
+        Arc::new(Self {
+            pbo,
+            stored,
+            actual,
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct Unsorted {
+    pbo: String,
+}
+
+impl Code for Unsorted {
+    fn ident(&self) -> &'static str {
+        "BUPE3"
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` has unsorted file headers", self.pbo)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(String::from(
+            "Signatures for unsorted PBOs are not valid, repack the PBO",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl Unsorted {
+    pub fn code(pbo: String) -> Arc<dyn Code> {
+        Arc::new(Self { pbo })
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct DuplicateEntry {
+    pbo: String,
+    names: Vec<String>,
+}
+
+impl Code for DuplicateEntry {
+    fn ident(&self) -> &'static str {
+        "BUPE4"
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` contains duplicate entries: {}",
+            self.pbo,
+            self.names
+                .iter()
+                .map(|n| format!("`{n}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(String::from(
+            "Arma paths are case-insensitive, only one of these entries can be used",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl DuplicateEntry {
+    pub fn code(pbo: String, names: Vec<String>) -> Arc<dyn Code> {
+        Arc::new(Self { pbo, names })
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct MissingPrefix {
+    pbo: String,
+}
+
+impl Code for MissingPrefix {
+    fn ident(&self) -> &'static str {
+        "BUPE5"
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` has no `prefix` property", self.pbo)
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl MissingPrefix {
+    pub fn code(pbo: String) -> Arc<dyn Code> {
+        Arc::new(Self { pbo })
+    }
+}
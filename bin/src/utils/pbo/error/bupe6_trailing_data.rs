@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct TrailingData {
+    pbo: String,
+    bytes: u64,
+}
+
+impl Code for TrailingData {
+    fn ident(&self) -> &'static str {
+        "BUPE6"
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` has {} bytes of unexpected data after the checksum",
+            self.pbo, self.bytes
+        )
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl TrailingData {
+    pub fn code(pbo: String, bytes: u64) -> Arc<dyn Code> {
+        Arc::new(Self { pbo, bytes })
+    }
+}
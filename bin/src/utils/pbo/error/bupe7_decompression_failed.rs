@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct DecompressionFailed {
+    pbo: String,
+    entry: String,
+    error: String,
+}
+
+impl Code for DecompressionFailed {
+    fn ident(&self) -> &'static str {
+        "BUPE7"
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` in `{}` does not decompress cleanly: {}",
+            self.entry, self.pbo, self.error
+        )
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl DecompressionFailed {
+    pub fn code(pbo: String, entry: String, error: String) -> Arc<dyn Code> {
+        Arc::new(Self { pbo, entry, error })
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic, Severity};
+
+pub struct EmptyEntry {
+    pbo: String,
+    entry: String,
+}
+
+impl Code for EmptyEntry {
+    fn ident(&self) -> &'static str {
+        "BUPW1"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` in `{}` is empty", self.entry, self.pbo)
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl EmptyEntry {
+    pub fn code(pbo: String, entry: String) -> Arc<dyn Code> {
+        Arc::new(Self { pbo, entry })
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic, Severity};
+
+pub struct OddPrefix {
+    pbo: String,
+    prefix: String,
+    reason: String,
+}
+
+impl Code for OddPrefix {
+    fn ident(&self) -> &'static str {
+        "BUPW2"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` has an unusual prefix `{}`", self.pbo, self.prefix)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(self.reason.clone())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl OddPrefix {
+    pub fn code(pbo: String, prefix: String, reason: String) -> Arc<dyn Code> {
+        Arc::new(Self {
+            pbo,
+            prefix,
+            reason,
+        })
+    }
+}
@@ -0,0 +1,9 @@
+pub mod bupe1_unreadable;
+pub mod bupe2_checksum_mismatch;
+pub mod bupe3_unsorted;
+pub mod bupe4_duplicate_entry;
+pub mod bupe5_missing_prefix;
+pub mod bupe6_trailing_data;
+pub mod bupe7_decompression_failed;
+pub mod bupw1_empty_entry;
+pub mod bupw2_odd_prefix;
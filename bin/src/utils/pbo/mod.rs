@@ -1,9 +1,13 @@
 use std::{fs::File, path::PathBuf};
 
-use crate::Error;
+use crate::{Error, report::Report};
 
+mod check;
+pub(crate) mod diff;
+mod error;
 mod extract;
 mod inspect;
+mod pack;
 mod unpack;
 
 pub use inspect::inspect;
@@ -18,6 +22,33 @@ pub struct Command {
 
 #[derive(clap::Subcommand)]
 enum Subcommands {
+    #[command(verbatim_doc_comment)]
+    /// Check PBOs for structural problems
+    ///
+    /// Reports invalid checksums, unsorted headers, duplicate or empty entries,
+    /// missing or unusual prefixes, data after the checksum, and compressed entries
+    /// that do not decompress cleanly.
+    ///
+    /// ## Example
+    /// Check every PBO in a mod
+    ///
+    /// ```bash
+    /// hemtt.exe utils pbo check @abe
+    /// ```
+    Check(check::PboCheckArgs),
+    #[command(verbatim_doc_comment)]
+    /// Compare two PBOs
+    ///
+    /// Reports added, removed and changed files, and changed properties.
+    /// Rapified files such as `config.bin` are compared by class and property.
+    ///
+    /// ## Example
+    /// Compare a hotfix to the previous release
+    ///
+    /// ```bash
+    /// hemtt.exe utils pbo diff old\abe_main.pbo new\abe_main.pbo
+    /// ```
+    Diff(diff::PboDiffArgs),
     /// Extract a file from a PBO
     ///
     /// Useful for quickly retrieving a specific file without unpacking the entire PBO.
@@ -32,6 +63,19 @@ enum Subcommands {
     /// hemtt.exe utils pbo inspect .hemttout\build\addons\abe_main.pbo
     /// ```
     Inspect(inspect::PboInspectArgs),
+    #[command(verbatim_doc_comment)]
+    /// Pack a folder into a PBO
+    ///
+    /// The reverse of `unpack`, the prefix is read from `$PBOPREFIX$`
+    /// and other properties from `properties.txt` in the folder.
+    ///
+    /// ## Example
+    /// Repack a patched PBO and sign it
+    ///
+    /// ```bash
+    /// hemtt.exe utils pbo pack cba_main --rapify --sign cba_3.20.biprivatekey
+    /// ```
+    Pack(pack::PboPackArgs),
     /// Unpack a PBO file
     ///
     /// A `$PBOPREFIX$` file will be created in the output directory containing the prefix of the PBO.
@@ -46,12 +90,16 @@ enum Subcommands {
 ///
 /// # Panics
 /// If the args are not present from clap
-pub fn execute(cmd: &Command) -> Result<(), Error> {
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
     match &cmd.commands {
+        Subcommands::Check(args) => return check::execute(args),
+        Subcommands::Diff(args) => diff::execute(args),
         Subcommands::Extract(args) => extract::execute(args),
         Subcommands::Inspect(args) => {
             inspect::inspect(File::open(PathBuf::from(&args.pbo))?, &args.format)
         }
+        Subcommands::Pack(args) => pack::execute(args),
         Subcommands::Unpack(args) => unpack::execute(args),
-    }
+    }?;
+    Ok(Report::new())
 }
@@ -0,0 +1,245 @@
+use std::{
+    fs::File,
+    io::{Cursor, Read},
+    path::PathBuf,
+};
+
+use hemtt_common::{
+    BISignVersion,
+    config::{PDriveOption, PreprocessorOptions},
+    prefix::{FILES, Prefix},
+};
+use hemtt_config::rapify::Rapify;
+use hemtt_pbo::{ReadablePbo, WritablePbo};
+use hemtt_preprocessor::Processor;
+use hemtt_signing::BIPrivateKey;
+use hemtt_workspace::{LayerType, WorkspacePath, reporting::WorkspaceFiles};
+use vfs::VfsFileType;
+
+use crate::{Error, modules::rapifier::can_rapify};
+
+#[derive(clap::Args)]
+pub struct PboPackArgs {
+    /// Folder to pack
+    folder: String,
+    /// PBO file to create
+    ///
+    /// If not specified, creates a PBO named after the folder next to it.
+    output: Option<String>,
+    #[arg(long = "include", short = 'i', action = clap::ArgAction::Append)]
+    /// Only pack files matching these globs
+    ///
+    /// Globs are matched against the path relative to the folder, e.g. `data/*.paa`
+    include: Vec<String>,
+    #[arg(long = "exclude", short = 'e', action = clap::ArgAction::Append)]
+    /// Do not pack files matching these globs
+    ///
+    /// Globs are matched against the path relative to the folder, e.g. `*.psd`
+    exclude: Vec<String>,
+    #[arg(long = "compress", action = clap::ArgAction::Append)]
+    /// Compress files matching these globs
+    compress: Vec<String>,
+    #[arg(long = "rapify", short = 'r')]
+    /// Rapify configs (config.cpp, *.rvmat, etc.)
+    ///
+    /// `.cpp` files are packed as `.bin`, `config.cpp` replaces any existing `config.bin`.
+    rapify: bool,
+    #[arg(long = "sign", short = 's')]
+    /// Sign the PBO with a `.biprivatekey`
+    ///
+    /// The `.bisign` is created next to the PBO.
+    sign: Option<String>,
+    #[arg(long = "sign-version", default_value = "3", value_parser = ["2", "3"])]
+    /// BI signature version to use when signing
+    sign_version: String,
+}
+
+/// Execute the pack command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(args: &PboPackArgs) -> Result<(), Error> {
+    let folder = PathBuf::from(&args.folder);
+    if !folder.is_dir() {
+        error!("Folder does not exist");
+        return Ok(());
+    }
+    let output = args.output.as_ref().map_or_else(
+        || {
+            folder.file_name().map_or_else(
+                || PathBuf::from("packed_pbo.pbo"),
+                |name| folder.with_file_name(format!("{}.pbo", name.to_string_lossy())),
+            )
+        },
+        PathBuf::from,
+    );
+    if output.exists() {
+        error!("Output file already exists");
+        return Ok(());
+    }
+    let key = if let Some(key) = &args.sign {
+        Some(BIPrivateKey::read(&mut File::open(key)?)?)
+    } else {
+        None
+    };
+
+    let include = args
+        .include
+        .iter()
+        .map(|g| glob::Pattern::new(g))
+        .collect::<Result<Vec<_>, _>>()?;
+    let exclude = args
+        .exclude
+        .iter()
+        .map(|g| glob::Pattern::new(g))
+        .collect::<Result<Vec<_>, _>>()?;
+    let compress = args
+        .compress
+        .iter()
+        .map(|g| glob::Pattern::new(g))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&folder, LayerType::Source)
+        .finish(None, false, &PDriveOption::Disallow)?;
+
+    let mut pbo = WritablePbo::new();
+    for entry in workspace.walk_dir()? {
+        if entry.metadata()?.file_type != VfsFileType::File {
+            continue;
+        }
+        let relative = entry.as_str().trim_start_matches('/');
+        if !relative.contains('/') {
+            if FILES.contains(&entry.filename().to_lowercase().as_str()) {
+                let prefix = Prefix::new(&entry.read_to_string()?)?;
+                pbo.add_property("prefix", prefix.to_string());
+                continue;
+            }
+            if entry.filename() == "properties.txt" {
+                for line in entry.read_to_string()?.lines() {
+                    if let Some((key, value)) = line.split_once('=') {
+                        pbo.add_property(key.trim(), value.trim());
+                    }
+                }
+                continue;
+            }
+        }
+        if !include.is_empty() && !include.iter().any(|g| g.matches(relative)) {
+            continue;
+        }
+        if exclude.iter().any(|g| g.matches(relative)) {
+            continue;
+        }
+        let file = relative.replace('/', "\\");
+        let compress = compress.iter().any(|g| g.matches(relative));
+        if args.rapify && can_rapify(&entry)? {
+            if let Some((name, data)) = rapify(&entry)? {
+                trace!("adding rapified file {:?}", name);
+                if compress {
+                    pbo.add_file_compressed(name.replace('/', "\\"), Cursor::new(data))?;
+                } else {
+                    pbo.add_file(name.replace('/', "\\"), Cursor::new(data))?;
+                }
+                continue;
+            }
+        } else if entry.filename() == "config.cpp" && entry.parent().join("config.bin")?.exists()? {
+            continue;
+        }
+        if args.rapify
+            && entry.filename() == "config.bin"
+            && entry.parent().join("config.cpp")?.exists()?
+        {
+            continue;
+        }
+        if compress {
+            trace!("adding compressed file {:?}", file);
+            pbo.add_file_compressed(file, read(&entry)?)?;
+        } else {
+            trace!("adding file {:?}", file);
+            pbo.add_file(file, read(&entry)?)?;
+        }
+    }
+    pbo.write(&mut File::create(&output)?, true)?;
+    info!("Packed {}", output.display());
+
+    if let Some(key) = key {
+        let version = if args.sign_version == "2" {
+            BISignVersion::V2
+        } else {
+            BISignVersion::V3
+        };
+        let mut pbo = ReadablePbo::from(File::open(&output)?)?;
+        let sig = key.sign(&mut pbo, version)?;
+        let sig_path = output.with_file_name(format!(
+            "{}.{}.bisign",
+            output
+                .file_name()
+                .expect("output has a file name")
+                .to_string_lossy(),
+            key.authority()
+        ));
+        sig.write(&mut File::create(&sig_path)?)?;
+        info!("Signed {}", sig_path.display());
+    }
+    Ok(())
+}
+
+/// Rapify a file, returning the path to pack it as and its rapified contents
+///
+/// Returns `None` if the preprocessor was instructed not to rapify the file
+fn rapify(entry: &WorkspacePath) -> Result<Option<(String, Vec<u8>)>, Error> {
+    let workspacefiles = WorkspaceFiles::new();
+    let processed = match Processor::run(entry, &PreprocessorOptions::default()) {
+        Ok(processed) => processed,
+        Err((_, hemtt_preprocessor::Error::Code(e))) => {
+            if let Some(diag) = e.diagnostic() {
+                eprintln!("{}", diag.to_string(&workspacefiles));
+            }
+            return Err(Error::Config(format!(
+                "failed to preprocess {}",
+                entry.as_str()
+            )));
+        }
+        Err((_, e)) => return Err(e.into()),
+    };
+    if processed.no_rapify() {
+        debug!("skipping rapify for {}", entry.as_str());
+        return Ok(None);
+    }
+    let report = match hemtt_config::parse(None, &processed) {
+        Ok(report) if report.errors().is_empty() => report,
+        Ok(report) => {
+            for error in report.errors() {
+                if let Some(diag) = error.diagnostic() {
+                    eprintln!("{}", diag.to_string(&workspacefiles));
+                }
+            }
+            return Err(Error::Config(format!("failed to parse {}", entry.as_str())));
+        }
+        Err(errors) => {
+            for error in errors {
+                if let Some(diag) = error.diagnostic() {
+                    eprintln!("{}", diag.to_string(&workspacefiles));
+                }
+            }
+            return Err(Error::Config(format!("failed to parse {}", entry.as_str())));
+        }
+    };
+    let mut data = Vec::new();
+    report.config().rapify(&mut data, 0)?;
+    let name = if std::path::Path::new(&entry.filename())
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("cpp"))
+    {
+        entry.with_extension("bin")?.as_str().to_string()
+    } else {
+        entry.as_str().to_string()
+    };
+    Ok(Some((name.trim_start_matches('/').to_string(), data)))
+}
+
+fn read(entry: &WorkspacePath) -> Result<Cursor<Vec<u8>>, Error> {
+    let mut buffer = Vec::new();
+    entry.open_file()?.read_to_end(&mut buffer)?;
+    Ok(Cursor::new(buffer))
+}
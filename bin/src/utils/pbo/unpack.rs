@@ -32,7 +32,7 @@ pub struct PboUnpackArgs {
 /// [`Error`] depending on the modules
 pub fn execute(args: &PboUnpackArgs) -> Result<(), Error> {
     let pbo_path = PathBuf::from(&args.pbo);
-    let mut pbo = ReadablePbo::from(File::open(&pbo_path)?)?;
+    let mut pbo = ReadablePbo::from_mmap(&File::open(&pbo_path)?)?;
     let output = args.output.as_ref().map_or_else(
         || {
             pbo_path
@@ -58,13 +58,10 @@ pub fn execute(args: &PboUnpackArgs) -> Result<(), Error> {
             file.write_all(format!("{key}={value}\n").as_bytes())?;
         }
     }
-    for header in pbo.files() {
+    pbo.for_each_file(|header, file| {
         let path = output.join(header.filename().replace('\\', "/"));
         fs_err::create_dir_all(path.parent().expect("must have parent, just joined"))?;
         let mut out = File::create(&path)?;
-        let mut file = pbo
-            .file(header.filename())?
-            .expect("file must exist if header exists");
 
         if args.derap {
             let mut buffer = Vec::new();
@@ -73,7 +70,7 @@ pub fn execute(args: &PboUnpackArgs) -> Result<(), Error> {
             let mut header = vec![0; 4];
             let Ok(()) = cursor.read_exact(&mut header) else {
                 std::io::copy(&mut cursor, &mut out)?;
-                continue;
+                return Ok(());
             };
             cursor.set_position(0);
             if header == b"\0raP" || header == b"BLMX" {
@@ -122,10 +119,11 @@ pub fn execute(args: &PboUnpackArgs) -> Result<(), Error> {
                 cursor.set_position(0);
             }
             out.write_all(&cursor.into_inner())?;
-            continue;
+            return Ok(());
         }
 
-        std::io::copy(&mut file, &mut out)?;
-    }
+        std::io::copy(file, &mut out)?;
+        Ok::<(), Error>(())
+    })?;
     Ok(())
 }
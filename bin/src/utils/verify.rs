@@ -1,9 +1,17 @@
-use std::path::PathBuf;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
+use hemtt_common::BISignVersion;
 use hemtt_pbo::ReadablePbo;
+use hemtt_signing::{BIPublicKey, BISign};
+use serde::Serialize;
+use tabled::{Table, Tabled, settings::Style};
 
 use crate::{
-    Error,
+    Error, TableFormat,
+    context::Context,
     utils::inspect::{bikey, bisign},
 };
 
@@ -22,16 +30,82 @@ use crate::{
 ///
 /// Returns a success message if all checks pass, or specific error details if verification fails.
 ///
+/// ## Release Folders
+///
+/// When given a folder, every PBO in it is verified against every `.bisign`
+/// next to it, using the key with the matching authority from the keys folder.
+///
+/// - **Unsigned**: PBO has no signatures
+/// - **No Key**: Signature has no matching key
+/// - **Stale**: Signature does not match the PBO, or the PBO no longer exists
+/// - **Version Mismatch**: Signature version differs from `signing.version`
+///
 /// ## Usage
 ///
 /// ```bash
 /// hemtt utils verify my_addon.pbo my_key.bikey
+/// hemtt utils verify @my_mod @my_mod/keys
 /// ```
 pub struct Command {
     /// Path to the PBO to check. The corresponding .bisign file must be present.
+    ///
+    /// Can also be a release folder, which is searched recursively for PBOs.
     pbo: String,
     /// Path to the public key (.bikey) used to verify the signature.
+    ///
+    /// Can also be a folder of public keys.
     bikey: String,
+    #[arg(long = "sign-version", value_parser = ["2", "3"])]
+    /// Expected BI signature version of a release folder
+    ///
+    /// Defaults to `signing.version` when run in a project.
+    sign_version: Option<String>,
+    #[arg(long, default_value = "ascii")]
+    /// Output format for a release folder
+    format: TableFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Verified,
+    Unsigned,
+    NoKey,
+    Stale,
+    VersionMismatch,
+    Invalid,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Verified => write!(f, "Verified"),
+            Self::Unsigned => write!(f, "Unsigned"),
+            Self::NoKey => write!(f, "No Key"),
+            Self::Stale => write!(f, "Stale"),
+            Self::VersionMismatch => write!(f, "Version Mismatch"),
+            Self::Invalid => write!(f, "Invalid"),
+        }
+    }
+}
+
+#[derive(Debug, Tabled, Serialize)]
+/// The result of verifying one signature, or one unsigned PBO
+pub struct Entry {
+    pbo: String,
+    #[tabled(display = "display_option")]
+    authority: Option<String>,
+    #[tabled(display = "display_option")]
+    version: Option<String>,
+    status: Status,
+    #[tabled(display = "display_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+#[allow(clippy::ref_option)]
+fn display_option(value: &Option<String>) -> String {
+    value.clone().unwrap_or_default()
 }
 
 /// Execute the verify command
@@ -45,6 +119,10 @@ pub fn execute(cmd: &Command) -> Result<(), Error> {
     let pbo_path = PathBuf::from(&cmd.pbo);
     let bikey_path = PathBuf::from(&cmd.bikey);
 
+    if pbo_path.is_dir() {
+        return execute_release(cmd, &pbo_path, &bikey_path);
+    }
+
     debug!("Reading PBO: {:?}", &pbo_path);
     let mut pbo = ReadablePbo::from(fs_err::File::open(&pbo_path)?)?;
     debug!("Reading BIKey: {:?}", &bikey_path);
@@ -107,6 +185,9 @@ pub fn execute(cmd: &Command) -> Result<(), Error> {
         Err(hemtt_signing::Error::InvalidLength) => {
             error!("Verification Failed: Invalid length");
         }
+        Err(hemtt_signing::Error::InvalidResponse(e)) => {
+            error!("Verification Failed: Invalid response from signer: {e}");
+        }
         Err(hemtt_signing::Error::AuthorityMissing) => {
             error!("Verification Failed: Missing authority");
         }
@@ -121,3 +202,254 @@ pub fn execute(cmd: &Command) -> Result<(), Error> {
 
     Ok(())
 }
+
+fn execute_release(cmd: &Command, folder: &Path, keys: &Path) -> Result<(), Error> {
+    let version = match cmd.sign_version.as_deref() {
+        Some("2") => Some(BISignVersion::V2),
+        Some(_) => Some(BISignVersion::V3),
+        None => Context::read_project()
+            .ok()
+            .map(|config| config.signing().version()),
+    };
+    let entries = verify_release(folder, &read_keys(keys)?, version)?;
+    match cmd.format {
+        TableFormat::Ascii => println!("{}", Table::new(&entries).with(Style::modern())),
+        TableFormat::Json => println!("{}", serde_json::to_string(&entries)?),
+        TableFormat::PrettyJson => println!("{}", serde_json::to_string_pretty(&entries)?),
+        TableFormat::Markdown => println!("{}", Table::new(&entries).with(Style::markdown())),
+    }
+    let failed = entries
+        .iter()
+        .filter(|e| e.status != Status::Verified)
+        .count();
+    if failed > 0 {
+        error!("Verification Failed: {failed} of {} entries", entries.len());
+        std::process::exit(1);
+    }
+    info!("Verified {} signatures", entries.len());
+    Ok(())
+}
+
+/// Read a `.bikey`, or every `.bikey` in a folder, by authority
+fn read_keys(path: &Path) -> Result<HashMap<String, BIPublicKey>, Error> {
+    let paths = if path.is_dir() {
+        let mut paths = Vec::new();
+        for entry in fs_err::read_dir(path)? {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("bikey"))
+            {
+                paths.push(path);
+            }
+        }
+        paths
+    } else {
+        vec![path.to_path_buf()]
+    };
+    let mut keys = HashMap::new();
+    for path in paths {
+        let key = BIPublicKey::read(&mut fs_err::File::open(&path)?)?;
+        debug!("Read key {} from {}", key.authority(), path.display());
+        keys.insert(key.authority().to_string(), key);
+    }
+    Ok(keys)
+}
+
+/// Verify every PBO in a release folder against the given keys
+fn verify_release(
+    folder: &Path,
+    keys: &HashMap<String, BIPublicKey>,
+    version: Option<BISignVersion>,
+) -> Result<Vec<Entry>, Error> {
+    let mut pbos = Vec::new();
+    let mut signatures = Vec::new();
+    for entry in walkdir::WalkDir::new(folder) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        match entry.path().extension() {
+            Some(ext) if ext.eq_ignore_ascii_case("pbo") => pbos.push(entry.into_path()),
+            Some(ext) if ext.eq_ignore_ascii_case("bisign") => signatures.push(entry.into_path()),
+            _ => {}
+        }
+    }
+    pbos.sort();
+    signatures.sort();
+
+    let mut entries = Vec::new();
+    for pbo_path in &pbos {
+        let display = pbo_path
+            .strip_prefix(folder)
+            .unwrap_or(pbo_path)
+            .display()
+            .to_string();
+        let pbo_name = pbo_path
+            .file_name()
+            .map(|n| format!("{}.", n.to_string_lossy().to_lowercase()))
+            .unwrap_or_default();
+        let own = signatures
+            .iter()
+            .filter(|s| {
+                s.parent() == pbo_path.parent()
+                    && s.file_name()
+                        .is_some_and(|n| n.to_string_lossy().to_lowercase().starts_with(&pbo_name))
+            })
+            .collect::<Vec<_>>();
+        if own.is_empty() {
+            entries.push(Entry {
+                pbo: display,
+                authority: None,
+                version: None,
+                status: Status::Unsigned,
+                reason: None,
+            });
+            continue;
+        }
+        for signature_path in own {
+            let signature = match BISign::read(&mut fs_err::File::open(signature_path)?) {
+                Ok(signature) => signature,
+                Err(e) => {
+                    entries.push(Entry {
+                        pbo: display.clone(),
+                        authority: None,
+                        version: None,
+                        status: Status::Invalid,
+                        reason: Some(e.to_string()),
+                    });
+                    continue;
+                }
+            };
+            let (status, reason) = keys.get(signature.authority()).map_or_else(
+                || Ok::<_, Error>((Status::NoKey, None)),
+                |key| {
+                    let mut pbo = ReadablePbo::from(fs_err::File::open(pbo_path)?)?;
+                    Ok(match key.verify(&mut pbo, &signature) {
+                        Ok(()) => match version {
+                            Some(version) if version != signature.version() => {
+                                (Status::VersionMismatch, Some(format!("expected {version}")))
+                            }
+                            _ => (Status::Verified, None),
+                        },
+                        Err(hemtt_signing::Error::HashMismatch { .. }) => (Status::Stale, None),
+                        Err(e) => (Status::Invalid, Some(e.to_string())),
+                    })
+                },
+            )?;
+            entries.push(Entry {
+                pbo: display.clone(),
+                authority: Some(signature.authority().to_string()),
+                version: Some(signature.version().to_string()),
+                status,
+                reason,
+            });
+        }
+    }
+
+    // signatures left behind by a PBO that was removed or renamed
+    for signature_path in &signatures {
+        let orphaned = signature_path.file_name().is_some_and(|n| {
+            let name = n.to_string_lossy().to_lowercase();
+            !pbos.iter().any(|p| {
+                p.parent() == signature_path.parent()
+                    && p.file_name().is_some_and(|p| {
+                        name.starts_with(&format!("{}.", p.to_string_lossy().to_lowercase()))
+                    })
+            })
+        });
+        if orphaned {
+            entries.push(Entry {
+                pbo: signature_path
+                    .strip_prefix(folder)
+                    .unwrap_or(signature_path)
+                    .display()
+                    .to_string(),
+                authority: None,
+                version: None,
+                status: Status::Stale,
+                reason: Some("no matching PBO".to_string()),
+            });
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{collections::HashMap, io::Cursor};
+
+    use hemtt_common::BISignVersion;
+    use hemtt_pbo::{ReadablePbo, WritablePbo};
+    use hemtt_signing::BIPrivateKey;
+
+    use super::Status;
+
+    fn write_pbo(path: &std::path::Path, content: &[u8]) {
+        let mut pbo = WritablePbo::new();
+        pbo.add_property("prefix", "z\\test\\addons\\main");
+        pbo.add_file("script.sqf", Cursor::new(content.to_vec()))
+            .unwrap();
+        pbo.write(&mut fs_err::File::create(path).unwrap(), true)
+            .unwrap();
+    }
+
+    fn sign(key: &BIPrivateKey, path: &std::path::Path, version: BISignVersion) {
+        let mut pbo = ReadablePbo::from(fs_err::File::open(path).unwrap()).unwrap();
+        key.sign(&mut pbo, version)
+            .unwrap()
+            .write(
+                &mut fs_err::File::create(format!("{}.{}.bisign", path.display(), key.authority()))
+                    .unwrap(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn release() {
+        let folder = std::env::temp_dir().join("hemtt_verify_release");
+        let _ = fs_err::remove_dir_all(&folder);
+        let addons = folder.join("addons");
+        fs_err::create_dir_all(&addons).unwrap();
+
+        let key = BIPrivateKey::generate(1024, "test").unwrap();
+        let other = BIPrivateKey::generate(1024, "other").unwrap();
+
+        write_pbo(&addons.join("verified.pbo"), b"hint 'a';");
+        sign(&key, &addons.join("verified.pbo"), BISignVersion::V3);
+        write_pbo(&addons.join("unsigned.pbo"), b"hint 'a';");
+        write_pbo(&addons.join("stale.pbo"), b"hint 'a';");
+        sign(&key, &addons.join("stale.pbo"), BISignVersion::V3);
+        write_pbo(&addons.join("stale.pbo"), b"hint 'b';");
+        write_pbo(&addons.join("nokey.pbo"), b"hint 'a';");
+        sign(&other, &addons.join("nokey.pbo"), BISignVersion::V3);
+        write_pbo(&addons.join("old.pbo"), b"hint 'a';");
+        sign(&key, &addons.join("old.pbo"), BISignVersion::V2);
+        fs_err::copy(
+            addons.join("verified.pbo.test.bisign"),
+            addons.join("removed.pbo.test.bisign"),
+        )
+        .unwrap();
+
+        let keys = HashMap::from([("test".to_string(), key.to_public_key())]);
+        let entries = super::verify_release(&folder, &keys, Some(BISignVersion::V3)).unwrap();
+        let status = |pbo: &str| {
+            entries
+                .iter()
+                .find(|e| e.pbo.ends_with(pbo))
+                .unwrap()
+                .status
+        };
+        assert_eq!(entries.len(), 6);
+        assert_eq!(status("verified.pbo"), Status::Verified);
+        assert_eq!(status("unsigned.pbo"), Status::Unsigned);
+        assert_eq!(status("stale.pbo"), Status::Stale);
+        assert_eq!(status("nokey.pbo"), Status::NoKey);
+        assert_eq!(status("old.pbo"), Status::VersionMismatch);
+        assert_eq!(status("removed.pbo.test.bisign"), Status::Stale);
+        fs_err::remove_dir_all(&folder).unwrap();
+    }
+}
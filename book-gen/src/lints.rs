@@ -3,6 +3,8 @@ use std::sync::Arc;
 
 use hemtt_common::config::LintEnabled;
 use hemtt_config::analyze::CONFIG_LINTS;
+use hemtt_p3d::analyze::MODEL_LINTS;
+use hemtt_paa::analyze::TEXTURE_LINTS;
 use hemtt_sqf::analyze::{
     LintData, SQF_LINTS,
     lints::s02_event_handlers::{
@@ -11,24 +13,47 @@ use hemtt_sqf::analyze::{
 };
 use hemtt_stringtable::analyze::STRINGTABLE_LINTS;
 use hemtt_workspace::lint::{Lint, Lints};
+use hemtt_wss::analyze::AUDIO_LINTS;
 use mdbook_preprocessor::book::{BookItem, Chapter};
 
 pub fn run(chapter: &mut Chapter) {
     for item in &mut chapter.sub_items {
         if let BookItem::Chapter(chapter) = item {
+            if chapter.name == "Audio" {
+                audio(chapter);
+            }
             if chapter.name == "Config" {
                 config(chapter);
             }
+            if chapter.name == "Models" {
+                models(chapter);
+            }
             if chapter.name == "SQF" {
                 sqf(chapter);
             }
             if chapter.name == "Stringtables" {
                 stringtables(chapter);
             }
+            if chapter.name == "Textures" {
+                textures(chapter);
+            }
         }
     }
 }
 
+fn audio(chapter: &mut Chapter) {
+    let mut output = String::from("# Lints - Audio\n\n");
+    let mut lint_text: Vec<(u32, String)> = Vec::new();
+    for lint in AUDIO_LINTS.iter().filter(|l| l.display()) {
+        lint_text.push((lint.sort(), get_text(&**lint, "L-A")));
+    }
+    lint_text.sort_by_key(|a| a.0);
+    for (_, text) in lint_text {
+        output.push_str(&text);
+    }
+    chapter.content = output;
+}
+
 fn config(chapter: &mut Chapter) {
     let mut output = String::from("# Lints - Config\n\n");
     let mut lint_text: Vec<(u32, String)> = Vec::new();
@@ -42,6 +67,19 @@ fn config(chapter: &mut Chapter) {
     chapter.content = output;
 }
 
+fn models(chapter: &mut Chapter) {
+    let mut output = String::from("# Lints - Models\n\n");
+    let mut lint_text: Vec<(u32, String)> = Vec::new();
+    for lint in MODEL_LINTS.iter().filter(|l| l.display()) {
+        lint_text.push((lint.sort(), get_text(&**lint, "L-M")));
+    }
+    lint_text.sort_by_key(|a| a.0);
+    for (_, text) in lint_text {
+        output.push_str(&text);
+    }
+    chapter.content = output;
+}
+
 fn sqf(chapter: &mut Chapter) {
     let mut output = String::from("# Lints - SQF\n\n");
     let mut lint_text: Vec<(u32, String)> = Vec::new();
@@ -81,6 +119,19 @@ fn stringtables(chapter: &mut Chapter) {
     chapter.content = output;
 }
 
+fn textures(chapter: &mut Chapter) {
+    let mut output = String::from("# Lints - Textures\n\n");
+    let mut lint_text: Vec<(u32, String)> = Vec::new();
+    for lint in TEXTURE_LINTS.iter().filter(|l| l.display()) {
+        lint_text.push((lint.sort(), get_text(&**lint, "L-T")));
+    }
+    lint_text.sort_by_key(|a| a.0);
+    for (_, text) in lint_text {
+        output.push_str(&text);
+    }
+    chapter.content = output;
+}
+
 fn get_text<D>(lint: &Arc<Box<dyn Lint<D>>>, prefix: &str) -> String {
     let mut text = String::new();
     let _ = write!(text, "\n***\n## {}\n", lint.ident());
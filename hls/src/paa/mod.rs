@@ -36,7 +36,11 @@ pub fn convert(url: &Url, to: &str, out: Option<String>) -> Result<PathBuf, Stri
     ) {
         let paa = hemtt_paa::Paa::read(fs_err::File::open(path).map_err(|e| format!("{e:?}"))?)
             .map_err(|e| format!("{e:?}"))?;
-        if let Err(e) = paa.maps()[0].0.get_image().save(&output) {
+        let image = paa.maps()[0]
+            .0
+            .get_image()
+            .map_err(|e| format!("Failed to decode PAA: {e}"))?;
+        if let Err(e) = image.save(&output) {
             error!("Failed to save image: {}", e);
             return Err(format!(
                 "Failed to save image to {}: {}",
@@ -46,19 +50,23 @@ pub fn convert(url: &Url, to: &str, out: Option<String>) -> Result<PathBuf, Stri
         }
     } else {
         let image = image::open(path).map_err(|e| format!("{e:?}"))?;
-        let paa = hemtt_paa::Paa::from_dynamic(&image, {
-            let (width, height) = image.dimensions();
-            if !height.is_power_of_two() || !width.is_power_of_two() {
-                hemtt_paa::PaXType::ARGB8
-            } else {
-                let has_transparency = image.pixels().any(|p| p.2[3] < 255);
-                if has_transparency {
-                    hemtt_paa::PaXType::DXT5
+        let paa = hemtt_paa::Paa::from_dynamic(
+            &image,
+            {
+                let (width, height) = image.dimensions();
+                if !height.is_power_of_two() || !width.is_power_of_two() {
+                    hemtt_paa::PaXType::ARGB8
                 } else {
-                    hemtt_paa::PaXType::DXT1
+                    let has_transparency = image.pixels().any(|p| p.2[3] < 255);
+                    if has_transparency {
+                        hemtt_paa::PaXType::DXT5
+                    } else {
+                        hemtt_paa::PaXType::DXT1
+                    }
                 }
-            }
-        })
+            },
+            hemtt_paa::Quality::default(),
+        )
         .map_err(|e| format!("{e:?}"))?;
         let mut file = fs_err::File::create(&output)
             .map_err(|e| format!("Failed to create output file: {e}"))?;
@@ -12,7 +12,7 @@ use tower_lsp::{
     Client,
     lsp_types::{DidChangeWorkspaceFoldersParams, WorkspaceFolder},
 };
-use tracing::debug;
+use tracing::{debug, warn};
 use url::Url;
 
 use crate::{config::ConfigAnalyzer, sqf::SqfAnalyzer};
@@ -163,6 +163,23 @@ impl EditorWorkspace {
             if include.is_dir() {
                 builder = builder.physical(&include, LayerType::Include);
             }
+            if let Ok(config) = ProjectConfig::from_file(&root.join(".hemtt").join("project.toml"))
+            {
+                for mount in config.mount().mods() {
+                    let mount = root.join(mount);
+                    if !mount.is_dir() {
+                        warn!("Mounted mod not found: {}", mount.display());
+                        continue;
+                    }
+                    match builder.pbos(&mount) {
+                        Ok(b) => builder = b,
+                        Err(e) => {
+                            warn!("Failed to mount {}: {}", mount.display(), e);
+                            return None;
+                        }
+                    }
+                }
+            }
             let Ok(workspace) = builder.finish(None, true, &PDriveOption::Disallow) else {
                 return None;
             };
@@ -1,6 +1,9 @@
 mod binarize;
 mod files;
 mod rapify;
+mod textures;
+
+pub use textures::TextureQuality;
 
 use std::{collections::HashMap, sync::Once};
 
@@ -25,6 +28,9 @@ pub struct AddonConfig {
     /// Files to exclude from the pbo
     files: files::FilesConfig,
 
+    /// Source images to convert to PAA
+    textures: textures::TexturesConfig,
+
     /// Ignore pboprefix checks
     ignore_pboprefix: bool,
 }
@@ -54,6 +60,12 @@ impl AddonConfig {
         &self.files
     }
 
+    #[must_use]
+    /// Source images to convert to PAA
+    pub const fn textures(&self) -> &textures::TexturesConfig {
+        &self.textures
+    }
+
     #[must_use]
     /// Ignore pboprefix checks
     pub const fn ignore_pboprefix(&self) -> bool {
@@ -93,6 +105,9 @@ pub struct AddonConfigFile {
     #[serde(default)]
     files: files::FilesSectionFile,
 
+    #[serde(default)]
+    textures: textures::TexturesSectionFile,
+
     #[serde(default)]
     ignore_pboprefix: bool,
 }
@@ -148,6 +163,7 @@ impl From<AddonConfigFile> for AddonConfig {
                 files.exclude_mut().extend(file.exclude);
                 files
             },
+            textures: file.textures.into(),
             ignore_pboprefix: file.ignore_pboprefix,
         }
     }
@@ -172,6 +188,9 @@ test = "test"
 [files]
 exclude = ["test"]
 
+[textures]
+convert = ["data/*.png"]
+
 "#;
         let file: AddonConfigFile = toml::from_str(toml).expect("failed to deserialize");
         let config = AddonConfig::from(file);
@@ -179,6 +198,7 @@ exclude = ["test"]
         assert!(config.binarize().enabled());
         assert_eq!(config.properties().get("test"), Some(&"test".to_string()));
         assert_eq!(config.files().exclude(), &["test"]);
+        assert_eq!(config.textures().convert(), &["data/*.png"]);
     }
 
     #[test]
@@ -190,6 +210,7 @@ exclude = ["test"]
         assert!(config.binarize().enabled());
         assert!(config.properties().is_empty());
         assert!(config.files().exclude().is_empty());
+        assert!(config.textures().convert().is_empty());
     }
 
     #[test]
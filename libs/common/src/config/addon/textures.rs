@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+/// Textures config
+pub struct TexturesConfig {
+    /// Source images to convert to PAA
+    convert: Vec<String>,
+    /// DXT compression quality when converting
+    quality: TexturesQuality,
+}
+
+impl TexturesConfig {
+    /// Source images to convert to PAA
+    pub const fn convert(&self) -> &Vec<String> {
+        &self.convert
+    }
+
+    #[must_use]
+    /// DXT compression quality when converting
+    pub const fn quality(&self) -> &TexturesQuality {
+        &self.quality
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// DXT compression quality, trading speed for accuracy
+pub enum TextureQuality {
+    /// Range fit, fast and low quality
+    Fast,
+    /// Cluster fit
+    Normal,
+    /// Iterative cluster fit, slow and high quality
+    Best,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// DXT compression quality for `hemtt dev`, and for `hemtt build` and `hemtt release`
+pub struct TexturesQuality {
+    #[serde(default = "default_dev")]
+    dev: TextureQuality,
+    #[serde(default = "default_release")]
+    release: TextureQuality,
+}
+
+impl TexturesQuality {
+    #[must_use]
+    /// Quality used by `hemtt dev`
+    pub const fn dev(&self) -> TextureQuality {
+        self.dev
+    }
+
+    #[must_use]
+    /// Quality used by `hemtt build` and `hemtt release`
+    pub const fn release(&self) -> TextureQuality {
+        self.release
+    }
+}
+
+impl Default for TexturesQuality {
+    fn default() -> Self {
+        Self {
+            dev: default_dev(),
+            release: default_release(),
+        }
+    }
+}
+
+const fn default_dev() -> TextureQuality {
+    TextureQuality::Fast
+}
+
+const fn default_release() -> TextureQuality {
+    TextureQuality::Best
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Section of the addon.toml file for textures
+pub struct TexturesSectionFile {
+    #[serde(default)]
+    /// Source images to convert to PAA
+    pub convert: Vec<String>,
+    #[serde(default)]
+    /// DXT compression quality when converting
+    pub quality: TexturesQuality,
+}
+
+impl From<TexturesSectionFile> for TexturesConfig {
+    fn from(file: TexturesSectionFile) -> Self {
+        Self {
+            convert: file.convert,
+            quality: file.quality,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+convert = ["data/*.png", "data/*.tga"]
+
+[quality]
+dev = "normal"
+release = "normal"
+"#;
+        let file: TexturesSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = TexturesConfig::from(file);
+        assert_eq!(config.convert(), &["data/*.png", "data/*.tga"]);
+        assert_eq!(config.quality().dev(), TextureQuality::Normal);
+        assert_eq!(config.quality().release(), TextureQuality::Normal);
+    }
+
+    #[test]
+    fn default() {
+        let toml = "";
+        let file: TexturesSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = TexturesConfig::from(file);
+        assert!(config.convert().is_empty());
+        assert_eq!(config.quality().dev(), TextureQuality::Fast);
+        assert_eq!(config.quality().release(), TextureQuality::Best);
+    }
+}
@@ -9,7 +9,7 @@ mod global;
 mod pdrive;
 mod project;
 
-pub use addon::AddonConfig;
+pub use addon::{AddonConfig, TextureQuality};
 pub use global::GlobalConfig;
 pub use pdrive::PDriveOption;
 pub use project::{
@@ -17,6 +17,7 @@ pub use project::{
     hemtt::{RuntimeArguments, launch::LaunchOptions},
     lint::{LintConfig, LintConfigOverride, LintEnabled},
     preprocessor::PreprocessorOptions,
+    signing::ExternalSigner,
 };
 
 fn deprecated(file: &str, key: &str, replacement: &str, info: Option<&str>) {
@@ -7,6 +7,7 @@ use crate::config::pdrive::PDriveOption;
 /// Configuration for `hemtt check`
 pub struct CheckOptions {
     pdrive: PDriveOption,
+    allow_unused: Vec<String>,
 }
 
 impl CheckOptions {
@@ -14,6 +15,11 @@ impl CheckOptions {
     pub const fn pdrive(&self) -> &PDriveOption {
         &self.pdrive
     }
+
+    /// Globs of assets that are not reported when unused
+    pub const fn allow_unused(&self) -> &Vec<String> {
+        &self.allow_unused
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -22,12 +28,15 @@ impl CheckOptions {
 pub struct CheckOptionsFile {
     #[serde(default)]
     pdrive: Option<PDriveOption>,
+    #[serde(default)]
+    allow_unused: Vec<String>,
 }
 
 impl From<CheckOptionsFile> for CheckOptions {
     fn from(file: CheckOptionsFile) -> Self {
         Self {
             pdrive: file.pdrive.unwrap_or_default(),
+            allow_unused: file.allow_unused,
         }
     }
 }
@@ -40,10 +49,12 @@ mod tests {
     fn fully_defined() {
         let toml = r#"
 pdrive = "disallow"
+allow_unused = ["addons/main/data/ui/*.paa"]
 "#;
         let file: CheckOptionsFile = toml::from_str(toml).expect("failed to deserialize");
         let config = CheckOptions::from(file);
         assert_eq!(config.pdrive(), &PDriveOption::Disallow);
+        assert_eq!(config.allow_unused(), &["addons/main/data/ui/*.paa"]);
     }
 
     #[test]
@@ -52,5 +63,6 @@ pdrive = "disallow"
         let file: CheckOptionsFile = toml::from_str(toml).expect("failed to deserialize");
         let config = CheckOptions::from(file);
         assert_eq!(config.pdrive(), &PDriveOption::Ignore);
+        assert!(config.allow_unused().is_empty());
     }
 }
@@ -7,18 +7,33 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Lint group config
 pub struct LintGroupConfig {
+    audio: HashMap<String, LintConfigOverride>,
     config: HashMap<String, LintConfigOverride>,
+    models: HashMap<String, LintConfigOverride>,
     sqf: HashMap<String, LintConfigOverride>,
     stringtables: HashMap<String, LintConfigOverride>,
+    textures: HashMap<String, LintConfigOverride>,
 }
 
 impl LintGroupConfig {
+    #[must_use]
+    /// Get the audio lints
+    pub const fn audio(&self) -> &HashMap<String, LintConfigOverride> {
+        &self.audio
+    }
+
     #[must_use]
     /// Get the lints
     pub const fn config(&self) -> &HashMap<String, LintConfigOverride> {
         &self.config
     }
 
+    #[must_use]
+    /// Get the models lints
+    pub const fn models(&self) -> &HashMap<String, LintConfigOverride> {
+        &self.models
+    }
+
     #[must_use]
     /// Get the sqf lints
     pub const fn sqf(&self) -> &HashMap<String, LintConfigOverride> {
@@ -31,8 +46,19 @@ impl LintGroupConfig {
         &self.stringtables
     }
 
+    #[must_use]
+    /// Get the textures lints
+    pub const fn textures(&self) -> &HashMap<String, LintConfigOverride> {
+        &self.textures
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.config.is_empty() && self.sqf.is_empty() && self.stringtables.is_empty()
+        self.audio.is_empty()
+            && self.config.is_empty()
+            && self.models.is_empty()
+            && self.sqf.is_empty()
+            && self.stringtables.is_empty()
+            && self.textures.is_empty()
     }
 }
 
@@ -201,20 +227,35 @@ impl LintConfigOverride {
 #[allow(clippy::module_name_repetitions)]
 #[derive(PartialEq, Eq, Debug, Default, Clone, Serialize, Deserialize)]
 pub struct LintSectionFile {
+    pub audio: Option<HashMap<String, LintConfigFile>>,
     pub config: Option<HashMap<String, LintConfigFile>>,
+    pub models: Option<HashMap<String, LintConfigFile>>,
     pub sqf: Option<HashMap<String, LintConfigFile>>,
     pub stringtables: Option<HashMap<String, LintConfigFile>>,
+    pub textures: Option<HashMap<String, LintConfigFile>>,
 }
 
 impl From<LintSectionFile> for LintGroupConfig {
     fn from(file: LintSectionFile) -> Self {
         Self {
+            audio: file
+                .audio
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
             config: file
                 .config
                 .unwrap_or_default()
                 .into_iter()
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
+            models: file
+                .models
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
             sqf: file
                 .sqf
                 .unwrap_or_default()
@@ -227,6 +268,12 @@ impl From<LintSectionFile> for LintGroupConfig {
                 .into_iter()
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
+            textures: file
+                .textures
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
         }
     }
 }
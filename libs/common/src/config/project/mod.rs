@@ -13,6 +13,7 @@ use super::deprecated;
 pub mod files;
 pub mod hemtt;
 pub mod lint;
+pub mod mount;
 pub mod preprocessor;
 pub mod signing;
 pub mod version;
@@ -48,6 +49,9 @@ pub struct ProjectConfig {
     // Preprocessor options
     preprocessor: preprocessor::PreprocessorOptions,
 
+    /// Existing mods to mount into the workspace
+    mount: mount::MountConfig,
+
     /// Signing specific configuration
     signing: signing::SigningConfig,
 
@@ -119,6 +123,12 @@ impl ProjectConfig {
         &self.preprocessor
     }
 
+    #[must_use]
+    /// Existing mods to mount into the workspace
+    pub const fn mount(&self) -> &mount::MountConfig {
+        &self.mount
+    }
+
     #[must_use]
     /// Signing specific configuration
     pub const fn signing(&self) -> &signing::SigningConfig {
@@ -199,6 +209,9 @@ pub struct ProjectFile {
     #[serde(default)]
     preprocessor: preprocessor::PreprocessorOptionsFile,
 
+    #[serde(default)]
+    mount: mount::MountSectionFile,
+
     #[serde(default)]
     signing: signing::SigningSectionFile,
 
@@ -257,6 +270,7 @@ impl TryFrom<ProjectFile> for ProjectConfig {
             files: file.files.into(),
             lints: file.lints.into(),
             preprocessor: file.preprocessor.into(),
+            mount: file.mount.into(),
             signing: file.signing.into(),
             runtime: RuntimeArguments::default(),
             expected_path,
@@ -290,7 +304,7 @@ impl TryFrom<ProjectFile> for ProjectConfig {
 mod test_helper {
     use std::collections::HashMap;
 
-    use super::{files, hemtt, lint, preprocessor, signing, version};
+    use super::{files, hemtt, lint, mount, preprocessor, signing, version};
 
     impl super::ProjectConfig {
         #[must_use]
@@ -310,6 +324,7 @@ mod test_helper {
                 lints: lint::LintSectionFile::default(),
                 hemtt: hemtt::HemttSectionFile::default(),
                 preprocessor: preprocessor::PreprocessorOptionsFile::default(),
+                mount: mount::MountSectionFile::default(),
                 signing: signing::SigningSectionFile::default(),
                 meta_path: std::path::PathBuf::default(),
             }
@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+/// Configuration for mounting existing mods into the workspace
+pub struct MountConfig {
+    /// `@mod` folders whose PBOs are mounted as read-only include layers
+    mods: Vec<PathBuf>,
+}
+
+impl MountConfig {
+    #[must_use]
+    /// `@mod` folders whose PBOs are mounted as read-only include layers
+    pub fn mods(&self) -> &[PathBuf] {
+        &self.mods
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(PartialEq, Eq, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MountSectionFile {
+    #[serde(default)]
+    /// `@mod` folders whose PBOs are mounted as read-only include layers
+    mods: Vec<PathBuf>,
+}
+
+impl From<MountSectionFile> for MountConfig {
+    fn from(file: MountSectionFile) -> Self {
+        Self { mods: file.mods }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+mods = ["../@CBA_A3", "/opt/arma3/@ace"]
+"#;
+        let file: MountSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = MountConfig::from(file);
+        assert_eq!(
+            config.mods(),
+            &[
+                PathBuf::from("../@CBA_A3"),
+                PathBuf::from("/opt/arma3/@ace")
+            ]
+        );
+    }
+
+    #[test]
+    fn default() {
+        let toml = "";
+        let file: MountSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = MountConfig::from(file);
+        assert!(config.mods().is_empty());
+    }
+}
@@ -10,6 +10,10 @@ pub struct SigningConfig {
     authority: Option<String>,
 
     private_key_hash: Option<String>,
+
+    keys: Vec<SigningKey>,
+
+    external: Option<ExternalSigner>,
 }
 
 impl SigningConfig {
@@ -24,6 +28,80 @@ impl SigningConfig {
     pub fn private_key_hash(&self) -> Option<&str> {
         self.private_key_hash.as_deref()
     }
+
+    /// Additional keys to sign with, such as the previous key during a rotation
+    pub fn keys(&self) -> &[SigningKey] {
+        &self.keys
+    }
+
+    /// A signer outside of HEMTT that holds the private key
+    pub const fn external(&self) -> Option<&ExternalSigner> {
+        self.external.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// A signer outside of HEMTT, reached by running a command or connecting to a socket
+pub struct ExternalSigner {
+    public_key: String,
+    #[serde(default)]
+    command: Vec<String>,
+    #[serde(default)]
+    socket: Option<String>,
+}
+
+impl ExternalSigner {
+    #[must_use]
+    pub const fn new(public_key: String, command: Vec<String>, socket: Option<String>) -> Self {
+        Self {
+            public_key,
+            command,
+            socket,
+        }
+    }
+
+    #[must_use]
+    /// Path to the `.bikey` of the external signer, relative to the project
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    #[must_use]
+    /// Command and arguments to run for each PBO
+    pub fn command(&self) -> &[String] {
+        &self.command
+    }
+
+    #[must_use]
+    /// Address of a signing service, such as `127.0.0.1:9123`
+    pub fn socket(&self) -> Option<&str> {
+        self.socket.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// An additional HEMTT private key to sign with
+pub struct SigningKey {
+    authority: String,
+    private_key_hash: String,
+}
+
+impl SigningKey {
+    #[must_use]
+    pub const fn new(authority: String, private_key_hash: String) -> Self {
+        Self {
+            authority,
+            private_key_hash,
+        }
+    }
+
+    pub fn authority(&self) -> &str {
+        &self.authority
+    }
+
+    pub fn private_key_hash(&self) -> &str {
+        &self.private_key_hash
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -36,6 +114,12 @@ pub struct SigningSectionFile {
 
     #[serde(default)]
     private_key_hash: Option<String>,
+
+    #[serde(default)]
+    keys: Vec<SigningKey>,
+
+    #[serde(default)]
+    external: Option<ExternalSigner>,
 }
 
 impl From<SigningSectionFile> for SigningConfig {
@@ -44,6 +128,8 @@ impl From<SigningSectionFile> for SigningConfig {
             version: file.version.unwrap_or_default(),
             authority: file.authority,
             private_key_hash: file.private_key_hash,
+            keys: file.keys,
+            external: file.external,
         }
     }
 }
@@ -58,12 +144,27 @@ mod tests {
 version = 2
 authority = "test"
 private_key_hash = "abcd1234"
+
+[[keys]]
+authority = "test_old"
+private_key_hash = "efgh5678"
+
+[external]
+public_key = "keys/test.bikey"
+socket = "127.0.0.1:9123"
 "#;
         let file: SigningSectionFile = toml::from_str(toml).expect("failed to deserialize");
         let config = SigningConfig::from(file);
         assert_eq!(config.version(), BISignVersion::V2);
         assert_eq!(config.authority(), Some("test"));
         assert_eq!(config.private_key_hash(), Some("abcd1234"));
+        assert_eq!(config.keys().len(), 1);
+        assert_eq!(config.keys()[0].authority(), "test_old");
+        assert_eq!(config.keys()[0].private_key_hash(), "efgh5678");
+        let external = config.external().expect("external signer");
+        assert_eq!(external.public_key(), "keys/test.bikey");
+        assert!(external.command().is_empty());
+        assert_eq!(external.socket(), Some("127.0.0.1:9123"));
     }
 
     #[test]
@@ -73,5 +174,7 @@ private_key_hash = "abcd1234"
         let config = SigningConfig::from(file);
         assert_eq!(config.version(), BISignVersion::V3);
         assert!(config.authority().is_none());
+        assert!(config.keys().is_empty());
+        assert!(config.external().is_none());
     }
 }
@@ -12,6 +12,7 @@ use std::{
 pub mod analyze;
 pub mod display;
 mod model;
+pub mod model_cfg;
 pub mod parse;
 pub mod rapify;
 
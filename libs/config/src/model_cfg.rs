@@ -0,0 +1,377 @@
+//! Typed `model.cfg`
+//!
+//! Binarize reads `CfgSkeletons` and `CfgModels` from the `model.cfg` next to a P3D,
+//! or the nearest one in a parent folder, to find the bones, sections and animations of the model.
+
+use crate::{Class, Config, Ident, Item, Number, Property, Str, Value};
+
+/// Deepest class inheritance followed before giving up
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The skeletons and models of a `model.cfg`
+pub struct ModelCfg {
+    skeletons: Vec<Skeleton>,
+    models: Vec<CfgModel>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A class in `CfgSkeletons`
+pub struct Skeleton {
+    pub name: Ident,
+    pub parent: Option<Ident>,
+    /// `skeletonInherit`, a skeleton whose bones come before these
+    pub inherit: Option<Str>,
+    /// `skeletonBones`, `None` when not set in this class
+    pub bones: Option<Vec<Bone>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A bone of a skeleton, with its parent bone, empty for none
+pub struct Bone {
+    pub name: Str,
+    pub parent: Str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A class in `CfgModels`, named after the P3D it applies to
+pub struct CfgModel {
+    pub name: Ident,
+    pub parent: Option<Ident>,
+    /// `skeletonName`, empty for no skeleton
+    pub skeleton_name: Option<Str>,
+    /// `sections`, `None` when not set in this class
+    pub sections: Option<Vec<Str>>,
+    /// `sectionsInherit`, a model whose sections come before these
+    pub sections_inherit: Option<Str>,
+    /// Classes of `Animations` declared in this class
+    pub animations: Vec<Animation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A class in the `Animations` of a model
+pub struct Animation {
+    pub name: Ident,
+    pub parent: Option<Ident>,
+    /// `type`, such as `rotation` or `hide`
+    pub kind: Option<Str>,
+    pub source: Option<Str>,
+    pub selection: Option<Str>,
+    pub axis: Option<Str>,
+    pub begin: Option<Str>,
+    pub end: Option<Str>,
+    /// Are `axis`, `begin` and `end` in the Memory LOD
+    pub memory: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An error resolving the inheritance of a skeleton
+pub enum ResolveError {
+    /// A skeleton named by `skeletonName` or `skeletonInherit` does not exist
+    UnknownSkeleton(Str),
+    /// A skeleton inherits its bones from itself
+    CircularSkeleton(Str),
+}
+
+impl ModelCfg {
+    #[must_use]
+    /// Read `CfgSkeletons` and `CfgModels` from a parsed config
+    pub fn from_config(config: &Config) -> Self {
+        let mut model_cfg = Self::default();
+        for property in &config.0 {
+            let Property::Class(Class::Local {
+                name, properties, ..
+            }) = property
+            else {
+                continue;
+            };
+            if name.as_str().eq_ignore_ascii_case("cfgskeletons") {
+                model_cfg.skeletons.extend(classes(properties).map(
+                    |(name, parent, properties)| Skeleton {
+                        name: name.clone(),
+                        parent: parent.cloned(),
+                        inherit: string(properties, "skeletonInherit"),
+                        bones: strings(properties, "skeletonBones").map(|bones| {
+                            bones
+                                .chunks_exact(2)
+                                .map(|pair| Bone {
+                                    name: pair[0].clone(),
+                                    parent: pair[1].clone(),
+                                })
+                                .collect()
+                        }),
+                    },
+                ));
+            } else if name.as_str().eq_ignore_ascii_case("cfgmodels") {
+                model_cfg
+                    .models
+                    .extend(classes(properties).map(|(name, parent, properties)| {
+                        CfgModel {
+                            name: name.clone(),
+                            parent: parent.cloned(),
+                            skeleton_name: string(properties, "skeletonName"),
+                            sections: strings(properties, "sections"),
+                            sections_inherit: string(properties, "sectionsInherit"),
+                            animations: properties
+                                .iter()
+                                .filter_map(|property| match property {
+                                    Property::Class(Class::Local {
+                                        name, properties, ..
+                                    }) if name.as_str().eq_ignore_ascii_case("animations") => {
+                                        Some(properties)
+                                    }
+                                    _ => None,
+                                })
+                                .flat_map(|properties| classes(properties))
+                                .map(|(name, parent, properties)| Animation {
+                                    name: name.clone(),
+                                    parent: parent.cloned(),
+                                    kind: string(properties, "type"),
+                                    source: string(properties, "source"),
+                                    selection: string(properties, "selection"),
+                                    axis: string(properties, "axis"),
+                                    begin: string(properties, "begin"),
+                                    end: string(properties, "end"),
+                                    memory: number(properties, "memory")
+                                        .map(|memory| memory.abs() > f64::EPSILON),
+                                })
+                                .collect(),
+                        }
+                    }));
+            }
+        }
+        model_cfg
+    }
+
+    #[must_use]
+    pub fn skeletons(&self) -> &[Skeleton] {
+        &self.skeletons
+    }
+
+    #[must_use]
+    pub fn models(&self) -> &[CfgModel] {
+        &self.models
+    }
+
+    #[must_use]
+    /// A skeleton by name, case-insensitive
+    pub fn skeleton(&self, name: &str) -> Option<&Skeleton> {
+        self.skeletons
+            .iter()
+            .rev()
+            .find(|s| s.name.as_str().eq_ignore_ascii_case(name))
+    }
+
+    #[must_use]
+    /// A model by name, case-insensitive
+    pub fn model(&self, name: &str) -> Option<&CfgModel> {
+        self.models
+            .iter()
+            .rev()
+            .find(|m| m.name.as_str().eq_ignore_ascii_case(name))
+    }
+
+    /// The bones of a skeleton, following `skeletonInherit` and class inheritance
+    ///
+    /// # Errors
+    /// [`ResolveError`] if an inherited skeleton does not exist, or inherits from itself
+    pub fn bones(&self, skeleton: &Skeleton) -> Result<Vec<Bone>, ResolveError> {
+        self.bones_inner(skeleton, &mut Vec::new())
+    }
+
+    fn bones_inner(
+        &self,
+        skeleton: &Skeleton,
+        visited: &mut Vec<String>,
+    ) -> Result<Vec<Bone>, ResolveError> {
+        visited.push(skeleton.name.as_str().to_lowercase());
+        let mut bones = if let Some(inherit) =
+            self.skeleton_property(skeleton, |s| s.inherit.as_ref())
+            && !inherit.value().is_empty()
+        {
+            if visited.contains(&inherit.value().to_lowercase()) {
+                return Err(ResolveError::CircularSkeleton(inherit.clone()));
+            }
+            let Some(inherited) = self.skeleton(inherit.value()) else {
+                return Err(ResolveError::UnknownSkeleton(inherit.clone()));
+            };
+            self.bones_inner(inherited, visited)?
+        } else {
+            Vec::new()
+        };
+        if let Some(own) = self.skeleton_property(skeleton, |s| s.bones.as_ref()) {
+            bones.extend(own.iter().cloned());
+        }
+        Ok(bones)
+    }
+
+    /// A property of a skeleton, or the nearest parent class that sets it
+    fn skeleton_property<'a, T>(
+        &'a self,
+        skeleton: &'a Skeleton,
+        property: impl Fn(&'a Skeleton) -> Option<&'a T>,
+    ) -> Option<&'a T> {
+        let mut current = skeleton;
+        for _ in 0..MAX_DEPTH {
+            if let Some(value) = property(current) {
+                return Some(value);
+            }
+            current = self.skeleton(current.parent.as_ref()?.as_str())?;
+        }
+        None
+    }
+
+    /// The chain of classes a model inherits from, starting with the model
+    fn model_chain<'a>(&'a self, model: &'a CfgModel) -> Vec<&'a CfgModel> {
+        let mut chain = vec![model];
+        let mut current = model;
+        while chain.len() < MAX_DEPTH {
+            let Some(parent) = current
+                .parent
+                .as_ref()
+                .and_then(|parent| self.model(parent.as_str()))
+            else {
+                break;
+            };
+            if std::ptr::eq(parent, current) {
+                break;
+            }
+            chain.push(parent);
+            current = parent;
+        }
+        chain
+    }
+
+    #[must_use]
+    /// The `skeletonName` of a model, following class inheritance
+    pub fn skeleton_name<'a>(&'a self, model: &'a CfgModel) -> Option<&'a Str> {
+        self.model_chain(model)
+            .into_iter()
+            .find_map(|m| m.skeleton_name.as_ref())
+    }
+
+    #[must_use]
+    /// The animations of a model, with the properties inherited from parent classes
+    pub fn animations(&self, model: &CfgModel) -> Vec<Animation> {
+        let mut animations: Vec<Animation> = Vec::new();
+        for class in self.model_chain(model).into_iter().rev() {
+            for animation in &class.animations {
+                let find = |name: &str| {
+                    animations
+                        .iter()
+                        .position(|a| a.name.as_str().eq_ignore_ascii_case(name))
+                };
+                if let Some(index) = find(animation.name.as_str()) {
+                    animations[index] = animation.inherit(&animations[index]);
+                } else if let Some(index) = animation
+                    .parent
+                    .as_ref()
+                    .and_then(|parent| find(parent.as_str()))
+                {
+                    let inherited = animation.inherit(&animations[index]);
+                    animations.push(inherited);
+                } else {
+                    animations.push(animation.clone());
+                }
+            }
+        }
+        animations
+    }
+}
+
+impl Animation {
+    /// Fill the properties not set in this class from another
+    fn inherit(&self, base: &Self) -> Self {
+        Self {
+            name: self.name.clone(),
+            parent: self.parent.clone(),
+            kind: self.kind.clone().or_else(|| base.kind.clone()),
+            source: self.source.clone().or_else(|| base.source.clone()),
+            selection: self.selection.clone().or_else(|| base.selection.clone()),
+            axis: self.axis.clone().or_else(|| base.axis.clone()),
+            begin: self.begin.clone().or_else(|| base.begin.clone()),
+            end: self.end.clone().or_else(|| base.end.clone()),
+            memory: self.memory.or(base.memory),
+        }
+    }
+
+    #[must_use]
+    /// The source of the animation, which defaults to the name of the class
+    pub fn source_name(&self) -> &str {
+        self.source
+            .as_ref()
+            .map_or_else(|| self.name.as_str(), |source| source.value())
+    }
+}
+
+impl ResolveError {
+    #[must_use]
+    /// The name of the skeleton that could not be resolved
+    pub const fn name(&self) -> &Str {
+        match self {
+            Self::UnknownSkeleton(name) | Self::CircularSkeleton(name) => name,
+        }
+    }
+}
+
+/// The classes declared in a class, with their parent and properties
+fn classes(properties: &[Property]) -> impl Iterator<Item = (&Ident, Option<&Ident>, &[Property])> {
+    properties.iter().filter_map(|property| match property {
+        Property::Class(Class::Local {
+            name,
+            parent,
+            properties,
+            ..
+        }) => Some((name, parent.as_ref(), properties.as_slice())),
+        _ => None,
+    })
+}
+
+fn entry<'a>(properties: &'a [Property], key: &str) -> Option<&'a Value> {
+    properties.iter().rev().find_map(|property| match property {
+        Property::Entry { name, value, .. } if name.as_str().eq_ignore_ascii_case(key) => {
+            Some(value)
+        }
+        _ => None,
+    })
+}
+
+fn string(properties: &[Property], key: &str) -> Option<Str> {
+    match entry(properties, key)? {
+        Value::Str(value) => Some(value.clone()),
+        Value::Number(number) => Some(Str {
+            value: number.to_string(),
+            span: number.span().clone(),
+        }),
+        _ => None,
+    }
+}
+
+fn strings(properties: &[Property], key: &str) -> Option<Vec<Str>> {
+    match entry(properties, key)? {
+        Value::Array(array) | Value::UnexpectedArray(array) => Some(
+            array
+                .items()
+                .iter()
+                .filter_map(|item| match item {
+                    Item::Str(value) => Some(value.clone()),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn number(properties: &[Property], key: &str) -> Option<f64> {
+    match entry(properties, key)? {
+        Value::Number(Number::Int32 { value, .. }) => Some(f64::from(*value)),
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "model.cfg flags are small numbers"
+        )]
+        Value::Number(Number::Int64 { value, .. }) => Some(*value as f64),
+        Value::Number(Number::Float32 { value, .. }) => Some(f64::from(*value)),
+        _ => None,
+    }
+}
@@ -0,0 +1,88 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_common::config::{PDriveOption, PreprocessorOptions};
+use hemtt_config::model_cfg::{ModelCfg, ResolveError};
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::LayerType;
+
+fn model_cfg() -> ModelCfg {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(
+            &std::path::PathBuf::from("tests/model_cfg"),
+            LayerType::Source,
+        )
+        .finish(None, false, &PDriveOption::Disallow)
+        .unwrap();
+    let processed = Processor::run(
+        &workspace.join("model.cfg").unwrap(),
+        &PreprocessorOptions::default(),
+    )
+    .unwrap();
+    let report = hemtt_config::parse(None, &processed).unwrap();
+    ModelCfg::from_config(report.config())
+}
+
+#[test]
+fn skeletons() {
+    let model_cfg = model_cfg();
+    assert_eq!(model_cfg.skeletons().len(), 5);
+    let bones = |name: &str| {
+        model_cfg
+            .bones(model_cfg.skeleton(name).unwrap())
+            .unwrap()
+            .into_iter()
+            .map(|bone| {
+                (
+                    bone.name.value().to_string(),
+                    bone.parent.value().to_string(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert!(bones("default").is_empty());
+    assert_eq!(
+        bones("ABE_BASE"),
+        [("body", ""), ("door", "body")].map(|(b, p)| (b.to_string(), p.to_string()))
+    );
+    // inherited bones come first
+    assert_eq!(
+        bones("abe_car"),
+        [("body", ""), ("door", "body"), ("wheel", "body")]
+            .map(|(b, p)| (b.to_string(), p.to_string()))
+    );
+
+    let error = model_cfg
+        .bones(model_cfg.skeleton("abe_loop_a").unwrap())
+        .unwrap_err();
+    assert!(matches!(error, ResolveError::CircularSkeleton(_)));
+    assert_eq!(error.name().value(), "abe_loop_a");
+}
+
+#[test]
+fn models() {
+    let model_cfg = model_cfg();
+    let car = model_cfg.model("abe_car").unwrap();
+    assert_eq!(model_cfg.skeleton_name(car).unwrap().value(), "abe_car");
+    let base = model_cfg.model("abe_base").unwrap();
+    assert_eq!(base.sections.as_ref().unwrap()[0].value(), "camo");
+
+    let animations = model_cfg.animations(car);
+    assert_eq!(
+        animations
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect::<Vec<_>>(),
+        ["Rotation", "door", "wheel"]
+    );
+    let door = &animations[1];
+    assert_eq!(door.kind.as_ref().unwrap().value(), "rotation");
+    assert_eq!(door.source_name(), "doorSource");
+    assert_eq!(door.selection.as_ref().unwrap().value(), "door");
+    assert_eq!(door.memory, Some(true));
+    let wheel = &animations[2];
+    assert_eq!(wheel.axis.as_ref().unwrap().value(), "wheel_axis");
+    assert_eq!(wheel.source_name(), "door");
+
+    let base_animations = model_cfg.animations(base);
+    assert_eq!(base_animations[1].source_name(), "door");
+}
@@ -17,6 +17,7 @@ pub unsafe extern "C" fn lzo1x_decompress_safe(
     in_len: usize,
     out: *mut u8,
     out_len: *mut usize,
+    in_used: *mut usize,
 ) -> i32 {
     unsafe {
         let mut current_block;
@@ -435,6 +436,7 @@ pub unsafe extern "C" fn lzo1x_decompress_safe(
                     *out_len = ((op as isize).wrapping_sub(out as isize)
                         / ::std::mem::size_of::<u8>() as isize)
                         as usize;
+                    *in_used = (ip as isize).wrapping_sub(in_ as isize) as usize;
                     return if t != 3usize {
                         -1i32
                     } else if std::ptr::eq(ip, ip_end) {
@@ -121,11 +121,13 @@ pub fn compress_to_slice<'a>(in_: &[u8], out: &'a mut [u8]) -> Result<&'a mut [u
 pub fn decompress_to_slice<'a>(in_: &[u8], out: &'a mut [u8]) -> Result<&'a mut [u8], LzoError> {
     unsafe {
         let mut out_len = out.len();
+        let mut in_used = 0;
         let err = decompress::lzo1x_decompress_safe(
             in_.as_ptr(),
             in_.len(),
             out.as_mut_ptr(),
             &raw mut out_len,
+            &raw mut in_used,
         );
         let res = mem::transmute::<i32, LzoError>(err);
         if res == LzoError::Ok {
@@ -136,6 +138,32 @@ pub fn decompress_to_slice<'a>(in_: &[u8], out: &'a mut [u8]) -> Result<&'a mut
     }
 }
 
+#[cfg(feature = "decompress")]
+/// decompresses a stream that is followed by other data, filling `out`
+/// returns the number of bytes of `in_` that were consumed
+///
+/// # Errors
+/// [`LzoError`] if an error occurs, or `out` was not filled
+pub fn decompress_stream(in_: &[u8], out: &mut [u8]) -> Result<usize, LzoError> {
+    unsafe {
+        let mut out_len = out.len();
+        let mut in_used = 0;
+        let err = decompress::lzo1x_decompress_safe(
+            in_.as_ptr(),
+            in_.len(),
+            out.as_mut_ptr(),
+            &raw mut out_len,
+            &raw mut in_used,
+        );
+        let res = mem::transmute::<i32, LzoError>(err);
+        match res {
+            LzoError::Ok | LzoError::InputNotConsumed if out_len == out.len() => Ok(in_used),
+            LzoError::Ok | LzoError::InputNotConsumed => Err(LzoError::Error),
+            _ => Err(res),
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 #[test]
@@ -171,3 +199,18 @@ fn compress_and_back() {
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[test]
+fn stream_with_trailing_data() {
+    let data = (0..4096u32).map(|i| (i % 61) as u8).collect::<Vec<_>>();
+    let mut stream = Vec::with_capacity(worst_compress(data.len()));
+    compress(&data, &mut stream).unwrap();
+    let compressed = stream.len();
+    stream.extend_from_slice(&[1, 2, 3, 4]);
+
+    let mut out = vec![0; data.len()];
+    assert_eq!(decompress_stream(&stream, &mut out).unwrap(), compressed);
+    assert_eq!(out, data);
+}
@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{LintData, ModelFile};
+
+crate::analyze::lint!(LintM01RequiredLods);
+
+/// LODs required for each `class`, when not set in the options
+const DEFAULT_REQUIRED: [(&str, &[&str]); 4] = [
+    ("default", &["Geometry"]),
+    (
+        "house",
+        &["Geometry", "Fire Geometry", "View Geometry", "Memory"],
+    ),
+    ("building", &["Geometry", "Fire Geometry", "View Geometry"]),
+    (
+        "vehicle",
+        &["Geometry", "Fire Geometry", "View Geometry", "Memory"],
+    ),
+];
+
+impl Lint<LintData> for LintM01RequiredLods {
+    fn ident(&self) -> &'static str {
+        "required_lods"
+    }
+
+    fn sort(&self) -> u32 {
+        10
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on models missing LODs required by their class"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"### Configuration
+
+Each option is a `class`, as set by the named property in the Geometry LOD, with the LODs it requires. Models without a `class` use `default`.
+
+- **default**: `["Geometry"]`
+- **house**: `["Geometry", "Fire Geometry", "View Geometry", "Memory"]`
+- **building**: `["Geometry", "Fire Geometry", "View Geometry"]`
+- **vehicle**: `["Geometry", "Fire Geometry", "View Geometry", "Memory"]`
+
+```toml
+[lints.models.required_lods]
+options.default = []
+options.thingx = ["Geometry", "Fire Geometry"]
+```
+
+### Explanation
+
+Models without a Geometry LOD can not be collided with, without a Fire Geometry they can not be shot, and without a View Geometry they do not block vision. Models only containing special LODs, such as proxies made of a Memory LOD, are not checked."#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = ModelFile;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &ModelFile,
+        _data: &LintData,
+    ) -> Codes {
+        if !target.has_resolutions() {
+            return vec![];
+        }
+        let class = target.class().unwrap_or_else(|| String::from("default"));
+        let required = required(config, &class)
+            .or_else(|| required(config, "default"))
+            .unwrap_or_default();
+        let missing = required
+            .into_iter()
+            .filter(|name| target.lod(name).is_none())
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return vec![];
+        }
+        vec![Arc::new(CodeModelRequiredLods::new(
+            target.path().to_string(),
+            class,
+            missing,
+            config.severity(),
+        ))]
+    }
+}
+
+/// The LODs required for a class, from the options or the defaults
+fn required(config: &LintConfig, class: &str) -> Option<Vec<String>> {
+    if let Some(value) = config.option(class) {
+        return Some(
+            value
+                .as_array()?
+                .iter()
+                .filter_map(|lod| lod.as_str().map(ToString::to_string))
+                .collect(),
+        );
+    }
+    DEFAULT_REQUIRED
+        .iter()
+        .find(|(name, _)| *name == class)
+        .map(|(_, lods)| lods.iter().map(ToString::to_string).collect())
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeModelRequiredLods {
+    path: String,
+    class: String,
+    missing: Vec<String>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeModelRequiredLods {
+    fn ident(&self) -> &'static str {
+        "L-M01"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/models.html#required_lods")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Model `{}` is missing LODs: {}",
+            self.path,
+            self.missing.join(", ")
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "models of class `{}` require these LODs, add them or change `required_lods` for the class",
+            self.class
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeModelRequiredLods {
+    #[must_use]
+    pub fn new(path: String, class: String, missing: Vec<String>, severity: Severity) -> Self {
+        Self {
+            path,
+            class,
+            missing,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{LintData, ModelFile};
+
+crate::analyze::lint!(LintM02GeometryMass);
+
+impl Lint<LintData> for LintM02GeometryMass {
+    fn ident(&self) -> &'static str {
+        "geometry_mass"
+    }
+
+    fn sort(&self) -> u32 {
+        20
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on models with a Geometry LOD that has no mass"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Explanation
+
+The mass of a model is the total of the masses of the points in its Geometry LOD. Models without mass can not be moved by physics, and vehicles without mass will not behave correctly. Assign a mass to the points of each component in Object Builder."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = ModelFile;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &ModelFile,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(geometry) = target.lod("Geometry") else {
+            return vec![];
+        };
+        let mass = geometry.mass();
+        if mass.is_some_and(|mass| mass > 0.0) {
+            return vec![];
+        }
+        vec![Arc::new(CodeModelGeometryMass::new(
+            target.path().to_string(),
+            mass,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeModelGeometryMass {
+    path: String,
+    mass: Option<f32>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeModelGeometryMass {
+    fn ident(&self) -> &'static str {
+        "L-M02"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/models.html#geometry_mass")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        self.mass.map_or_else(
+            || format!("Model `{}` has no mass in its Geometry LOD", self.path),
+            |mass| {
+                format!(
+                    "Model `{}` has a total mass of {} in its Geometry LOD",
+                    self.path, mass
+                )
+            },
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("assign a mass to the points of the Geometry LOD".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeModelGeometryMass {
+    #[must_use]
+    pub fn new(path: String, mass: Option<f32>, severity: Severity) -> Self {
+        Self {
+            path,
+            mass,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{LintData, ModelFile};
+
+crate::analyze::lint!(LintM03MissingProxy);
+
+impl Lint<LintData> for LintM03MissingProxy {
+    fn ident(&self) -> &'static str {
+        "missing_proxy"
+    }
+
+    fn sort(&self) -> u32 {
+        30
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on proxies that use models which do not exist"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Explanation
+
+A proxy places another model inside a model, such as a flag or a crew position. If the model used by the proxy does not exist, nothing will be shown in game. Only proxies inside the project are checked, proxies to other mods or the base game are assumed to exist."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = ModelFile;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &ModelFile,
+        data: &LintData,
+    ) -> Codes {
+        let Some(workspace) = data.workspace() else {
+            return vec![];
+        };
+        let missing = target
+            .proxies()
+            .into_iter()
+            .filter(|proxy| data.in_prefixes(proxy))
+            .filter(|proxy| matches!(workspace.locate_with_pdrive(proxy), Ok(None)))
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return vec![];
+        }
+        vec![Arc::new(CodeModelMissingProxy::new(
+            target.path().to_string(),
+            missing,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeModelMissingProxy {
+    path: String,
+    missing: Vec<String>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeModelMissingProxy {
+    fn ident(&self) -> &'static str {
+        "L-M03"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/models.html#missing_proxy")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Model `{}` has proxies to missing models: {}",
+            self.path,
+            self.missing.join(", ")
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("check the path of the proxy, or add the missing model".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeModelMissingProxy {
+    #[must_use]
+    pub fn new(path: String, missing: Vec<String>, severity: Severity) -> Self {
+        Self {
+            path,
+            missing,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
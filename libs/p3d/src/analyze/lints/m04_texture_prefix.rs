@@ -0,0 +1,155 @@
+use std::{collections::BTreeSet, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{LintData, ModelFile};
+
+/// Prefixes allowed when `allowed` is not set
+const DEFAULT_ALLOWED: [&str; 1] = ["a3"];
+
+crate::analyze::lint!(LintM04TexturePrefix);
+
+impl Lint<LintData> for LintM04TexturePrefix {
+    fn ident(&self) -> &'static str {
+        "texture_prefix"
+    }
+
+    fn sort(&self) -> u32 {
+        40
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on textures and materials outside the project prefix"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"### Configuration
+
+- **allowed**: Prefixes outside the project that may be used, default is `["a3"]`.
+
+```toml
+[lints.models.texture_prefix]
+options.allowed = ["a3", "z\\cba"]
+```
+
+### Explanation
+
+Textures and materials of a model should be inside the project, or in a mod or the base game it depends on. Paths from another project are usually left over from copying a model, and will be missing in game when that project is not loaded. Procedural textures, such as `#(argb,8,8,3)color(1,1,1,1)`, are not checked."#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = ModelFile;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &ModelFile,
+        data: &LintData,
+    ) -> Codes {
+        let allowed = if let Some(toml::Value::Array(allowed)) = config.option("allowed") {
+            allowed
+                .iter()
+                .filter_map(|a| a.as_str().map(ToString::to_string))
+                .collect()
+        } else {
+            DEFAULT_ALLOWED
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        let allowed = LintData::new(None, &allowed);
+        let outside = target
+            .p3d()
+            .lods
+            .iter()
+            .flat_map(|lod| &lod.faces)
+            .flat_map(|face| [&face.texture, &face.material])
+            .filter(|path| !path.is_empty() && !path.starts_with('#'))
+            .filter(|path| !data.in_prefixes(path) && !allowed.in_prefixes(path))
+            .map(|path| path.to_lowercase())
+            .collect::<BTreeSet<_>>();
+        if outside.is_empty() {
+            return vec![];
+        }
+        vec![Arc::new(CodeModelTexturePrefix::new(
+            target.path().to_string(),
+            outside.into_iter().collect(),
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeModelTexturePrefix {
+    path: String,
+    outside: Vec<String>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeModelTexturePrefix {
+    fn ident(&self) -> &'static str {
+        "L-M04"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/models.html#texture_prefix")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Model `{}` uses textures or materials outside the project: {}",
+            self.path,
+            self.outside.join(", ")
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(
+            "move the files into the project, or add their prefix to `allowed` if the project depends on it"
+                .to_string(),
+        )
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeModelTexturePrefix {
+    #[must_use]
+    pub fn new(path: String, outside: Vec<String>, severity: Severity) -> Self {
+        Self {
+            path,
+            outside,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{
+    LOD,
+    analyze::{LintData, ModelFile},
+};
+
+crate::analyze::lint!(LintM05Polycount);
+
+impl Lint<LintData> for LintM05Polycount {
+    fn ident(&self) -> &'static str {
+        "polycount"
+    }
+
+    fn sort(&self) -> u32 {
+        50
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on LODs with more triangles than their budget"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"### Configuration
+
+- **budgets**: The most triangles allowed in each LOD, by resolution or name. LODs without a budget are not checked, there are no budgets by default.
+
+```toml
+[lints.models.polycount]
+options.budgets = { "1" = 20000, "2" = 10000, "Geometry" = 500, "Fire Geometry" = 2000 }
+```
+
+### Explanation
+
+Every triangle of a LOD is rendered or tested for collisions. Budgets keep the detail of each LOD in line with the distance it is shown at, and the special LODs simple enough to not slow down physics. Quads count as two triangles."#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = ModelFile;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &ModelFile,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(toml::Value::Table(budgets)) = config.option("budgets") else {
+            return vec![];
+        };
+        let budgets = budgets
+            .iter()
+            .filter_map(|(lod, max)| {
+                let resolution = lod
+                    .parse::<f32>()
+                    .ok()
+                    .or_else(|| LOD::resolution_from_name(lod))?;
+                Some((resolution, max.as_integer()?))
+            })
+            .collect::<Vec<_>>();
+        target
+            .p3d()
+            .lods
+            .iter()
+            .filter_map(|lod| {
+                let max = budgets
+                    .iter()
+                    .find(|(resolution, _)| (resolution - lod.resolution).abs() < f32::EPSILON)?
+                    .1;
+                let triangles = lod
+                    .faces
+                    .iter()
+                    .map(|face| face.vertices.len().saturating_sub(2))
+                    .sum::<usize>();
+                if i64::try_from(triangles).unwrap_or(i64::MAX) <= max {
+                    return None;
+                }
+                Some(Arc::new(CodeModelPolycount::new(
+                    target.path().to_string(),
+                    lod.type_name.clone(),
+                    triangles,
+                    max,
+                    config.severity(),
+                )) as Arc<dyn Code>)
+            })
+            .collect()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeModelPolycount {
+    path: String,
+    lod: String,
+    triangles: usize,
+    max: i64,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeModelPolycount {
+    fn ident(&self) -> &'static str {
+        "L-M05"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/models.html#polycount")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "LOD `{}` of model `{}` has {} triangles, more than its budget of {}",
+            self.lod, self.path, self.triangles, self.max
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "reduce the triangles of the LOD to at most {}, or raise its budget",
+            self.max
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeModelPolycount {
+    #[must_use]
+    pub fn new(path: String, lod: String, triangles: usize, max: i64, severity: Severity) -> Self {
+        Self {
+            path,
+            lod,
+            triangles,
+            max,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::BTreeSet;
+
+use hemtt_common::config::{ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{WorkspacePath, lint::LintManager, lint_manager, reporting::Codes};
+
+use crate::{LOD, P3D};
+
+pub mod lints {
+    automod::dir!(pub "src/analyze/lints");
+}
+
+lint_manager!(model, vec![]);
+
+pub struct LintData {
+    workspace: Option<WorkspacePath>,
+    prefixes: Vec<String>,
+}
+
+impl LintData {
+    #[must_use]
+    /// Proxies are located in the workspace, they are not checked without one
+    ///
+    /// Prefixes are the project and addon prefixes, such as `z\ace` and `z\ace\addons\main`
+    pub fn new(workspace: Option<WorkspacePath>, prefixes: &[String]) -> Self {
+        Self {
+            workspace,
+            prefixes: prefixes.iter().map(|p| normalize_path(p)).collect(),
+        }
+    }
+
+    #[must_use]
+    pub const fn workspace(&self) -> Option<&WorkspacePath> {
+        self.workspace.as_ref()
+    }
+
+    #[must_use]
+    /// Is the path inside one of the prefixes
+    pub fn in_prefixes(&self, path: &str) -> bool {
+        let path = normalize_path(path);
+        self.prefixes
+            .iter()
+            .any(|prefix| path.starts_with(&format!("{prefix}\\")))
+    }
+}
+
+/// An MLOD to be linted, with the path it was read from
+pub struct ModelFile {
+    path: String,
+    p3d: P3D,
+}
+
+impl ModelFile {
+    #[must_use]
+    pub const fn new(path: String, p3d: P3D) -> Self {
+        Self { path, p3d }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn p3d(&self) -> &P3D {
+        &self.p3d
+    }
+
+    #[must_use]
+    /// A LOD by its name, such as `Geometry` or `Resolution 1`, case-insensitive
+    pub fn lod(&self, name: &str) -> Option<&LOD> {
+        self.p3d
+            .lods
+            .iter()
+            .find(|lod| lod.type_name.eq_ignore_ascii_case(name))
+    }
+
+    #[must_use]
+    /// Does the model have a visual resolution LOD
+    pub fn has_resolutions(&self) -> bool {
+        self.p3d.lods.iter().any(|lod| lod.resolution < 1000.0)
+    }
+
+    #[must_use]
+    /// The `class` named property of the Geometry LOD, in lowercase
+    pub fn class(&self) -> Option<String> {
+        self.lod("Geometry")?
+            .named_properties()
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("class"))
+            .map(|(_, value)| value.to_lowercase())
+    }
+
+    #[must_use]
+    /// The models used by proxies in any LOD, such as `\a3\data_f\proxies\flag\flag.p3d`
+    pub fn proxies(&self) -> BTreeSet<String> {
+        self.p3d
+            .lods
+            .iter()
+            .flat_map(|lod| &lod.taggs)
+            .filter_map(|(name, _)| {
+                let (kind, path) = name.split_once(':')?;
+                if !kind.eq_ignore_ascii_case("proxy") {
+                    return None;
+                }
+                // proxies are numbered with an extension, `flag.001`
+                let path = path
+                    .rsplit_once('.')
+                    .filter(|(_, index)| index.chars().all(|c| c.is_ascii_digit()))
+                    .map_or(path, |(path, _)| path);
+                let path = if path.starts_with('\\') {
+                    path.to_string()
+                } else {
+                    format!("\\{path}")
+                };
+                Some(if path.to_lowercase().ends_with(".p3d") {
+                    path
+                } else {
+                    format!("{path}.p3d")
+                })
+            })
+            .collect()
+    }
+}
+
+/// Lowercase with backslashes and no leading separator
+fn normalize_path(path: &str) -> String {
+    path.replace('/', "\\")
+        .trim_start_matches('\\')
+        .trim_end_matches('\\')
+        .to_lowercase()
+}
+
+pub fn lint_one(
+    model: &ModelFile,
+    project_config: Option<&ProjectConfig>,
+    data: &LintData,
+) -> Codes {
+    let mut manager = LintManager::new(
+        project_config.map_or_else(Default::default, |project| project.lints().models().clone()),
+        project_config.map_or_else(RuntimeArguments::default, |p| p.runtime().clone()),
+    );
+    if let Err(e) = manager.extend(
+        MODEL_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return e;
+    }
+    manager.run(data, project_config, None, model)
+}
@@ -8,10 +8,22 @@ pub enum Error {
     #[error("Unsupported p3d type: {0}")]
     /// Unsupported p3d type
     UnsupportedP3DType(String),
+    #[error("Unsupported ODOL version: {0}")]
+    /// Unsupported ODOL version
+    UnsupportedODOLVersion(u32),
+    #[error("Invalid ODOL: {0}")]
+    /// Invalid ODOL
+    InvalidODOL(String),
+    #[error("Invalid mesh: {0}")]
+    /// A glTF or OBJ mesh could not be imported
+    InvalidMesh(String),
     #[error("Unsupported lod type: {0}")]
     /// Unsupported lod type
     UnsupportedLODType(String),
 
+    #[error("Invalid TAGG: {0}")]
+    /// A typed TAGG could not be written
+    InvalidTagg(String),
     #[error("Unexpected bytes where `TAGG` expected: {0}")]
     /// Unexpected bytes where `TAGG` expected
     UnexpectedBytesTagg(String),
@@ -0,0 +1,203 @@
+use serde_json::{Value, json};
+
+use super::Mesh;
+
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+
+#[derive(Default)]
+struct Builder {
+    buffer: Vec<u8>,
+    views: Vec<Value>,
+    accessors: Vec<Value>,
+    materials: Vec<(String, String)>,
+    textures: Vec<String>,
+}
+
+impl Builder {
+    fn accessor(&mut self, data: &[u8], target: u32, mut accessor: Value) -> usize {
+        self.views.push(json!({
+            "buffer": 0,
+            "byteOffset": self.buffer.len(),
+            "byteLength": data.len(),
+            "target": target,
+        }));
+        self.buffer.extend_from_slice(data);
+        accessor["bufferView"] = json!(self.views.len() - 1);
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+
+    fn floats<const N: usize>(&mut self, values: &[[f32; N]], kind: &str, bounds: bool) -> usize {
+        let data = values
+            .iter()
+            .flatten()
+            .flat_map(|f| f.to_le_bytes())
+            .collect::<Vec<_>>();
+        let mut accessor = json!({
+            "componentType": FLOAT,
+            "count": values.len(),
+            "type": kind,
+        });
+        if bounds {
+            let mut min = [f32::MAX; N];
+            let mut max = [f32::MIN; N];
+            for value in values {
+                for i in 0..N {
+                    min[i] = min[i].min(value[i]);
+                    max[i] = max[i].max(value[i]);
+                }
+            }
+            accessor["min"] = json!(min.to_vec());
+            accessor["max"] = json!(max.to_vec());
+        }
+        self.accessor(&data, ARRAY_BUFFER, accessor)
+    }
+
+    fn indices(&mut self, indices: &[u32]) -> usize {
+        let data = indices
+            .iter()
+            .flat_map(|i| i.to_le_bytes())
+            .collect::<Vec<_>>();
+        self.accessor(
+            &data,
+            ELEMENT_ARRAY_BUFFER,
+            json!({
+                "componentType": UNSIGNED_INT,
+                "count": indices.len(),
+                "type": "SCALAR",
+            }),
+        )
+    }
+
+    fn material(&mut self, texture: &str, material: &str) -> usize {
+        let key = (texture.to_string(), material.to_string());
+        self.materials
+            .iter()
+            .position(|m| *m == key)
+            .unwrap_or_else(|| {
+                self.materials.push(key);
+                self.materials.len() - 1
+            })
+    }
+
+    fn texture(&mut self, uri: String) -> usize {
+        self.textures
+            .iter()
+            .position(|t| *t == uri)
+            .unwrap_or_else(|| {
+                self.textures.push(uri);
+                self.textures.len() - 1
+            })
+    }
+}
+
+#[must_use]
+/// Export meshes to glTF, returning the JSON document and the binary buffer
+///
+/// The document references the buffer as `buffer_uri`.
+/// `texture_uri` maps a face texture to the image it should use, if any.
+pub fn to_gltf(
+    meshes: &[Mesh],
+    buffer_uri: &str,
+    texture_uri: impl Fn(&str) -> Option<String>,
+) -> (String, Vec<u8>) {
+    let mut builder = Builder::default();
+    let mut nodes = Vec::with_capacity(meshes.len());
+    let mut gltf_meshes = Vec::new();
+    for mesh in meshes {
+        let mut node = json!({
+            "name": mesh.name,
+            "extras": {
+                "resolution": mesh.resolution,
+                "selections": mesh.selections,
+            },
+        });
+        if !mesh.primitives.is_empty() {
+            let primitives = mesh
+                .primitives
+                .iter()
+                .map(|primitive| {
+                    json!({
+                        "attributes": {
+                            "POSITION": builder.floats(&primitive.positions, "VEC3", true),
+                            "NORMAL": builder.floats(&primitive.normals, "VEC3", false),
+                            "TEXCOORD_0": builder.floats(&primitive.uvs, "VEC2", false),
+                        },
+                        "indices": builder.indices(&primitive.indices),
+                        "material": builder.material(&primitive.texture, &primitive.material),
+                    })
+                })
+                .collect::<Vec<_>>();
+            node["mesh"] = json!(gltf_meshes.len());
+            gltf_meshes.push(json!({
+                "name": mesh.name,
+                "primitives": primitives,
+            }));
+        }
+        nodes.push(node);
+    }
+
+    let materials = builder
+        .materials
+        .clone()
+        .into_iter()
+        .map(|(texture, material)| {
+            let name = if material.is_empty() {
+                &texture
+            } else {
+                &material
+            };
+            let mut pbr = json!({ "metallicFactor": 0.0 });
+            if let Some(uri) = texture_uri(&texture) {
+                pbr["baseColorTexture"] = json!({ "index": builder.texture(uri) });
+            }
+            json!({
+                "name": name,
+                "pbrMetallicRoughness": pbr,
+                "extras": {
+                    "texture": texture,
+                    "material": material,
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut document = json!({
+        "asset": {
+            "version": "2.0",
+            "generator": "HEMTT",
+        },
+        "scene": 0,
+        "scenes": [{ "nodes": (0..nodes.len()).collect::<Vec<_>>() }],
+        "nodes": nodes,
+    });
+    // glTF does not allow empty arrays, so they are only added with a mesh
+    if !gltf_meshes.is_empty() {
+        document["meshes"] = json!(gltf_meshes);
+        document["materials"] = json!(materials);
+        document["accessors"] = json!(builder.accessors);
+        document["bufferViews"] = json!(builder.views);
+        document["buffers"] = json!([{
+            "uri": buffer_uri,
+            "byteLength": builder.buffer.len(),
+        }]);
+    }
+    if !builder.textures.is_empty() {
+        document["images"] = json!(
+            builder
+                .textures
+                .iter()
+                .map(|uri| json!({ "uri": uri }))
+                .collect::<Vec<_>>()
+        );
+        document["textures"] = json!(
+            (0..builder.textures.len())
+                .map(|i| json!({ "source": i }))
+                .collect::<Vec<_>>()
+        );
+    }
+    (format!("{document:#}"), builder.buffer)
+}
@@ -0,0 +1,139 @@
+//! Export MLOD LODs to common mesh formats
+//!
+//! Arma uses a left handed coordinate system with inward facing normals,
+//! meshes are mirrored on the Z axis and their normals flipped to match glTF and OBJ.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use crate::LOD;
+
+mod gltf;
+mod obj;
+
+pub use gltf::to_gltf;
+pub use obj::to_obj;
+
+#[derive(Debug, Default, PartialEq)]
+/// A triangulated LOD, ready to be exported
+pub struct Mesh {
+    pub name: String,
+    pub resolution: f32,
+    /// Faces grouped by their texture and material
+    pub primitives: Vec<Primitive>,
+    pub selections: BTreeMap<String, Selection>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+/// Triangles sharing a texture and material
+pub struct Primitive {
+    pub texture: String,
+    pub material: String,
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub uvs: Vec<[f32; 2]>,
+    pub indices: Vec<u32>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+/// Points and faces of a named selection, as indices into the LOD
+pub struct Selection {
+    pub points: Vec<u32>,
+    pub faces: Vec<u32>,
+}
+
+type VertexKey = (u32, u32, u32, u32);
+
+impl LOD {
+    #[must_use]
+    /// Triangulate the LOD, grouping faces by texture and material
+    ///
+    /// Faces that reference missing points are skipped
+    pub fn mesh(&self) -> Mesh {
+        // each primitive with its vertices, keyed by point, normal and uv
+        let mut groups: Vec<(Primitive, HashMap<VertexKey, u32>)> = Vec::new();
+        for face in &self.faces {
+            if face
+                .vertices
+                .iter()
+                .any(|v| v.point_index as usize >= self.points.len())
+            {
+                continue;
+            }
+            let group = groups
+                .iter()
+                .position(|(p, _)| p.texture == face.texture && p.material == face.material)
+                .unwrap_or_else(|| {
+                    groups.push((
+                        Primitive {
+                            texture: face.texture.clone(),
+                            material: face.material.clone(),
+                            ..Default::default()
+                        },
+                        HashMap::new(),
+                    ));
+                    groups.len() - 1
+                });
+            let (primitive, lookup) = &mut groups[group];
+            let indices = face
+                .vertices
+                .iter()
+                .map(|vertex| {
+                    let key = (
+                        vertex.point_index,
+                        vertex.normal_index,
+                        vertex.uv.0.to_bits(),
+                        vertex.uv.1.to_bits(),
+                    );
+                    *lookup.entry(key).or_insert_with(|| {
+                        let point = self.points[vertex.point_index as usize].coords;
+                        let normal = self
+                            .face_normals
+                            .get(vertex.normal_index as usize)
+                            .map_or([0.0, 1.0, 0.0], |n| [-n.0, -n.1, n.2]);
+                        primitive.positions.push([point.0, point.1, -point.2]);
+                        primitive.normals.push(normalize(normal));
+                        primitive.uvs.push([vertex.uv.0, vertex.uv.1]);
+                        u32::try_from(primitive.positions.len() - 1).unwrap_or(u32::MAX)
+                    })
+                })
+                .collect::<Vec<_>>();
+            primitive
+                .indices
+                .extend_from_slice(&[indices[0], indices[1], indices[2]]);
+            if let Some(fourth) = indices.get(3) {
+                primitive
+                    .indices
+                    .extend_from_slice(&[indices[0], indices[2], *fourth]);
+            }
+        }
+        Mesh {
+            name: self.type_name.clone(),
+            resolution: self.resolution,
+            primitives: groups.into_iter().map(|(p, _)| p).collect(),
+            selections: self
+                .named_selections()
+                .into_iter()
+                .map(|(name, selection)| {
+                    (
+                        name,
+                        Selection {
+                            points: selection.points.into_keys().collect(),
+                            faces: selection.faces.into_keys().collect(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+pub(crate) fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let length = v[2].mul_add(v[2], v[0].mul_add(v[0], v[1] * v[1])).sqrt();
+    if length == 0.0 {
+        [0.0, 1.0, 0.0]
+    } else {
+        [v[0] / length, v[1] / length, v[2] / length]
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt::Write;
+
+use super::Mesh;
+
+#[must_use]
+/// Export meshes to OBJ, returning the OBJ and MTL documents
+///
+/// The OBJ references the MTL as `mtl_uri`, each mesh becomes an object.
+/// `texture_uri` maps a face texture to the image it should use, if any.
+pub fn to_obj(
+    meshes: &[Mesh],
+    mtl_uri: &str,
+    texture_uri: impl Fn(&str) -> Option<String>,
+) -> (String, String) {
+    let mut obj = String::new();
+    let mut mtl = String::new();
+    let mut materials: Vec<(&str, &str)> = Vec::new();
+    let _ = writeln!(obj, "# Exported by HEMTT\nmtllib {mtl_uri}");
+    let mut offset = 1;
+    for mesh in meshes {
+        let _ = writeln!(obj, "o {}", mesh.name.replace(' ', "_"));
+        let _ = writeln!(obj, "# resolution {}", mesh.resolution);
+        for primitive in &mesh.primitives {
+            let key = (primitive.texture.as_str(), primitive.material.as_str());
+            let material = materials.iter().position(|m| *m == key).unwrap_or_else(|| {
+                materials.push(key);
+                materials.len() - 1
+            });
+            for p in &primitive.positions {
+                let _ = writeln!(obj, "v {} {} {}", p[0], p[1], p[2]);
+            }
+            for n in &primitive.normals {
+                let _ = writeln!(obj, "vn {} {} {}", n[0], n[1], n[2]);
+            }
+            for uv in &primitive.uvs {
+                let _ = writeln!(obj, "vt {} {}", uv[0], 1.0 - uv[1]);
+            }
+            let _ = writeln!(obj, "usemtl material_{material}");
+            for triangle in primitive.indices.chunks_exact(3) {
+                let _ = write!(obj, "f");
+                for index in triangle {
+                    let index = index + offset;
+                    let _ = write!(obj, " {index}/{index}/{index}");
+                }
+                let _ = writeln!(obj);
+            }
+            offset += u32::try_from(primitive.positions.len()).unwrap_or(u32::MAX);
+        }
+    }
+
+    let _ = writeln!(mtl, "# Exported by HEMTT");
+    for (i, (texture, material)) in materials.into_iter().enumerate() {
+        let _ = writeln!(mtl, "\nnewmtl material_{i}");
+        let _ = writeln!(mtl, "# texture {texture}\n# material {material}");
+        let _ = writeln!(mtl, "Kd 1 1 1");
+        if let Some(uri) = texture_uri(texture) {
+            let _ = writeln!(mtl, "map_Kd {uri}");
+        }
+    }
+    (obj, mtl)
+}
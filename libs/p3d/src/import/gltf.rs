@@ -0,0 +1,468 @@
+use base64::Engine;
+use serde_json::Value;
+
+use super::{Corner, MaterialPaths, Object, Scene, Triangle, normalize};
+use crate::Error;
+
+const GLB_MAGIC: &[u8; 4] = b"glTF";
+const GLB_BIN: u32 = 0x004E_4942;
+const TRIANGLES: u64 = 4;
+const IDENTITY: Matrix = [
+    1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+];
+/// Deepest node hierarchy followed, guarding against cycles
+const MAX_DEPTH: usize = 64;
+
+/// Column major 4x4 matrix
+type Matrix = [f32; 16];
+
+/// Read a glTF or binary GLB document
+///
+/// `load` reads the external buffers referenced by their URI, data URIs are decoded directly.
+/// Every node with a mesh becomes an object named after the node, with the node transforms applied.
+/// Only triangle primitives are imported.
+///
+/// # Errors
+/// [`Error::InvalidMesh`] if the document is invalid or a buffer can not be loaded
+pub fn from_gltf(data: &[u8], load: impl Fn(&str) -> Option<Vec<u8>>) -> Result<Scene, Error> {
+    let (json, bin) = if data.starts_with(GLB_MAGIC) {
+        split_glb(data)?
+    } else {
+        (data, None)
+    };
+    let document: Value = serde_json::from_slice(json)
+        .map_err(|e| Error::InvalidMesh(format!("glTF is not valid JSON: {e}")))?;
+    let buffers = array(&document["buffers"])
+        .iter()
+        .enumerate()
+        .map(|(i, buffer)| {
+            buffer["uri"]
+                .as_str()
+                .map_or_else(|| bin.map(<[u8]>::to_vec), |uri| read_uri(uri, &load))
+                .ok_or_else(|| Error::InvalidMesh(format!("buffer {i} could not be loaded")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let gltf = Gltf {
+        document: &document,
+        buffers,
+        materials: material_names(&document),
+    };
+
+    let mut scene = Scene::default();
+    for (material, name) in array(&document["materials"]).iter().zip(&gltf.materials) {
+        let texture = material["extras"]["texture"].as_str();
+        let rvmat = material["extras"]["material"].as_str();
+        if texture.is_some() || rvmat.is_some() {
+            scene.materials.insert(
+                name.clone(),
+                MaterialPaths {
+                    texture: texture.unwrap_or_default().to_string(),
+                    material: rvmat.unwrap_or_default().to_string(),
+                },
+            );
+        }
+    }
+    for root in gltf.roots() {
+        gltf.node(root, &IDENTITY, 0, &mut scene)?;
+    }
+    Ok(scene)
+}
+
+struct Gltf<'a> {
+    document: &'a Value,
+    buffers: Vec<Vec<u8>>,
+    /// Unique name of each material
+    materials: Vec<String>,
+}
+
+impl Gltf<'_> {
+    /// Nodes of the default scene, or every node that is not a child if there are no scenes
+    fn roots(&self) -> Vec<usize> {
+        let scenes = array(&self.document["scenes"]);
+        if let Some(scene) = scenes.get(
+            self.document["scene"]
+                .as_u64()
+                .and_then(|s| usize::try_from(s).ok())
+                .unwrap_or_default(),
+        ) {
+            return indices(&scene["nodes"]);
+        }
+        let nodes = array(&self.document["nodes"]);
+        let children = nodes
+            .iter()
+            .flat_map(|node| indices(&node["children"]))
+            .collect::<Vec<_>>();
+        (0..nodes.len()).filter(|i| !children.contains(i)).collect()
+    }
+
+    fn node(
+        &self,
+        index: usize,
+        parent: &Matrix,
+        depth: usize,
+        scene: &mut Scene,
+    ) -> Result<(), Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error::InvalidMesh(format!(
+                "node hierarchy deeper than {MAX_DEPTH}"
+            )));
+        }
+        let node = array(&self.document["nodes"])
+            .get(index)
+            .ok_or_else(|| Error::InvalidMesh(format!("node {index} does not exist")))?;
+        let world = multiply(parent, &local_matrix(node));
+        if let Some(mesh) = node["mesh"].as_u64() {
+            let mesh = &self.document["meshes"][usize::try_from(mesh).unwrap_or(usize::MAX)];
+            if mesh.is_null() {
+                return Err(Error::InvalidMesh(format!(
+                    "node {index} references a missing mesh"
+                )));
+            }
+            let name = node["name"]
+                .as_str()
+                .or_else(|| mesh["name"].as_str())
+                .map_or_else(|| format!("node_{index}"), ToString::to_string);
+            let mut triangles = Vec::new();
+            for primitive in array(&mesh["primitives"]) {
+                self.primitive(primitive, &world, &mut triangles)?;
+            }
+            scene.objects.push(Object { name, triangles });
+        }
+        for child in indices(&node["children"]) {
+            self.node(child, &world, depth + 1, scene)?;
+        }
+        Ok(())
+    }
+
+    fn primitive(
+        &self,
+        primitive: &Value,
+        world: &Matrix,
+        triangles: &mut Vec<Triangle>,
+    ) -> Result<(), Error> {
+        if primitive["mode"].as_u64().unwrap_or(TRIANGLES) != TRIANGLES {
+            return Ok(());
+        }
+        let attributes = &primitive["attributes"];
+        let positions = self.floats::<3>(&attributes["POSITION"])?;
+        let normals = if attributes["NORMAL"].is_null() {
+            None
+        } else {
+            Some(self.floats::<3>(&attributes["NORMAL"])?)
+        };
+        let uvs = if attributes["TEXCOORD_0"].is_null() {
+            None
+        } else {
+            Some(self.floats::<2>(&attributes["TEXCOORD_0"])?)
+        };
+        let indices = if primitive["indices"].is_null() {
+            (0..u32::try_from(positions.len()).unwrap_or(u32::MAX)).collect()
+        } else {
+            self.indices(&primitive["indices"])?
+        };
+        let material = primitive["material"]
+            .as_u64()
+            .and_then(|m| usize::try_from(m).ok())
+            .and_then(|m| self.materials.get(m))
+            .cloned()
+            .unwrap_or_default();
+        // a mirroring transform turns the winding around
+        let mirrored = determinant(world) < 0.0;
+        for triangle in indices.chunks_exact(3) {
+            let mut corners = [Corner::default(); 3];
+            for (corner, index) in corners.iter_mut().zip(triangle) {
+                let index = *index as usize;
+                let position = positions
+                    .get(index)
+                    .ok_or_else(|| Error::InvalidMesh(format!("index {index} is out of bounds")))?;
+                corner.position = transform(world, *position, 1.0);
+                corner.normal = normals
+                    .as_ref()
+                    .and_then(|n| n.get(index))
+                    .map(|n| normalize(transform(world, *n, 0.0)));
+                corner.uv = uvs
+                    .as_ref()
+                    .and_then(|uv| uv.get(index))
+                    .copied()
+                    .unwrap_or_default();
+            }
+            if mirrored {
+                corners.swap(1, 2);
+            }
+            triangles.push(Triangle {
+                material: material.clone(),
+                corners,
+            });
+        }
+        Ok(())
+    }
+
+    fn accessor(&self, index: &Value) -> Result<Accessor<'_>, Error> {
+        let invalid = |message: &str| Error::InvalidMesh(format!("accessor {index}: {message}"));
+        let accessor =
+            &self.document["accessors"][as_index(index).ok_or_else(|| invalid("invalid index"))?];
+        let view = &self.document["bufferViews"]
+            [as_index(&accessor["bufferView"]).ok_or_else(|| invalid("no buffer view"))?];
+        let buffer = self
+            .buffers
+            .get(as_index(&view["buffer"]).ok_or_else(|| invalid("no buffer"))?)
+            .ok_or_else(|| invalid("missing buffer"))?;
+        let view_start = as_index(&view["byteOffset"]).unwrap_or_default();
+        let view_length = as_index(&view["byteLength"]).ok_or_else(|| invalid("no length"))?;
+        let data = buffer
+            .get(view_start..view_start.saturating_add(view_length))
+            .ok_or_else(|| invalid("buffer view is out of bounds"))?;
+        let component_type = accessor["componentType"].as_u64().unwrap_or_default();
+        let component_size = match component_type {
+            5120 | 5121 => 1,
+            5122 | 5123 => 2,
+            5125 | 5126 => 4,
+            _ => return Err(invalid("unknown component type")),
+        };
+        let components = match accessor["type"].as_str() {
+            Some("SCALAR") => 1,
+            Some("VEC2") => 2,
+            Some("VEC3") => 3,
+            Some("VEC4") => 4,
+            _ => return Err(invalid("unsupported type")),
+        };
+        let count = as_index(&accessor["count"]).ok_or_else(|| invalid("no count"))?;
+        let accessor = Accessor {
+            data,
+            offset: as_index(&accessor["byteOffset"]).unwrap_or_default(),
+            stride: as_index(&view["byteStride"]).unwrap_or(component_size * components),
+            count,
+            component_type,
+            component_size,
+            components,
+            normalized: accessor["normalized"].as_bool().unwrap_or_default(),
+        };
+        if count > 0 {
+            let end = (count - 1)
+                .checked_mul(accessor.stride)
+                .and_then(|e| e.checked_add(accessor.offset + component_size * components));
+            if end.is_none_or(|end| end > data.len()) {
+                return Err(invalid("elements are out of bounds"));
+            }
+        }
+        Ok(accessor)
+    }
+
+    fn floats<const N: usize>(&self, index: &Value) -> Result<Vec<[f32; N]>, Error> {
+        let accessor = self.accessor(index)?;
+        if accessor.components != N {
+            return Err(Error::InvalidMesh(format!(
+                "accessor {index}: expected {N} components"
+            )));
+        }
+        Ok((0..accessor.count)
+            .map(|i| std::array::from_fn(|c| accessor.float(i, c)))
+            .collect())
+    }
+
+    fn indices(&self, index: &Value) -> Result<Vec<u32>, Error> {
+        let accessor = self.accessor(index)?;
+        if accessor.components != 1 || !matches!(accessor.component_type, 5121 | 5123 | 5125) {
+            return Err(Error::InvalidMesh(format!(
+                "accessor {index}: indices must be unsigned integers"
+            )));
+        }
+        Ok((0..accessor.count).map(|i| accessor.int(i)).collect())
+    }
+}
+
+struct Accessor<'a> {
+    data: &'a [u8],
+    offset: usize,
+    stride: usize,
+    count: usize,
+    component_type: u64,
+    component_size: usize,
+    components: usize,
+    normalized: bool,
+}
+
+impl Accessor<'_> {
+    /// Bytes of a component, the accessor is bounds checked when created
+    fn bytes<const N: usize>(&self, element: usize, component: usize) -> [u8; N] {
+        let start = self.offset + element * self.stride + component * self.component_size;
+        std::array::from_fn(|i| self.data[start + i])
+    }
+
+    fn float(&self, element: usize, component: usize) -> f32 {
+        let (value, max) = match self.component_type {
+            5120 => (
+                f32::from(i8::from_le_bytes(self.bytes(element, component))),
+                127.0,
+            ),
+            5121 => (
+                f32::from(u8::from_le_bytes(self.bytes(element, component))),
+                255.0,
+            ),
+            5122 => (
+                f32::from(i16::from_le_bytes(self.bytes(element, component))),
+                32767.0,
+            ),
+            5123 => (
+                f32::from(u16::from_le_bytes(self.bytes(element, component))),
+                65535.0,
+            ),
+            _ => return f32::from_le_bytes(self.bytes(element, component)),
+        };
+        if self.normalized {
+            (value / max).max(-1.0)
+        } else {
+            value
+        }
+    }
+
+    fn int(&self, element: usize) -> u32 {
+        match self.component_type {
+            5121 => u32::from(u8::from_le_bytes(self.bytes(element, 0))),
+            5123 => u32::from(u16::from_le_bytes(self.bytes(element, 0))),
+            _ => u32::from_le_bytes(self.bytes(element, 0)),
+        }
+    }
+}
+
+/// Split a GLB into its JSON chunk and optional binary chunk
+fn split_glb(data: &[u8]) -> Result<(&[u8], Option<&[u8]>), Error> {
+    let invalid = || Error::InvalidMesh("GLB is truncated".to_string());
+    let chunk = |offset: usize| -> Result<(u32, &[u8], usize), Error> {
+        let header = data.get(offset..offset + 8).ok_or_else(invalid)?;
+        let length = u32::from_le_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let kind = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+        let end = offset + 8 + length;
+        Ok((kind, data.get(offset + 8..end).ok_or_else(invalid)?, end))
+    };
+    // 12 byte header of magic, version and length
+    let (_, json, end) = chunk(12)?;
+    let bin = if data.len() > end {
+        let (kind, bin, _) = chunk(end)?;
+        (kind == GLB_BIN).then_some(bin)
+    } else {
+        None
+    };
+    Ok((json, bin))
+}
+
+fn read_uri(uri: &str, load: impl Fn(&str) -> Option<Vec<u8>>) -> Option<Vec<u8>> {
+    if let Some(data) = uri.strip_prefix("data:") {
+        let (_, encoded) = data.split_once(";base64,")?;
+        return base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .ok();
+    }
+    load(&uri.replace("%20", " "))
+}
+
+/// Names of the materials, unnamed materials are named by index
+/// and repeated names are made unique with the index
+fn material_names(document: &Value) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for (index, material) in array(&document["materials"]).iter().enumerate() {
+        let name = match material["name"].as_str() {
+            Some(name) if !names.iter().any(|n| n == name) => name.to_string(),
+            Some(name) => format!("{name}_{index}"),
+            None => format!("material_{index}"),
+        };
+        names.push(name);
+    }
+    names
+}
+
+fn array(value: &Value) -> &[Value] {
+    value.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+fn as_index(value: &Value) -> Option<usize> {
+    value.as_u64().and_then(|v| usize::try_from(v).ok())
+}
+
+fn indices(value: &Value) -> Vec<usize> {
+    array(value).iter().filter_map(as_index).collect()
+}
+
+fn numbers<const N: usize>(value: &Value) -> Option<[f32; N]> {
+    let values = value.as_array()?;
+    if values.len() != N {
+        return None;
+    }
+    let mut numbers = [0.0; N];
+    for (number, value) in numbers.iter_mut().zip(values) {
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "glTF stores single precision values"
+        )]
+        {
+            *number = value.as_f64()? as f32;
+        }
+    }
+    Some(numbers)
+}
+
+/// The transform of a node, from its matrix or its translation, rotation and scale
+fn local_matrix(node: &Value) -> Matrix {
+    if let Some(matrix) = numbers::<16>(&node["matrix"]) {
+        return matrix;
+    }
+    let [tx, ty, tz] = numbers(&node["translation"]).unwrap_or([0.0; 3]);
+    let [x, y, z, w] = numbers(&node["rotation"]).unwrap_or([0.0, 0.0, 0.0, 1.0]);
+    let [sx, sy, sz] = numbers(&node["scale"]).unwrap_or([1.0; 3]);
+    let rotation = [
+        [
+            2.0f32.mul_add(-y.mul_add(y, z * z), 1.0),
+            2.0 * x.mul_add(y, z * w),
+            2.0 * x.mul_add(z, -(y * w)),
+        ],
+        [
+            2.0 * x.mul_add(y, -(z * w)),
+            2.0f32.mul_add(-x.mul_add(x, z * z), 1.0),
+            2.0 * y.mul_add(z, x * w),
+        ],
+        [
+            2.0 * x.mul_add(z, y * w),
+            2.0 * y.mul_add(z, -(x * w)),
+            2.0f32.mul_add(-x.mul_add(x, y * y), 1.0),
+        ],
+    ];
+    let scale = [sx, sy, sz];
+    let mut matrix = IDENTITY;
+    for (column, (axis, scale)) in rotation.iter().zip(scale).enumerate() {
+        for (row, value) in axis.iter().enumerate() {
+            matrix[column * 4 + row] = value * scale;
+        }
+    }
+    matrix[12] = tx;
+    matrix[13] = ty;
+    matrix[14] = tz;
+    matrix
+}
+
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    std::array::from_fn(|i| {
+        let (column, row) = (i / 4, i % 4);
+        (0..4).fold(0.0, |sum, k| a[k * 4 + row].mul_add(b[column * 4 + k], sum))
+    })
+}
+
+/// Transform a position with `w` of 1, or a direction with `w` of 0
+fn transform(matrix: &Matrix, v: [f32; 3], w: f32) -> [f32; 3] {
+    std::array::from_fn(|row| {
+        matrix[row].mul_add(
+            v[0],
+            matrix[4 + row].mul_add(v[1], matrix[8 + row].mul_add(v[2], matrix[12 + row] * w)),
+        )
+    })
+}
+
+/// Determinant of the rotation and scale of the matrix
+fn determinant(m: &Matrix) -> f32 {
+    m[0].mul_add(
+        m[5].mul_add(m[10], -(m[9] * m[6])),
+        (-m[4]).mul_add(
+            m[1].mul_add(m[10], -(m[9] * m[2])),
+            m[8] * m[1].mul_add(m[6], -(m[5] * m[2])),
+        ),
+    )
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use super::{cross, intern, new_lod, sub};
+use crate::{Face, LOD, NamedSelection, Vertex, export::normalize};
+
+impl LOD {
+    #[must_use]
+    /// A Geometry LOD from the convex hull of the points of this LOD
+    ///
+    /// The hull is a single component, `Component01`, with `mass` spread evenly over its points.
+    /// Returns `None` if the points are flat and do not enclose a volume.
+    pub fn convex_geometry(&self, mass: f32) -> Option<Self> {
+        let positions = self
+            .points
+            .iter()
+            .map(|p| [p.coords.0, p.coords.1, p.coords.2])
+            .collect::<Vec<_>>();
+        let hull = convex_hull(&positions)?;
+
+        let mut points = Vec::new();
+        let mut lookup = HashMap::new();
+        let mut normals = Vec::new();
+        let mut faces = Vec::with_capacity(hull.len());
+        for [a, b, c] in hull {
+            let outward = normalize(cross(
+                sub(positions[b], positions[a]),
+                sub(positions[c], positions[a]),
+            ));
+            normals.push(outward.map(|n| -n));
+            let normal_index = u32::try_from(normals.len() - 1).unwrap_or(u32::MAX);
+            let mut face = Face::new();
+            // faces are wound the other way around, facing inward like their normals
+            for index in [a, c, b] {
+                face.vertices.push(Vertex {
+                    point_index: intern(&mut points, &mut lookup, positions[index]),
+                    normal_index,
+                    uv: (0.0, 0.0),
+                });
+            }
+            faces.push(face);
+        }
+
+        let component = NamedSelection {
+            points: (0..).zip(vec![1.0; points.len()]).collect(),
+            faces: (0..).zip(vec![1.0; faces.len()]).collect(),
+        };
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "a hull will not have millions of points"
+        )]
+        let point_mass = mass / points.len() as f32;
+        let mut geometry = new_lod(1e13, &points, &normals, faces);
+        geometry
+            .set_named_selection("Component01", &component)
+            .ok()?;
+        geometry.set_masses(&vec![point_mass; points.len()]).ok()?;
+        Some(geometry)
+    }
+}
+
+/// Triangles of the convex hull, wound counter clockwise when seen from outside
+///
+/// Built incrementally, each point outside the hull replaces the faces it can see
+/// with a fan of faces from the edges around them.
+fn convex_hull(positions: &[[f32; 3]]) -> Option<Vec<[usize; 3]>> {
+    let points = positions
+        .iter()
+        .map(|p| p.map(f64::from))
+        .collect::<Vec<_>>();
+    let extent = points
+        .iter()
+        .flatten()
+        .fold(0.0f64, |extent, v| extent.max(v.abs()));
+    let epsilon = extent.max(1.0) * 1e-9;
+
+    // the starting tetrahedron, from points far apart from each other
+    let first = (0..points.len()).min_by(|a, b| points[*a][0].total_cmp(&points[*b][0]))?;
+    let second = farthest(&points, |p| length(sub64(p, points[first])))?;
+    let third = farthest(&points, |p| {
+        length(cross64(
+            sub64(points[second], points[first]),
+            sub64(p, points[first]),
+        ))
+    })?;
+    let fourth = farthest(&points, |p| {
+        distance(&points, [first, second, third], p).abs()
+    })?;
+    if distance(&points, [first, second, third], points[fourth]).abs() <= epsilon
+        || length(cross64(
+            sub64(points[second], points[first]),
+            sub64(points[third], points[first]),
+        )) <= epsilon
+    {
+        return None;
+    }
+    let mut faces = [
+        [first, second, third],
+        [first, third, fourth],
+        [first, fourth, second],
+        [second, fourth, third],
+    ]
+    .into_iter()
+    .map(|face| {
+        let opposite = [first, second, third, fourth]
+            .into_iter()
+            .find(|i| !face.contains(i))
+            .unwrap_or(first);
+        if distance(&points, face, points[opposite]) > 0.0 {
+            [face[0], face[2], face[1]]
+        } else {
+            face
+        }
+    })
+    .collect::<Vec<_>>();
+
+    for (index, point) in points.iter().enumerate() {
+        let (visible, hidden): (Vec<_>, Vec<_>) = faces
+            .into_iter()
+            .partition(|face| distance(&points, *face, *point) > epsilon);
+        faces = hidden;
+        if visible.is_empty() {
+            continue;
+        }
+        let edges = visible
+            .iter()
+            .flat_map(|[a, b, c]| [(*a, *b), (*b, *c), (*c, *a)])
+            .collect::<Vec<_>>();
+        for (a, b) in &edges {
+            if !edges.contains(&(*b, *a)) {
+                faces.push([*a, *b, index]);
+            }
+        }
+    }
+    Some(faces)
+}
+
+/// Index of the point with the largest `metric`
+fn farthest(points: &[[f64; 3]], metric: impl Fn([f64; 3]) -> f64) -> Option<usize> {
+    (0..points.len()).max_by(|a, b| metric(points[*a]).total_cmp(&metric(points[*b])))
+}
+
+/// Signed distance of `point` in front of the face
+fn distance(points: &[[f64; 3]], [a, b, c]: [usize; 3], point: [f64; 3]) -> f64 {
+    let normal = cross64(sub64(points[b], points[a]), sub64(points[c], points[a]));
+    let length = length(normal);
+    if length == 0.0 {
+        return 0.0;
+    }
+    let offset = sub64(point, points[a]);
+    normal[2].mul_add(
+        offset[2],
+        normal[0].mul_add(offset[0], normal[1] * offset[1]),
+    ) / length
+}
+
+fn sub64(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross64(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1].mul_add(b[2], -(a[2] * b[1])),
+        a[2].mul_add(b[0], -(a[0] * b[2])),
+        a[0].mul_add(b[1], -(a[1] * b[0])),
+    ]
+}
+
+fn length(v: [f64; 3]) -> f64 {
+    v[2].mul_add(v[2], v[0].mul_add(v[0], v[1] * v[1])).sqrt()
+}
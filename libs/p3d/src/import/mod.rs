@@ -0,0 +1,169 @@
+//! Import meshes from common formats into MLOD LODs
+//!
+//! The inverse of [`crate::export`], meshes are mirrored on the Z axis and their normals flipped
+//! to match the left handed coordinate system and inward facing normals of Arma.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{Error, Face, LOD, NamedSelection, Point, Vertex, export::normalize};
+
+mod gltf;
+mod hull;
+mod obj;
+
+pub use gltf::from_gltf;
+pub use obj::from_obj;
+
+/// LOD version written by Object Builder
+const LOD_VERSION: (u32, u32) = (28, 256);
+
+#[derive(Debug, Default, PartialEq)]
+/// Triangles read from a mesh format, in the right handed coordinates of glTF
+pub struct Scene {
+    pub objects: Vec<Object>,
+    /// Texture and rvmat of each material, when the file provides them
+    pub materials: HashMap<String, MaterialPaths>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+/// A named object of the scene, imported as a named selection
+pub struct Object {
+    pub name: String,
+    pub triangles: Vec<Triangle>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+/// A triangle, wound counter clockwise when seen from the front
+pub struct Triangle {
+    /// Name of the material, empty if none is assigned
+    pub material: String,
+    pub corners: [Corner; 3],
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Corner {
+    pub position: [f32; 3],
+    /// Normal of the corner, the normal of the triangle is used when missing
+    pub normal: Option<[f32; 3]>,
+    pub uv: [f32; 2],
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+/// Texture and rvmat assigned to the faces of a material
+pub struct MaterialPaths {
+    pub texture: String,
+    pub material: String,
+}
+
+impl Triangle {
+    #[must_use]
+    /// The normal of the front of the triangle
+    pub fn normal(&self) -> [f32; 3] {
+        let [a, b, c] = self.corners.map(|c| c.position);
+        normalize(cross(sub(b, a), sub(c, a)))
+    }
+}
+
+impl Scene {
+    /// Build a LOD at `resolution` from the triangles of the scene
+    ///
+    /// `materials` takes precedence over the materials found in the file,
+    /// faces with an unknown material are left without a texture or rvmat.
+    /// Points sharing a position are merged, and each named object becomes a named selection.
+    ///
+    /// # Errors
+    /// [`Error::InvalidTagg`] if an object name can not be used for a named selection
+    pub fn to_lod(
+        &self,
+        resolution: f32,
+        materials: &HashMap<String, MaterialPaths>,
+    ) -> Result<LOD, Error> {
+        let mut points = Vec::new();
+        let mut point_lookup = HashMap::new();
+        let mut normals = Vec::new();
+        let mut normal_lookup = HashMap::new();
+        let mut faces = Vec::new();
+        let mut selections: BTreeMap<&str, NamedSelection> = BTreeMap::new();
+        for object in &self.objects {
+            for triangle in &object.triangles {
+                let paths = materials
+                    .get(&triangle.material)
+                    .or_else(|| self.materials.get(&triangle.material));
+                let face_normal = triangle.normal();
+                let mut face = Face::new();
+                for corner in &triangle.corners {
+                    let [x, y, z] = corner.position;
+                    let point_index = intern(&mut points, &mut point_lookup, [x, y, -z]);
+                    let [nx, ny, nz] = corner.normal.map_or(face_normal, normalize);
+                    let normal_index = intern(&mut normals, &mut normal_lookup, [-nx, -ny, nz]);
+                    face.vertices.push(Vertex {
+                        point_index,
+                        normal_index,
+                        uv: (corner.uv[0], corner.uv[1]),
+                    });
+                }
+                if let Some(paths) = paths {
+                    face.texture.clone_from(&paths.texture);
+                    face.material.clone_from(&paths.material);
+                }
+                if !object.name.is_empty() {
+                    let selection = selections.entry(&object.name).or_default();
+                    selection
+                        .points
+                        .extend(face.vertices.iter().map(|v| (v.point_index, 1.0)));
+                    selection
+                        .faces
+                        .insert(u32::try_from(faces.len()).unwrap_or(u32::MAX), 1.0);
+                }
+                faces.push(face);
+            }
+        }
+        let mut lod = new_lod(resolution, &points, &normals, faces);
+        for (name, selection) in selections {
+            lod.set_named_selection(name, &selection)?;
+        }
+        Ok(lod)
+    }
+}
+
+fn new_lod(resolution: f32, points: &[[f32; 3]], normals: &[[f32; 3]], faces: Vec<Face>) -> LOD {
+    LOD {
+        version_major: LOD_VERSION.0,
+        version_minor: LOD_VERSION.1,
+        unknown_flags: 0,
+        resolution,
+        type_name: LOD::get_lod_type_from_resolution(resolution),
+        points: points
+            .iter()
+            .map(|p| Point {
+                coords: (p[0], p[1], p[2]),
+                flags: 0,
+            })
+            .collect(),
+        face_normals: normals.iter().map(|n| (n[0], n[1], n[2])).collect(),
+        faces,
+        taggs: Vec::new(),
+    }
+}
+
+/// Index of `value` in `values`, adding it if it is new
+fn intern(values: &mut Vec<[f32; 3]>, lookup: &mut HashMap<[u32; 3], u32>, value: [f32; 3]) -> u32 {
+    // adding zero turns -0.0 into 0.0, so both share a key
+    let key = value.map(|v| (v + 0.0).to_bits());
+    *lookup.entry(key).or_insert_with(|| {
+        values.push(value);
+        u32::try_from(values.len() - 1).unwrap_or(u32::MAX)
+    })
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1].mul_add(b[2], -(a[2] * b[1])),
+        a[2].mul_add(b[0], -(a[0] * b[2])),
+        a[0].mul_add(b[1], -(a[1] * b[0])),
+    ]
+}
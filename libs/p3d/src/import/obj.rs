@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use super::{Corner, MaterialPaths, Object, Scene, Triangle};
+use crate::Error;
+
+/// Read an OBJ document
+///
+/// `load` reads the MTL files referenced by `mtllib`. Materials exported by HEMTT
+/// keep their texture and rvmat, polygons are split into triangles.
+///
+/// # Errors
+/// [`Error::InvalidMesh`] if a line can not be parsed or references a missing element
+pub fn from_obj(obj: &str, load: impl Fn(&str) -> Option<String>) -> Result<Scene, Error> {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut normals: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut scene = Scene::default();
+    let mut object = None;
+    let mut material = String::new();
+    for (number, line) in obj.lines().enumerate() {
+        let invalid = |message: &str| Error::InvalidMesh(format!("line {}: {message}", number + 1));
+        let mut parts = line.split_whitespace();
+        let Some(keyword) = parts.next() else {
+            continue;
+        };
+        let rest = parts.clone().collect::<Vec<_>>().join(" ");
+        match keyword {
+            "v" => positions.push(floats(parts).ok_or_else(|| invalid("invalid position"))?),
+            "vn" => normals.push(floats(parts).ok_or_else(|| invalid("invalid normal"))?),
+            "vt" => {
+                let [u, v]: [f32; 2] = floats(parts).ok_or_else(|| invalid("invalid uv"))?;
+                uvs.push([u, 1.0 - v]);
+            }
+            "o" | "g" => {
+                object = Some(
+                    scene
+                        .objects
+                        .iter()
+                        .position(|o| o.name == rest)
+                        .unwrap_or_else(|| {
+                            scene.objects.push(Object {
+                                name: rest,
+                                triangles: Vec::new(),
+                            });
+                            scene.objects.len() - 1
+                        }),
+                );
+            }
+            "usemtl" => material = rest,
+            "mtllib" => {
+                for file in parts {
+                    if let Some(mtl) = load(file) {
+                        scene.materials.extend(read_mtl(&mtl));
+                    }
+                }
+            }
+            "f" => {
+                let corners = parts
+                    .map(|corner| {
+                        let mut indices = corner.split('/');
+                        let position = index(indices.next(), &positions)
+                            .ok_or_else(|| invalid("invalid position index"))?;
+                        let uv = match indices.next() {
+                            Some(i) if !i.is_empty() => {
+                                index(Some(i), &uvs).ok_or_else(|| invalid("invalid uv index"))?
+                            }
+                            _ => [0.0, 0.0],
+                        };
+                        let normal = match indices.next() {
+                            Some(i) if !i.is_empty() => Some(
+                                index(Some(i), &normals)
+                                    .ok_or_else(|| invalid("invalid normal index"))?,
+                            ),
+                            _ => None,
+                        };
+                        Ok(Corner {
+                            position,
+                            normal,
+                            uv,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                if corners.len() < 3 {
+                    return Err(invalid("a face needs at least 3 vertices"));
+                }
+                let object = *object.get_or_insert_with(|| {
+                    scene.objects.push(Object::default());
+                    scene.objects.len() - 1
+                });
+                for i in 1..corners.len() - 1 {
+                    scene.objects[object].triangles.push(Triangle {
+                        material: material.clone(),
+                        corners: [corners[0], corners[i], corners[i + 1]],
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(scene)
+}
+
+/// Read the textures and rvmats of materials exported by HEMTT
+fn read_mtl(mtl: &str) -> HashMap<String, MaterialPaths> {
+    let mut materials: HashMap<String, MaterialPaths> = HashMap::new();
+    let mut current = None;
+    for line in mtl.lines().map(str::trim) {
+        if let Some(name) = line.strip_prefix("newmtl ") {
+            current = Some(name.trim().to_string());
+        } else if let Some(name) = &current {
+            if let Some(texture) = line.strip_prefix("# texture") {
+                materials.entry(name.clone()).or_default().texture = texture.trim().to_string();
+            } else if let Some(material) = line.strip_prefix("# material") {
+                materials.entry(name.clone()).or_default().material = material.trim().to_string();
+            }
+        }
+    }
+    materials
+}
+
+/// Parse the first `N` values, any extra values such as the `w` of a position are ignored
+fn floats<'a, const N: usize>(mut parts: impl Iterator<Item = &'a str>) -> Option<[f32; N]> {
+    let mut values = [0.0; N];
+    for value in &mut values {
+        *value = parts.next()?.parse().ok()?;
+    }
+    Some(values)
+}
+
+/// Resolve a one based index, negative indices count back from the last element
+fn index<T: Copy>(index: Option<&str>, values: &[T]) -> Option<T> {
+    let index = index?.parse::<isize>().ok()?;
+    let index = if index < 0 {
+        values.len().checked_sub(index.unsigned_abs())?
+    } else {
+        index.unsigned_abs().checked_sub(1)?
+    };
+    values.get(index).copied()
+}
@@ -12,18 +12,28 @@ use std::io::{BufReader, BufWriter, Read, Seek, Write};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use serde::Serialize;
 
+#[cfg(feature = "lints")]
+pub mod analyze;
 mod error;
+pub mod export;
 mod face;
 mod functions;
+pub mod import;
 mod lod;
+mod model;
+pub mod odol;
 mod point;
+mod taggs;
 mod vertex;
 
 pub use error::Error;
 pub use face::Face;
 pub use functions::*;
 pub use lod::LOD;
+pub use model::Model;
+pub use odol::ODOL;
 pub use point::Point;
+pub use taggs::{AnimationFrame, NamedSelection, UvSet};
 pub use vertex::Vertex;
 
 #[derive(Debug, Serialize)]
@@ -2,11 +2,52 @@ use std::io::{Read, Seek, Write};
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use hemtt_common::io::{ReadExt, WriteExt};
-use serde::Serialize;
+use serde::{Serialize, ser::SerializeStruct};
 
 use crate::{Error, Face, Point};
 
-#[derive(Debug, PartialEq, Serialize)]
+/// Resolutions of the special LODs and their names
+const NAMED_RESOLUTIONS: [(f32, &str); 31] = [
+    // View positions
+    (1000.0, "View Gunner"),
+    (1100.0, "View Pilot"),
+    (1200.0, "View Cargo"),
+    // Shadow volumes
+    (10000.0, "Shadow Volume 0"),
+    (10010.0, "Shadow Volume 10"),
+    (11000.0, "Shadow Buffer 0"),
+    (11010.0, "Shadow Buffer 10"),
+    // Geometry types
+    (1e13, "Geometry"),
+    (2e13, "Geometry Buoyancy"),
+    (4e13, "Geometry PysX"),
+    // Memory and special geometries
+    (1e15, "Memory"),
+    (2e15, "Land Contact"),
+    (3e15, "Roadway"),
+    (4e15, "Paths"),
+    (5e15, "Hit-points"),
+    (6e15, "View Geometry"),
+    (7e15, "Fire Geometry"),
+    (8e15, "View Cargo Geom."),
+    (9e15, "View Cargo Fire Geom."),
+    // Commander, pilot, gunner views
+    (1e16, "View Commander"),
+    (1.1e16, "View Commander Geom."),
+    (1.2e16, "View Commander Fire Geom."),
+    (1.3e16, "View Pilot Geom."),
+    (1.4e16, "View Pilot Fire Geom."),
+    (1.5e16, "View Gunner Geom."),
+    (1.6e16, "View Gunner Fire Geom."),
+    // Additional types
+    (1.7e16, "Sub Parts"),
+    (1.8e16, "Shadow Volume - View Cargo"),
+    (1.9e16, "Shadow Volume - View Pilot"),
+    (2e16, "Shadow Volume - View Gunner"),
+    (2.1e16, "Wreck"),
+];
+
+#[derive(Debug, PartialEq)]
 pub struct LOD {
     pub version_major: u32,
     pub version_minor: u32,
@@ -124,7 +165,7 @@ impl LOD {
         })
     }
 
-    fn get_lod_type_from_resolution(resolution: f32) -> String {
+    pub(crate) fn get_lod_type_from_resolution(resolution: f32) -> String {
         if (20000.0..30000.0).contains(&resolution) {
             return "Edit ".to_owned() + &(resolution - 20000.0).floor().to_string();
         }
@@ -146,107 +187,21 @@ impl LOD {
         reason = "All of the numbers should be safe for exact comparison"
     )]
     fn get_lod_type_from_resolution_match(resolution: f32) -> &'static str {
-        // View positions
-        if resolution == 1000.0 {
-            return "View Gunner";
-        }
-        if resolution == 1100.0 {
-            return "View Pilot";
-        }
-        if resolution == 1200.0 {
-            return "View Cargo";
-        }
-        // Shadow volumes
-        if resolution == 10000.0 {
-            return "Shadow Volume 0";
-        }
-        if resolution == 10010.0 {
-            return "Shadow Volume 10";
-        }
-        if resolution == 11000.0 {
-            return "Shadow Buffer 0";
-        }
-        if resolution == 11010.0 {
-            return "Shadow Buffer 10";
-        }
-        // Geometry types
-        if resolution == 1e13 {
-            return "Geometry";
-        }
-        if resolution == 2e13 {
-            return "Geometry Buoyancy";
-        }
-        if resolution == 4e13 {
-            return "Geometry PysX";
-        }
-        // Memory and special geometries
-        if resolution == 1e15 {
-            return "Memory";
-        }
-        if resolution == 2e15 {
-            return "Land Contact";
-        }
-        if resolution == 3e15 {
-            return "Roadway";
-        }
-        if resolution == 4e15 {
-            return "Paths";
-        }
-        if resolution == 5e15 {
-            return "Hit-points";
-        }
-        if resolution == 6e15 {
-            return "View Geometry";
-        }
-        if resolution == 7e15 {
-            return "Fire Geometry";
-        }
-        if resolution == 8e15 {
-            return "View Cargo Geom.";
-        }
-        if resolution == 9e15 {
-            return "View Cargo Fire Geom.";
-        }
-        // Commander, pilot, gunner views
-        if resolution == 1e16 {
-            return "View Commander";
-        }
-        if resolution == 1.1e16 {
-            return "View Commander Geom.";
-        }
-        if resolution == 1.2e16 {
-            return "View Commander Fire Geom.";
-        }
-        if resolution == 1.3e16 {
-            return "View Pilot Geom.";
-        }
-        if resolution == 1.4e16 {
-            return "View Pilot Fire Geom.";
-        }
-        if resolution == 1.5e16 {
-            return "View Gunner Geom.";
-        }
-        if resolution == 1.6e16 {
-            return "View Gunner Fire Geom.";
-        }
-        // Additional types
-        if resolution == 1.7e16 {
-            return "Sub Parts";
-        }
-        if resolution == 1.8e16 {
-            return "Shadow Volume - View Cargo";
-        }
-        if resolution == 1.9e16 {
-            return "Shadow Volume - View Pilot";
-        }
-        if resolution == 2e16 {
-            return "Shadow Volume - View Gunner";
-        }
-        if resolution == 2.1e16 {
-            return "Wreck";
-        }
+        NAMED_RESOLUTIONS
+            .iter()
+            .find(|(r, _)| *r == resolution)
+            .map_or("Unknown", |(_, name)| name)
+    }
 
-        "Unknown"
+    #[must_use]
+    /// The resolution of a special LOD from its name, such as `Geometry` or `Memory`
+    ///
+    /// Names are matched case insensitively, `Edit` LODs and visual resolutions are not named.
+    pub fn resolution_from_name(name: &str) -> Option<f32> {
+        NAMED_RESOLUTIONS
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|(r, _)| *r)
     }
 
     /// Writes the LOD to a given output stream.
@@ -302,3 +257,33 @@ impl LOD {
         Ok(())
     }
 }
+
+impl Serialize for LOD {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("LOD", 15)?;
+        state.serialize_field("version_major", &self.version_major)?;
+        state.serialize_field("version_minor", &self.version_minor)?;
+        state.serialize_field("unknown_flags", &self.unknown_flags)?;
+        state.serialize_field("resolution", &self.resolution)?;
+        state.serialize_field("type_name", &self.type_name)?;
+        state.serialize_field("points", &self.points)?;
+        state.serialize_field("face_normals", &self.face_normals)?;
+        state.serialize_field("faces", &self.faces)?;
+        state.serialize_field("named_properties", &self.named_properties())?;
+        state.serialize_field("masses", &self.masses())?;
+        state.serialize_field("named_selections", &self.named_selections())?;
+        state.serialize_field("uv_sets", &self.uv_sets())?;
+        state.serialize_field("sharp_edges", &self.sharp_edges())?;
+        state.serialize_field("animations", &self.animations())?;
+        // TAGGs without a typed accessor are kept as raw bytes
+        state.serialize_field(
+            "taggs",
+            &self
+                .taggs
+                .iter()
+                .filter(|(name, data)| !self.is_typed_tagg(name, data))
+                .collect::<Vec<_>>(),
+        )?;
+        state.end()
+    }
+}
@@ -0,0 +1,44 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use serde::Serialize;
+
+use crate::{Error, ODOL, P3D};
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+/// A P3D in either format
+pub enum Model {
+    /// An editable model, as saved by Object Builder
+    MLOD(P3D),
+    /// A binarized model, as found in released PBOs
+    ODOL(Box<ODOL>),
+}
+
+impl Model {
+    /// Reads a P3D from a given input stream, detecting the format from its signature.
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if an IO error occurs.
+    /// [`Error::UnsupportedP3DType`] if the input is neither an MLOD nor an ODOL.
+    pub fn read<I: Read + Seek>(input: &mut I) -> Result<Self, Error> {
+        let mut signature = [0; 4];
+        input.read_exact(&mut signature)?;
+        input.seek(SeekFrom::Current(-4))?;
+        match &signature {
+            b"MLOD" => Ok(Self::MLOD(P3D::read(input)?)),
+            b"ODOL" => Ok(Self::ODOL(Box::new(ODOL::read(input)?))),
+            _ => Err(Error::UnsupportedP3DType(
+                String::from_utf8_lossy(&signature).to_string(),
+            )),
+        }
+    }
+
+    #[must_use]
+    /// Textures and materials referenced by the model
+    pub fn dependencies(&self) -> Vec<String> {
+        match self {
+            Self::MLOD(p3d) => p3d.dependencies(),
+            Self::ODOL(odol) => odol.dependencies(),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::io::{Read, Seek};
+
+use serde::Serialize;
+
+use super::reader::OdolReader;
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnimationType {
+    Rotation,
+    RotationX,
+    RotationY,
+    RotationZ,
+    Translation,
+    TranslationX,
+    TranslationY,
+    TranslationZ,
+    Direct,
+    Hide,
+}
+
+impl AnimationType {
+    fn from_u32(value: u32) -> Result<Self, Error> {
+        Ok(match value {
+            0 => Self::Rotation,
+            1 => Self::RotationX,
+            2 => Self::RotationY,
+            3 => Self::RotationZ,
+            4 => Self::Translation,
+            5 => Self::TranslationX,
+            6 => Self::TranslationY,
+            7 => Self::TranslationZ,
+            8 => Self::Direct,
+            9 => Self::Hide,
+            _ => {
+                return Err(Error::InvalidODOL(format!(
+                    "unknown animation type {value}"
+                )));
+            }
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+/// An animation from `model.cfg`
+pub struct Animation {
+    pub name: String,
+    pub source: String,
+    pub kind: AnimationType,
+    pub min_value: f32,
+    pub max_value: f32,
+}
+
+impl Animation {
+    /// Read the animations, skipping the bone mapping of each LOD
+    pub(crate) fn read_all<R: Read + Seek>(reader: &mut OdolReader<R>) -> Result<Vec<Self>, Error> {
+        let count = reader.u32()?;
+        let animations = (0..count)
+            .map(|_| Self::read(reader))
+            .collect::<Result<Vec<_>, _>>()?;
+        let resolutions = reader.u32()?;
+        // bones to animations
+        for _ in 0..resolutions {
+            let bones = reader.u32()?;
+            for _ in 0..bones {
+                let count = reader.u32()?;
+                reader.skip(i64::from(count) * 4)?;
+            }
+        }
+        // animations to bones, with an axis for rotations and translations
+        for _ in 0..resolutions {
+            for animation in &animations {
+                let bone = reader.i32()?;
+                if bone != -1
+                    && !matches!(animation.kind, AnimationType::Direct | AnimationType::Hide)
+                {
+                    reader.skip(24)?;
+                }
+            }
+        }
+        Ok(animations)
+    }
+
+    fn read<R: Read + Seek>(reader: &mut OdolReader<R>) -> Result<Self, Error> {
+        let kind = AnimationType::from_u32(reader.u32()?)?;
+        let name = reader.cstring()?;
+        let source = reader.cstring()?;
+        // min phase, max phase
+        reader.skip(8)?;
+        let min_value = reader.f32()?;
+        let max_value = reader.f32()?;
+        if reader.version() >= 56 {
+            // period, initial phase
+            reader.skip(8)?;
+        }
+        // source address
+        reader.skip(4)?;
+        match kind {
+            AnimationType::Direct => {
+                // axis position, axis direction, angle, axis offset
+                reader.skip(32)?;
+            }
+            AnimationType::Hide => {
+                reader.skip(if reader.version() >= 55 { 8 } else { 4 })?;
+            }
+            _ => {
+                // angles or offsets
+                reader.skip(8)?;
+            }
+        }
+        Ok(Self {
+            name,
+            source,
+            kind,
+            min_value,
+            max_value,
+        })
+    }
+}
@@ -0,0 +1,281 @@
+use std::io::{Read, Seek};
+
+use serde::Serialize;
+
+use super::reader::OdolReader;
+use crate::{Error, LOD};
+
+#[derive(Debug, PartialEq, Serialize)]
+/// A binarized LOD
+///
+/// Only the parts needed to inspect the model are read, not the geometry itself
+pub struct OdolLod {
+    pub resolution: f32,
+    pub type_name: String,
+    pub proxies: Vec<Proxy>,
+    pub vertex_count: u32,
+    pub face_count: u32,
+    pub bounds: Bounds,
+    pub textures: Vec<String>,
+    pub materials: Vec<Material>,
+    pub named_selections: Vec<NamedSelection>,
+    pub named_properties: Vec<(String, String)>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Proxy {
+    pub model: String,
+    /// Rotation matrix followed by the position
+    pub transform: [f32; 12],
+    pub sequence_id: i32,
+    pub named_selection: i32,
+    pub bone: i32,
+    pub section: i32,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Bounds {
+    pub min: (f32, f32, f32),
+    pub max: (f32, f32, f32),
+    pub center: (f32, f32, f32),
+    pub radius: f32,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+/// An rvmat embedded in the LOD
+pub struct Material {
+    pub name: String,
+    pub surface: String,
+    /// Textures of each stage, including the texture of the TI stage
+    pub textures: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct NamedSelection {
+    pub name: String,
+    pub faces: usize,
+    pub vertices: usize,
+}
+
+impl OdolLod {
+    pub(crate) fn read<R: Read + Seek>(
+        reader: &mut OdolReader<R>,
+        resolution: f32,
+    ) -> Result<Self, Error> {
+        let version = reader.version();
+        let count = reader.u32()?;
+        let proxies = (0..count)
+            .map(|_| Proxy::read(reader))
+            .collect::<Result<Vec<_>, _>>()?;
+        // sub skeleton to skeleton
+        reader.ints()?;
+        // skeleton to sub skeleton
+        let count = reader.u32()?;
+        for _ in 0..count {
+            reader.ints()?;
+        }
+        let vertex_count = if version >= 50 {
+            reader.u32()?
+        } else {
+            // point flags
+            Self::skip_condensed(reader, 4)?
+        };
+        if version >= 51 {
+            // face area
+            reader.skip(4)?;
+        }
+        // or hints, and hints
+        reader.skip(8)?;
+        let bounds = Bounds {
+            min: reader.vec3()?,
+            max: reader.vec3()?,
+            center: reader.vec3()?,
+            radius: reader.f32()?,
+        };
+        let textures = reader.strings()?;
+        let count = reader.u32()?;
+        let materials = (0..count)
+            .map(|_| Material::read(reader))
+            .collect::<Result<Vec<_>, _>>()?;
+        // point to vertex, vertex to point
+        reader.vertex_indices()?;
+        reader.vertex_indices()?;
+        let face_count = reader.u32()?;
+        // allocated size, always zero
+        reader.skip(6)?;
+        for _ in 0..face_count {
+            let vertices = reader.u8()?;
+            for _ in 0..vertices {
+                reader.vertex_index()?;
+            }
+        }
+        let count = reader.u32()?;
+        for _ in 0..count {
+            Self::skip_section(reader)?;
+        }
+        let count = reader.u32()?;
+        let named_selections = (0..count)
+            .map(|_| NamedSelection::read(reader))
+            .collect::<Result<Vec<_>, _>>()?;
+        let count = reader.u32()?;
+        let named_properties = (0..count)
+            .map(|_| Ok((reader.cstring()?, reader.cstring()?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Self {
+            resolution,
+            type_name: LOD::get_lod_type_from_resolution(resolution),
+            proxies,
+            vertex_count,
+            face_count,
+            bounds,
+            textures,
+            materials,
+            named_selections,
+            named_properties,
+        })
+    }
+
+    /// An array that is either a single default value, or compressed
+    fn skip_condensed<R: Read + Seek>(reader: &mut OdolReader<R>, size: u32) -> Result<u32, Error> {
+        let count = reader.u32()?;
+        if reader.bool()? {
+            reader.skip(i64::from(size))?;
+        } else {
+            reader.compressed(count as usize * size as usize)?;
+        }
+        Ok(count)
+    }
+
+    fn skip_section<R: Read + Seek>(reader: &mut OdolReader<R>) -> Result<(), Error> {
+        // face range, bone range, material dummy, texture, flags
+        reader.skip(4 * 5 + 2 + 4)?;
+        if reader.i32()? == -1 {
+            // material name
+            reader.cstring()?;
+        }
+        if reader.version() >= 36 {
+            // area over texture for each stage
+            let stages = reader.u32()?;
+            reader.skip(i64::from(stages) * 4)?;
+        }
+        if reader.version() >= 67 && reader.i32()? >= 1 {
+            reader.skip(11 * 4)?;
+        }
+        Ok(())
+    }
+}
+
+impl Proxy {
+    fn read<R: Read + Seek>(reader: &mut OdolReader<R>) -> Result<Self, Error> {
+        let model = reader.cstring()?;
+        let transform = reader.floats::<12>()?;
+        let sequence_id = reader.i32()?;
+        let named_selection = reader.i32()?;
+        let bone = reader.i32()?;
+        let section = if reader.version() >= 40 {
+            reader.i32()?
+        } else {
+            -1
+        };
+        Ok(Self {
+            model,
+            transform,
+            sequence_id,
+            named_selection,
+            bone,
+            section,
+        })
+    }
+}
+
+impl Material {
+    fn read<R: Read + Seek>(reader: &mut OdolReader<R>) -> Result<Self, Error> {
+        let name = reader.cstring()?;
+        let version = reader.u32()?;
+        // emissive, ambient, diffuse, forced diffuse, specular, specular 2, specular power
+        // pixel shader, vertex shader, main light, fog mode
+        reader.skip(6 * 16 + 4 + 4 * 4)?;
+        if version == 3 {
+            reader.skip(1)?;
+        }
+        let surface = if version >= 6 {
+            reader.cstring()?
+        } else {
+            String::new()
+        };
+        if version >= 4 {
+            // render flags
+            reader.skip(8)?;
+        }
+        let stages = if version > 6 { reader.u32()? } else { 1 };
+        let transforms = if version > 8 { reader.u32()? } else { stages };
+        let mut textures = Vec::with_capacity(stages as usize + 1);
+        if version < 8 {
+            for _ in 0..stages {
+                Self::skip_transform(reader)?;
+                textures.push(Self::read_stage(reader, version)?);
+            }
+        } else {
+            for _ in 0..stages {
+                textures.push(Self::read_stage(reader, version)?);
+            }
+            for _ in 0..transforms {
+                Self::skip_transform(reader)?;
+            }
+        }
+        if version >= 10 {
+            textures.push(Self::read_stage(reader, version)?);
+        }
+        Ok(Self {
+            name,
+            surface,
+            textures,
+        })
+    }
+
+    fn read_stage<R: Read + Seek>(
+        reader: &mut OdolReader<R>,
+        version: u32,
+    ) -> Result<String, Error> {
+        if version >= 5 {
+            // filter
+            reader.skip(4)?;
+        }
+        let texture = reader.cstring()?;
+        if version >= 8 {
+            // stage id
+            reader.skip(4)?;
+        }
+        if version >= 11 {
+            // use world environment map
+            reader.skip(1)?;
+        }
+        Ok(texture)
+    }
+
+    fn skip_transform<R: Read + Seek>(reader: &mut OdolReader<R>) -> Result<(), Error> {
+        // uv source, matrix
+        reader.skip(4 + 12 * 4)
+    }
+}
+
+impl NamedSelection {
+    fn read<R: Read + Seek>(reader: &mut OdolReader<R>) -> Result<Self, Error> {
+        let name = reader.cstring()?;
+        let faces = reader.vertex_indices()?.len();
+        // always zero
+        reader.skip(4)?;
+        // is sectional
+        reader.skip(1)?;
+        // sections
+        reader.skip_compressed_array(4)?;
+        let vertices = reader.vertex_indices()?.len();
+        // vertex weights
+        reader.skip_compressed_array(1)?;
+        Ok(Self {
+            name,
+            faces,
+            vertices,
+        })
+    }
+}
@@ -0,0 +1,124 @@
+//! Binarized P3D models, as produced by binarize
+
+use std::{
+    io::{BufReader, Read, Seek},
+    ops::RangeInclusive,
+};
+
+use serde::Serialize;
+
+mod animation;
+mod lod;
+mod model_info;
+mod reader;
+
+pub use animation::{Animation, AnimationType};
+pub use lod::{Bounds, Material, NamedSelection, OdolLod, Proxy};
+pub use model_info::{ModelInfo, Skeleton, SpecialLods};
+
+use crate::Error;
+use reader::OdolReader;
+
+/// ODOL versions that can be read, from Arma 2 to Arma 3
+pub const SUPPORTED_VERSIONS: RangeInclusive<u32> = 44..=76;
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ODOL {
+    pub version: u32,
+    pub app_id: u32,
+    pub prefix: String,
+    pub model_info: ModelInfo,
+    pub animations: Vec<Animation>,
+    pub lods: Vec<OdolLod>,
+}
+
+impl ODOL {
+    /// Reads an ODOL from a given input stream.
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if an IO error occurs.
+    /// [`Error::UnsupportedP3DType`] if the input is not an ODOL.
+    /// [`Error::UnsupportedODOLVersion`] if the version can not be read.
+    /// [`Error::InvalidODOL`] if the ODOL is corrupt.
+    pub fn read<I: Read + Seek>(input: &mut I) -> Result<Self, Error> {
+        let mut buffer = BufReader::new(input);
+        let mut signature = [0; 4];
+        buffer.read_exact(&mut signature)?;
+        if &signature != b"ODOL" {
+            return Err(Error::UnsupportedP3DType(
+                String::from_utf8_lossy(&signature).to_string(),
+            ));
+        }
+        let mut version = [0; 4];
+        buffer.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+        if !SUPPORTED_VERSIONS.contains(&version) {
+            return Err(Error::UnsupportedODOLVersion(version));
+        }
+        let mut reader = OdolReader::new(&mut buffer, version);
+
+        let app_id = if version >= 59 { reader.u32()? } else { 0 };
+        let prefix = if version >= 58 {
+            reader.cstring()?
+        } else {
+            String::new()
+        };
+        let count = reader.u32()?;
+        let resolutions = (0..count)
+            .map(|_| reader.f32())
+            .collect::<Result<Vec<_>, _>>()?;
+        let model_info = ModelInfo::read(&mut reader, count)?;
+        let animations = if reader.bool()? {
+            Animation::read_all(&mut reader)?
+        } else {
+            Vec::new()
+        };
+        let starts = (0..count)
+            .map(|_| reader.u32())
+            .collect::<Result<Vec<_>, _>>()?;
+        let ends = (0..count)
+            .map(|_| reader.u32())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let length = reader.length()?;
+        let mut lods = Vec::with_capacity(resolutions.len());
+        for ((resolution, start), end) in resolutions.into_iter().zip(starts).zip(ends) {
+            if start >= end || u64::from(end) > length {
+                return Err(Error::InvalidODOL(format!(
+                    "LOD {resolution} has an invalid range {start}..{end}"
+                )));
+            }
+            reader.seek(u64::from(start))?;
+            lods.push(OdolLod::read(&mut reader, resolution)?);
+        }
+
+        Ok(Self {
+            version,
+            app_id,
+            prefix,
+            model_info,
+            animations,
+            lods,
+        })
+    }
+
+    #[must_use]
+    /// Textures and materials referenced by the model
+    pub fn dependencies(&self) -> Vec<String> {
+        use std::collections::HashSet;
+        let mut dependencies: HashSet<String> = HashSet::new();
+        for lod in &self.lods {
+            for texture in &lod.textures {
+                if !texture.is_empty() && !texture.starts_with('#') {
+                    dependencies.insert(texture.clone());
+                }
+            }
+            for material in &lod.materials {
+                if !material.name.is_empty() && !material.name.starts_with('#') {
+                    dependencies.insert(material.name.clone());
+                }
+            }
+        }
+        dependencies.into_iter().collect()
+    }
+}
@@ -0,0 +1,237 @@
+use std::io::{Read, Seek};
+
+use serde::Serialize;
+
+use super::reader::OdolReader;
+use crate::Error;
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, PartialEq, Serialize)]
+/// Information about the whole model, shared by all LODs
+pub struct ModelInfo {
+    pub special: i32,
+    pub bounding_sphere: f32,
+    pub geometry_sphere: f32,
+    pub aiming_center: (f32, f32, f32),
+    pub bbox_min: (f32, f32, f32),
+    pub bbox_max: (f32, f32, f32),
+    pub bbox_min_visual: (f32, f32, f32),
+    pub bbox_max_visual: (f32, f32, f32),
+    pub bounding_center: (f32, f32, f32),
+    pub geometry_center: (f32, f32, f32),
+    pub center_of_mass: (f32, f32, f32),
+    pub auto_center: bool,
+    pub lock_auto_center: bool,
+    pub can_occlude: bool,
+    pub can_be_occluded: bool,
+    pub skeleton: Option<Skeleton>,
+    pub mass: f32,
+    pub armor: f32,
+    pub special_lods: SpecialLods,
+    pub property_class: String,
+    pub property_damage: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+/// Bones of the model, from `model.cfg`
+pub struct Skeleton {
+    pub name: String,
+    pub discrete: bool,
+    /// Bone names with their parent, the parent is empty for root bones
+    pub bones: Vec<(String, String)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+/// Indices of the special LODs, 255 when the model does not have one
+pub struct SpecialLods {
+    pub memory: u8,
+    pub geometry: u8,
+    pub geometry_fire: u8,
+    pub geometry_view: u8,
+    pub geometry_view_pilot: u8,
+    pub geometry_view_gunner: u8,
+    pub geometry_view_cargo: u8,
+    pub land_contact: u8,
+    pub roadway: u8,
+    pub paths: u8,
+    pub hitpoints: u8,
+}
+
+impl ModelInfo {
+    pub(crate) fn read<R: Read + Seek>(
+        reader: &mut OdolReader<R>,
+        lods: u32,
+    ) -> Result<Self, Error> {
+        let version = reader.version();
+        let special = reader.i32()?;
+        let bounding_sphere = reader.f32()?;
+        let geometry_sphere = reader.f32()?;
+        // remarks, and hints, or hints
+        reader.skip(12)?;
+        let aiming_center = reader.vec3()?;
+        // color, color type, view density
+        reader.skip(12)?;
+        let bbox_min = reader.vec3()?;
+        let bbox_max = reader.vec3()?;
+        if version >= 70 {
+            // lod density coefficient
+            reader.skip(4)?;
+        }
+        if version >= 71 {
+            // draw importance
+            reader.skip(4)?;
+        }
+        let (bbox_min_visual, bbox_max_visual) = if version >= 52 {
+            (reader.vec3()?, reader.vec3()?)
+        } else {
+            (bbox_min, bbox_max)
+        };
+        let bounding_center = reader.vec3()?;
+        let geometry_center = reader.vec3()?;
+        let center_of_mass = reader.vec3()?;
+        // inverse inertia matrix
+        reader.skip(36)?;
+        let auto_center = reader.bool()?;
+        let lock_auto_center = reader.bool()?;
+        let can_occlude = reader.bool()?;
+        let can_be_occluded = reader.bool()?;
+        if version >= 73 {
+            // ai covers
+            reader.skip(1)?;
+        }
+        if version >= 42 {
+            // ht min, ht max, af max, mf max
+            reader.skip(16)?;
+        }
+        if version >= 43 {
+            // m fact, t body
+            reader.skip(8)?;
+        }
+        if version >= 33 {
+            // force not alpha
+            reader.skip(1)?;
+        }
+        if version >= 37 {
+            // shadow buffer source, prefer shadow volume
+            reader.skip(5)?;
+        }
+        if version >= 48 {
+            // shadow offset
+            reader.skip(4)?;
+        }
+        // animated
+        reader.skip(1)?;
+        let skeleton = Skeleton::read(reader)?;
+        // map type
+        reader.skip(1)?;
+        // mass array
+        reader.skip_compressed_array(4)?;
+        let mass = reader.f32()?;
+        // inverse mass
+        reader.skip(4)?;
+        let armor = reader.f32()?;
+        // inverse armor
+        reader.skip(4)?;
+        if version >= 72 {
+            // explosion shielding
+            reader.skip(4)?;
+        }
+        if version >= 53 {
+            // geometry simple
+            reader.skip(1)?;
+        }
+        if version >= 54 {
+            // geometry physx
+            reader.skip(1)?;
+        }
+        let memory = reader.u8()?;
+        let geometry = reader.u8()?;
+        let geometry_fire = reader.u8()?;
+        let geometry_view = reader.u8()?;
+        let geometry_view_pilot = reader.u8()?;
+        let geometry_view_gunner = reader.u8()?;
+        // geometry view commander, unused
+        reader.skip(1)?;
+        let geometry_view_cargo = reader.u8()?;
+        let land_contact = reader.u8()?;
+        let roadway = reader.u8()?;
+        let paths = reader.u8()?;
+        let hitpoints = reader.u8()?;
+        // min shadow
+        reader.skip(4)?;
+        if version >= 38 {
+            // can blend
+            reader.skip(1)?;
+        }
+        let property_class = reader.cstring()?;
+        let property_damage = reader.cstring()?;
+        // property frequent
+        reader.skip(1)?;
+        if version >= 31 {
+            // unknown
+            reader.skip(4)?;
+        }
+        if version >= 57 {
+            // preferred shadow volume, shadow buffer and visible shadow buffer lods
+            reader.skip(3 * 4 * i64::from(lods))?;
+        }
+        Ok(Self {
+            special,
+            bounding_sphere,
+            geometry_sphere,
+            aiming_center,
+            bbox_min,
+            bbox_max,
+            bbox_min_visual,
+            bbox_max_visual,
+            bounding_center,
+            geometry_center,
+            center_of_mass,
+            auto_center,
+            lock_auto_center,
+            can_occlude,
+            can_be_occluded,
+            skeleton,
+            mass,
+            armor,
+            special_lods: SpecialLods {
+                memory,
+                geometry,
+                geometry_fire,
+                geometry_view,
+                geometry_view_pilot,
+                geometry_view_gunner,
+                geometry_view_cargo,
+                land_contact,
+                roadway,
+                paths,
+                hitpoints,
+            },
+            property_class,
+            property_damage,
+        })
+    }
+}
+
+impl Skeleton {
+    fn read<R: Read + Seek>(reader: &mut OdolReader<R>) -> Result<Option<Self>, Error> {
+        let name = reader.cstring()?;
+        if name.is_empty() {
+            return Ok(None);
+        }
+        let discrete = reader.bool()?;
+        let count = reader.u32()?;
+        let bones = (0..count)
+            .map(|_| Ok((reader.cstring()?, reader.cstring()?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        if reader.version() > 40 {
+            // pivots name, obsolete
+            reader.cstring()?;
+        }
+        Ok(Some(Self {
+            name,
+            discrete,
+            bones,
+        }))
+    }
+}
@@ -0,0 +1,160 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use hemtt_common::io::ReadExt;
+
+use crate::Error;
+
+/// The largest decompressed array that will be read, to avoid allocating for corrupt counts
+const MAX_COMPRESSED_SIZE: usize = 256 * 1024 * 1024;
+
+/// Reads the primitive and compressed types of an ODOL, which depend on the version
+pub struct OdolReader<'a, R: Read + Seek> {
+    input: &'a mut R,
+    version: u32,
+}
+
+impl<'a, R: Read + Seek> OdolReader<'a, R> {
+    pub const fn new(input: &'a mut R, version: u32) -> Self {
+        Self { input, version }
+    }
+
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn seek(&mut self, position: u64) -> Result<(), Error> {
+        self.input.seek(SeekFrom::Start(position))?;
+        Ok(())
+    }
+
+    pub fn length(&mut self) -> Result<u64, Error> {
+        let position = self.input.stream_position()?;
+        let length = self.input.seek(SeekFrom::End(0))?;
+        self.input.seek(SeekFrom::Start(position))?;
+        Ok(length)
+    }
+
+    pub fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.input.read_u8()?)
+    }
+
+    pub fn bool(&mut self) -> Result<bool, Error> {
+        Ok(self.input.read_u8()? != 0)
+    }
+
+    pub fn u32(&mut self) -> Result<u32, Error> {
+        Ok(self.input.read_u32::<LittleEndian>()?)
+    }
+
+    pub fn i32(&mut self) -> Result<i32, Error> {
+        Ok(self.input.read_i32::<LittleEndian>()?)
+    }
+
+    pub fn f32(&mut self) -> Result<f32, Error> {
+        Ok(self.input.read_f32::<LittleEndian>()?)
+    }
+
+    pub fn vec3(&mut self) -> Result<(f32, f32, f32), Error> {
+        Ok((self.f32()?, self.f32()?, self.f32()?))
+    }
+
+    pub fn floats<const N: usize>(&mut self) -> Result<[f32; N], Error> {
+        let mut floats = [0.0; N];
+        for float in &mut floats {
+            *float = self.f32()?;
+        }
+        Ok(floats)
+    }
+
+    pub fn cstring(&mut self) -> Result<String, Error> {
+        Ok(self.input.read_cstring()?)
+    }
+
+    pub fn skip(&mut self, bytes: i64) -> Result<(), Error> {
+        self.input.seek(SeekFrom::Current(bytes))?;
+        Ok(())
+    }
+
+    /// A count followed by null-terminated strings
+    pub fn strings(&mut self) -> Result<Vec<String>, Error> {
+        let count = self.u32()?;
+        (0..count).map(|_| self.cstring()).collect()
+    }
+
+    /// A count followed by uncompressed `i32`s
+    pub fn ints(&mut self) -> Result<Vec<i32>, Error> {
+        let count = self.u32()?;
+        (0..count).map(|_| self.i32()).collect()
+    }
+
+    /// The size of a vertex index, `u16` before version 69
+    const fn vertex_index_size(&self) -> usize {
+        if self.version >= 69 { 4 } else { 2 }
+    }
+
+    /// A count followed by compressed vertex indices
+    pub fn vertex_indices(&mut self) -> Result<Vec<u32>, Error> {
+        let size = self.vertex_index_size();
+        let count = self.u32()? as usize;
+        let data = self.compressed(count * size)?;
+        Ok(data
+            .chunks_exact(size)
+            .map(|chunk| {
+                if size == 4 {
+                    u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])
+                } else {
+                    u32::from(u16::from_le_bytes([chunk[0], chunk[1]]))
+                }
+            })
+            .collect())
+    }
+
+    /// A single uncompressed vertex index
+    pub fn vertex_index(&mut self) -> Result<u32, Error> {
+        if self.vertex_index_size() == 4 {
+            self.u32()
+        } else {
+            Ok(u32::from(self.input.read_u16::<LittleEndian>()?))
+        }
+    }
+
+    /// A count followed by compressed elements of `size` bytes, returning the count
+    pub fn skip_compressed_array(&mut self, size: usize) -> Result<usize, Error> {
+        let count = self.u32()? as usize;
+        self.compressed(count * size)?;
+        Ok(count)
+    }
+
+    /// Data that is compressed with LZO if it is large enough, or if the flag is set
+    pub fn compressed(&mut self, expected: usize) -> Result<Vec<u8>, Error> {
+        if expected == 0 {
+            return Ok(Vec::new());
+        }
+        if expected > MAX_COMPRESSED_SIZE {
+            return Err(Error::InvalidODOL(format!(
+                "compressed block of {expected} bytes is too large"
+            )));
+        }
+        let compressed = if self.version >= 64 {
+            self.bool()?
+        } else {
+            expected >= 1024
+        };
+        let mut output = vec![0; expected];
+        if !compressed {
+            self.input.read_exact(&mut output)?;
+            return Ok(output);
+        }
+        let start = self.input.stream_position()?;
+        let mut input = Vec::with_capacity(hemtt_lzo::lzss::worst_compress(expected));
+        self.input
+            .by_ref()
+            .take(hemtt_lzo::lzss::worst_compress(expected) as u64)
+            .read_to_end(&mut input)?;
+        let used = hemtt_lzo::lzss::decompress_stream(&input, &mut output)
+            .map_err(|e| Error::InvalidODOL(format!("failed to decompress block: {e}")))?;
+        self.seek(start + used as u64)?;
+        Ok(output)
+    }
+}
@@ -0,0 +1,430 @@
+//! Typed access to the TAGGs of a LOD
+//!
+//! Named selections are TAGGs that do not start with `#`, holding one weight byte for each point
+//! and then each face. `0` is not selected, `1` is fully selected and other values are partial
+//! weights, `(256 - byte) / 255`.
+
+use std::collections::BTreeMap;
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::{Error, LOD};
+
+const PROPERTY: &str = "#Property#";
+const MASS: &str = "#Mass#";
+const UV_SET: &str = "#UVSet#";
+const SHARP_EDGES: &str = "#SharpEdges#";
+const ANIMATION: &str = "#Animation#";
+/// Length of the name and of the value of a named property
+const PROPERTY_FIELD: usize = 64;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+/// Points and faces of a named selection
+pub struct NamedSelection {
+    /// Weight of each selected point, from 0 to 1
+    pub points: BTreeMap<u32, f32>,
+    /// Weight of each selected face, from 0 to 1
+    pub faces: BTreeMap<u32, f32>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+/// An additional set of texture coordinates
+pub struct UvSet {
+    pub id: u32,
+    /// Coordinates of each vertex of each face, in the order of the faces
+    pub uvs: Vec<Vec<(f32, f32)>>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+/// A frame of an `#Animation#`, with the position of every point
+pub struct AnimationFrame {
+    pub time: f32,
+    pub points: Vec<(f32, f32, f32)>,
+}
+
+impl NamedSelection {
+    fn read(data: &[u8], points: usize) -> Self {
+        let (point_weights, face_weights) = data.split_at(points);
+        let selected = |weights: &[u8]| {
+            weights
+                .iter()
+                .zip(0..)
+                .filter(|(w, _)| **w != 0)
+                .map(|(w, i)| (i, weight(*w)))
+                .collect()
+        };
+        Self {
+            points: selected(point_weights),
+            faces: selected(face_weights),
+        }
+    }
+}
+
+impl LOD {
+    #[must_use]
+    /// Named properties, from the `#Property#` TAGGs
+    pub fn named_properties(&self) -> IndexMap<String, String> {
+        self.taggs_named(PROPERTY)
+            .filter(|data| data.len() == PROPERTY_FIELD * 2)
+            .map(|data| {
+                let (name, value) = data.split_at(PROPERTY_FIELD);
+                (cstring(name), cstring(value))
+            })
+            .collect()
+    }
+
+    /// Set a named property, replacing any property with the same name
+    ///
+    /// # Errors
+    /// [`Error::InvalidTagg`] if the name or value is longer than 63 bytes
+    pub fn set_named_property(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        if name.len() >= PROPERTY_FIELD || value.len() >= PROPERTY_FIELD {
+            return Err(Error::InvalidTagg(format!(
+                "named property `{name}` is longer than {} bytes",
+                PROPERTY_FIELD - 1
+            )));
+        }
+        let mut data = vec![0; PROPERTY_FIELD * 2];
+        data[..name.len()].copy_from_slice(name.as_bytes());
+        data[PROPERTY_FIELD..PROPERTY_FIELD + value.len()].copy_from_slice(value.as_bytes());
+        let data = data.into_boxed_slice();
+        if let Some(existing) = self.taggs.iter_mut().find(|(tagg, data)| {
+            tagg == PROPERTY
+                && data.len() == PROPERTY_FIELD * 2
+                && cstring(&data[..PROPERTY_FIELD]).eq_ignore_ascii_case(name)
+        }) {
+            existing.1 = data;
+        } else {
+            self.taggs.push((PROPERTY.to_string(), data));
+        }
+        Ok(())
+    }
+
+    /// Remove a named property, returning if it existed
+    pub fn remove_named_property(&mut self, name: &str) -> bool {
+        let before = self.taggs.len();
+        self.taggs.retain(|(tagg, data)| {
+            tagg != PROPERTY
+                || data.len() != PROPERTY_FIELD * 2
+                || !cstring(&data[..PROPERTY_FIELD]).eq_ignore_ascii_case(name)
+        });
+        self.taggs.len() != before
+    }
+
+    #[must_use]
+    /// Mass of each point, from the `#Mass#` TAGG
+    ///
+    /// `None` if the LOD has no masses, or they do not match the points
+    pub fn masses(&self) -> Option<Vec<f32>> {
+        self.taggs_named(MASS)
+            .find(|data| data.len() == self.points.len() * 4)
+            .map(floats)
+    }
+
+    #[must_use]
+    /// Total mass of the points
+    pub fn mass(&self) -> Option<f32> {
+        self.masses().map(|masses| masses.iter().sum())
+    }
+
+    /// Set the mass of each point
+    ///
+    /// # Errors
+    /// [`Error::InvalidTagg`] if there is not one mass for each point
+    pub fn set_masses(&mut self, masses: &[f32]) -> Result<(), Error> {
+        if masses.len() != self.points.len() {
+            return Err(Error::InvalidTagg(format!(
+                "{} masses for {} points",
+                masses.len(),
+                self.points.len()
+            )));
+        }
+        self.replace_taggs(MASS, vec![to_bytes(masses.iter().copied())]);
+        Ok(())
+    }
+
+    #[must_use]
+    /// Named selections, by name
+    pub fn named_selections(&self) -> IndexMap<String, NamedSelection> {
+        self.taggs
+            .iter()
+            .filter(|(name, data)| self.is_selection(name, data))
+            .map(|(name, data)| (name.clone(), NamedSelection::read(data, self.points.len())))
+            .collect()
+    }
+
+    #[must_use]
+    /// A named selection, names are case insensitive
+    pub fn named_selection(&self, name: &str) -> Option<NamedSelection> {
+        self.taggs
+            .iter()
+            .find(|(tagg, data)| tagg.eq_ignore_ascii_case(name) && self.is_selection(tagg, data))
+            .map(|(_, data)| NamedSelection::read(data, self.points.len()))
+    }
+
+    /// Names of the named selections, without reading them
+    pub fn selection_names(&self) -> impl Iterator<Item = &str> {
+        self.taggs
+            .iter()
+            .filter(|(name, data)| self.is_selection(name, data))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Set a named selection, replacing any selection with the same name
+    ///
+    /// The selection is stored against the current points and faces,
+    /// it needs to be set again if they change.
+    ///
+    /// # Errors
+    /// [`Error::InvalidTagg`] if the name starts with `#`, or the selection references a missing point or face
+    pub fn set_named_selection(
+        &mut self,
+        name: &str,
+        selection: &NamedSelection,
+    ) -> Result<(), Error> {
+        if name.is_empty() || name.starts_with('#') {
+            return Err(Error::InvalidTagg(format!(
+                "`{name}` is not a valid selection name"
+            )));
+        }
+        let points = self.points.len();
+        let mut data = vec![0; points + self.faces.len()];
+        for (point, w) in &selection.points {
+            *data.get_mut(*point as usize).ok_or_else(|| {
+                Error::InvalidTagg(format!(
+                    "selection `{name}` references missing point {point}"
+                ))
+            })? = weight_byte(*w);
+        }
+        for (face, w) in &selection.faces {
+            *data.get_mut(points + *face as usize).ok_or_else(|| {
+                Error::InvalidTagg(format!("selection `{name}` references missing face {face}"))
+            })? = weight_byte(*w);
+        }
+        let data = data.into_boxed_slice();
+        if let Some(index) = self.selection_index(name) {
+            self.taggs[index] = (name.to_string(), data);
+        } else {
+            self.taggs.push((name.to_string(), data));
+        }
+        Ok(())
+    }
+
+    /// Remove a named selection, returning if it existed
+    pub fn remove_named_selection(&mut self, name: &str) -> bool {
+        self.selection_index(name)
+            .map(|index| self.taggs.remove(index))
+            .is_some()
+    }
+
+    #[must_use]
+    /// Additional UV sets, from the `#UVSet#` TAGGs
+    ///
+    /// The first set usually matches the coordinates stored on the faces
+    pub fn uv_sets(&self) -> Vec<UvSet> {
+        let vertices = self.faces.iter().map(|f| f.vertices.len()).sum::<usize>();
+        self.taggs_named(UV_SET)
+            .filter(|data| data.len() == 4 + vertices * 8)
+            .map(|data| {
+                let (id, coords) = data.split_at(4);
+                let mut coords = floats(coords).into_iter();
+                UvSet {
+                    id: u32::from_le_bytes([id[0], id[1], id[2], id[3]]),
+                    uvs: self
+                        .faces
+                        .iter()
+                        .map(|face| {
+                            (&mut coords)
+                                .take(face.vertices.len() * 2)
+                                .collect::<Vec<_>>()
+                                .chunks_exact(2)
+                                .map(|uv| (uv[0], uv[1]))
+                                .collect()
+                        })
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Set a UV set, replacing any set with the same id
+    ///
+    /// # Errors
+    /// [`Error::InvalidTagg`] if the set does not have coordinates for each vertex of each face
+    pub fn set_uv_set(&mut self, uv_set: &UvSet) -> Result<(), Error> {
+        if uv_set.uvs.len() != self.faces.len()
+            || uv_set
+                .uvs
+                .iter()
+                .zip(&self.faces)
+                .any(|(uvs, face)| uvs.len() != face.vertices.len())
+        {
+            return Err(Error::InvalidTagg(format!(
+                "UV set {} does not match the faces",
+                uv_set.id
+            )));
+        }
+        let mut data = uv_set.id.to_le_bytes().to_vec();
+        data.extend(to_bytes(
+            uv_set.uvs.iter().flatten().flat_map(|(u, v)| [*u, *v]),
+        ));
+        let data = data.into_boxed_slice();
+        if let Some(existing) = self
+            .taggs
+            .iter_mut()
+            .find(|(tagg, data)| tagg == UV_SET && data.get(..4) == Some(&uv_set.id.to_le_bytes()))
+        {
+            existing.1 = data;
+        } else {
+            self.taggs.push((UV_SET.to_string(), data));
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    /// Pairs of points joined by a sharp edge, from the `#SharpEdges#` TAGG
+    pub fn sharp_edges(&self) -> Vec<(u32, u32)> {
+        self.taggs_named(SHARP_EDGES)
+            .flat_map(|data| data.chunks_exact(8))
+            .map(|edge| {
+                (
+                    u32::from_le_bytes([edge[0], edge[1], edge[2], edge[3]]),
+                    u32::from_le_bytes([edge[4], edge[5], edge[6], edge[7]]),
+                )
+            })
+            .collect()
+    }
+
+    /// Set the sharp edges, replacing the existing edges
+    pub fn set_sharp_edges(&mut self, edges: &[(u32, u32)]) {
+        let data = edges
+            .iter()
+            .flat_map(|(a, b)| [a.to_le_bytes(), b.to_le_bytes()])
+            .flatten()
+            .collect::<Box<[u8]>>();
+        self.replace_taggs(SHARP_EDGES, vec![data]);
+    }
+
+    #[must_use]
+    /// Frames of the `#Animation#` TAGGs
+    pub fn animations(&self) -> Vec<AnimationFrame> {
+        self.taggs_named(ANIMATION)
+            .filter(|data| data.len() == 4 + self.points.len() * 12)
+            .map(|data| {
+                let values = floats(data);
+                AnimationFrame {
+                    time: values[0],
+                    points: values[1..]
+                        .chunks_exact(3)
+                        .map(|p| (p[0], p[1], p[2]))
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Set the animation frames, replacing the existing frames
+    ///
+    /// # Errors
+    /// [`Error::InvalidTagg`] if a frame does not have a position for each point
+    pub fn set_animations(&mut self, frames: &[AnimationFrame]) -> Result<(), Error> {
+        if let Some(frame) = frames.iter().find(|f| f.points.len() != self.points.len()) {
+            return Err(Error::InvalidTagg(format!(
+                "animation frame {} has {} points, expected {}",
+                frame.time,
+                frame.points.len(),
+                self.points.len()
+            )));
+        }
+        let data = frames
+            .iter()
+            .map(|frame| {
+                to_bytes(
+                    std::iter::once(frame.time)
+                        .chain(frame.points.iter().flat_map(|p| [p.0, p.1, p.2])),
+                )
+            })
+            .collect();
+        self.replace_taggs(ANIMATION, data);
+        Ok(())
+    }
+
+    /// If the TAGG is exposed by one of the typed accessors
+    pub(crate) fn is_typed_tagg(&self, name: &str, data: &[u8]) -> bool {
+        matches!(name, PROPERTY | MASS | UV_SET | SHARP_EDGES | ANIMATION)
+            || self.is_selection(name, data)
+    }
+
+    fn is_selection(&self, name: &str, data: &[u8]) -> bool {
+        !name.starts_with('#') && data.len() == self.points.len() + self.faces.len()
+    }
+
+    fn selection_index(&self, name: &str) -> Option<usize> {
+        self.taggs.iter().position(|(tagg, data)| {
+            tagg.eq_ignore_ascii_case(name) && self.is_selection(tagg, data)
+        })
+    }
+
+    fn taggs_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a [u8]> + 'a {
+        self.taggs
+            .iter()
+            .filter(move |(tagg, _)| tagg == name)
+            .map(|(_, data)| &**data)
+    }
+
+    /// Replace every TAGG with the name, keeping the position of the first
+    fn replace_taggs(&mut self, name: &str, data: Vec<Box<[u8]>>) {
+        let position = self
+            .taggs
+            .iter()
+            .position(|(tagg, _)| tagg == name)
+            .unwrap_or(self.taggs.len());
+        self.taggs.retain(|(tagg, _)| tagg != name);
+        let position = position.min(self.taggs.len());
+        self.taggs.splice(
+            position..position,
+            data.into_iter().map(|data| (name.to_string(), data)),
+        );
+    }
+}
+
+fn weight(byte: u8) -> f32 {
+    match byte {
+        0 => 0.0,
+        1 => 1.0,
+        byte => f32::from(256 - u16::from(byte)) / 255.0,
+    }
+}
+
+fn weight_byte(weight: f32) -> u8 {
+    if weight >= 1.0 {
+        1
+    } else if weight <= 0.0 {
+        0
+    } else {
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "clamped to the range of partial weights"
+        )]
+        {
+            255.0f32.mul_add(-weight, 256.0).round().clamp(2.0, 255.0) as u8
+        }
+    }
+}
+
+fn cstring(data: &[u8]) -> String {
+    let end = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    String::from_utf8_lossy(&data[..end]).into_owned()
+}
+
+fn floats(data: &[u8]) -> Vec<f32> {
+    data.chunks_exact(4)
+        .map(|f| f32::from_le_bytes([f[0], f[1], f[2], f[3]]))
+        .collect()
+}
+
+fn to_bytes(values: impl Iterator<Item = f32>) -> Box<[u8]> {
+    values.flat_map(f32::to_le_bytes).collect()
+}
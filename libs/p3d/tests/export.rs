@@ -0,0 +1,93 @@
+#![allow(clippy::unwrap_used, clippy::float_cmp)]
+
+use hemtt_p3d::{P3D, export};
+
+fn gunbag() -> P3D {
+    P3D::read(&mut fs_err::File::open("tests/ace_gunbag.p3d").unwrap()).unwrap()
+}
+
+#[test]
+fn mesh() {
+    let p3d = gunbag();
+    let lod = &p3d.lods[0];
+    let mesh = lod.mesh();
+    let triangles = lod
+        .faces
+        .iter()
+        .map(|f| f.vertices.len() - 2)
+        .sum::<usize>();
+    assert_eq!(
+        mesh.primitives
+            .iter()
+            .map(|p| p.indices.len() / 3)
+            .sum::<usize>(),
+        triangles
+    );
+    for primitive in &mesh.primitives {
+        assert_eq!(primitive.positions.len(), primitive.normals.len());
+        assert_eq!(primitive.positions.len(), primitive.uvs.len());
+        assert!(
+            primitive
+                .indices
+                .iter()
+                .all(|i| (*i as usize) < primitive.positions.len())
+        );
+    }
+    // mirrored on the z axis
+    let first = &lod.points[lod.faces[0].vertices[0].point_index as usize];
+    assert!(
+        mesh.primitives
+            .iter()
+            .flat_map(|p| &p.positions)
+            .any(|p| *p == [first.coords.0, first.coords.1, -first.coords.2])
+    );
+    assert!(!mesh.selections.is_empty());
+}
+
+#[test]
+fn gltf() {
+    let p3d = gunbag();
+    let meshes = p3d
+        .lods
+        .iter()
+        .map(hemtt_p3d::LOD::mesh)
+        .collect::<Vec<_>>();
+    let (document, buffer) = export::to_gltf(&meshes, "gunbag.bin", |texture| {
+        texture
+            .ends_with("gunbag_co.paa")
+            .then(|| "gunbag_co.png".to_string())
+    });
+    let document: serde_json::Value = serde_json::from_str(&document).unwrap();
+    assert_eq!(document["asset"]["version"], "2.0");
+    assert_eq!(document["nodes"].as_array().unwrap().len(), p3d.lods.len());
+    assert_eq!(document["buffers"][0]["uri"], "gunbag.bin");
+    assert_eq!(document["buffers"][0]["byteLength"], buffer.len());
+    assert_eq!(document["images"][0]["uri"], "gunbag_co.png");
+    let views = document["bufferViews"].as_array().unwrap();
+    let last = views.last().unwrap();
+    assert_eq!(
+        last["byteOffset"].as_u64().unwrap() + last["byteLength"].as_u64().unwrap(),
+        buffer.len() as u64
+    );
+    assert!(document["nodes"][0]["extras"]["selections"].is_object());
+}
+
+#[test]
+fn obj() {
+    let p3d = gunbag();
+    let mesh = p3d.lods[0].mesh();
+    let (obj, mtl) = export::to_obj(std::slice::from_ref(&mesh), "gunbag.mtl", |_| None);
+    assert!(obj.starts_with("# Exported by HEMTT\nmtllib gunbag.mtl\n"));
+    assert_eq!(
+        obj.lines().filter(|l| l.starts_with("f ")).count(),
+        mesh.primitives
+            .iter()
+            .map(|p| p.indices.len() / 3)
+            .sum::<usize>()
+    );
+    assert_eq!(
+        mtl.lines().filter(|l| l.starts_with("newmtl ")).count(),
+        mesh.primitives.len()
+    );
+    assert!(!mtl.contains("map_Kd"));
+}
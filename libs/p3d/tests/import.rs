@@ -0,0 +1,188 @@
+#![allow(clippy::unwrap_used, clippy::float_cmp)]
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use hemtt_p3d::{LOD, P3D, export, import};
+
+fn gunbag() -> P3D {
+    P3D::read(&mut fs_err::File::open("tests/ace_gunbag.p3d").unwrap()).unwrap()
+}
+
+fn triangles(lod: &LOD) -> usize {
+    lod.faces.iter().map(|f| f.vertices.len() - 2).sum()
+}
+
+/// Positions of the points used by faces
+fn used_points(lod: &LOD) -> HashSet<[u32; 3]> {
+    lod.faces
+        .iter()
+        .flat_map(|f| &f.vertices)
+        .map(|v| {
+            let p: [f32; 3] = lod.points[v.point_index as usize].coords.into();
+            p.map(|c| (c + 0.0).to_bits())
+        })
+        .collect()
+}
+
+fn assert_round_trip(original: &LOD, imported: &LOD) {
+    assert_eq!(imported.faces.len(), triangles(original));
+    assert_eq!(used_points(imported), used_points(original));
+    assert_eq!(imported.faces[0].texture, original.faces[0].texture);
+    assert_eq!(imported.faces[0].material, original.faces[0].material);
+    assert_eq!(imported.version_major, 28);
+    assert_eq!(imported.version_minor, 256);
+}
+
+#[test]
+fn obj_round_trip() {
+    let p3d = gunbag();
+    let original = &p3d.lods[0];
+    let (obj, mtl) = export::to_obj(&[original.mesh()], "gunbag.mtl", |_| None);
+    let scene = import::from_obj(&obj, |uri| (uri == "gunbag.mtl").then(|| mtl.clone())).unwrap();
+    assert_eq!(scene.objects.len(), 1);
+    let imported = scene.to_lod(1.0, &HashMap::new()).unwrap();
+    assert_round_trip(original, &imported);
+    assert_eq!(imported.type_name, "Resolution 1");
+    assert_eq!(imported.taggs.len(), 1);
+    assert_eq!(
+        imported.taggs[0].1.len(),
+        imported.points.len() + imported.faces.len()
+    );
+}
+
+#[test]
+fn gltf_round_trip() {
+    let p3d = gunbag();
+    let original = &p3d.lods[0];
+    let (document, buffer) = export::to_gltf(&[original.mesh()], "gunbag.bin", |_| None);
+    let scene = import::from_gltf(document.as_bytes(), |uri| {
+        (uri == "gunbag.bin").then(|| buffer.clone())
+    })
+    .unwrap();
+    let imported = scene.to_lod(1e15, &HashMap::new()).unwrap();
+    assert_round_trip(original, &imported);
+    assert_eq!(imported.type_name, "Memory");
+
+    // exported again, the normals match the original
+    let normals = |lod: &LOD| {
+        lod.mesh()
+            .primitives
+            .iter()
+            .flat_map(|p| p.normals.clone())
+            .map(|n| n.map(|c| ((c * 1000.0).round() + 0.0).to_bits()))
+            .collect::<HashSet<_>>()
+    };
+    assert_eq!(normals(&imported), normals(original));
+
+    let mut written = Vec::new();
+    P3D {
+        version: 257,
+        lods: vec![imported],
+    }
+    .write(&mut written)
+    .unwrap();
+    let read = P3D::read(&mut std::io::Cursor::new(written)).unwrap();
+    assert_eq!(read.lods[0].faces.len(), triangles(original));
+}
+
+#[test]
+fn gltf_nodes() {
+    let document = r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "name": "parent", "translation": [0, 1, 0], "children": [1] },
+            { "name": "triangle", "mesh": 0, "scale": [2, 2, 2] }
+        ],
+        "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] }],
+        "materials": [{ "name": "box" }],
+        "accessors": [{ "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 36 }],
+        "buffers": [{ "byteLength": 36, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAA" }]
+    }"#;
+    let scene = import::from_gltf(document.as_bytes(), |_| None).unwrap();
+    assert_eq!(scene.objects.len(), 1);
+    assert_eq!(scene.objects[0].name, "triangle");
+    let triangle = &scene.objects[0].triangles[0];
+    assert_eq!(triangle.corners[1].position, [2.0, 1.0, 0.0]);
+    assert_eq!(triangle.corners[2].position, [0.0, 3.0, 0.0]);
+    assert_eq!(triangle.normal(), [0.0, 0.0, 1.0]);
+
+    let materials = HashMap::from([(
+        "box".to_string(),
+        import::MaterialPaths {
+            texture: r"\box\box_co.paa".to_string(),
+            material: r"\box\box.rvmat".to_string(),
+        },
+    )]);
+    let lod = scene.to_lod(1.0, &materials).unwrap();
+    assert_eq!(lod.faces[0].texture, r"\box\box_co.paa");
+    assert_eq!(lod.faces[0].material, r"\box\box.rvmat");
+    // mirrored on the z axis, with inward normals
+    assert_eq!(lod.face_normals, vec![(0.0, 0.0, 1.0)]);
+    assert_eq!(lod.taggs[0].0, "triangle");
+    assert_eq!(&*lod.taggs[0].1, &[1, 1, 1, 1]);
+}
+
+#[test]
+fn obj_polygons() {
+    let obj = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\ng side\nf 1/1 2/1 3/1 4/1\nf -4 -2 -1\n";
+    let scene = import::from_obj(obj, |_| None).unwrap();
+    assert_eq!(scene.objects[0].name, "side");
+    assert_eq!(scene.objects[0].triangles.len(), 3);
+    assert_eq!(scene.objects[0].triangles[0].corners[0].uv, [0.0, 1.0]);
+    assert_eq!(scene.to_lod(1.0, &HashMap::new()).unwrap().points.len(), 4);
+    assert!(import::from_obj("f 1 2 3", |_| None).is_err());
+}
+
+#[test]
+fn convex_geometry() {
+    let mut obj = String::new();
+    for x in [-1, 1] {
+        for y in [-1, 1] {
+            for z in [-1, 1] {
+                writeln!(obj, "v {x} {y} {z}").unwrap();
+            }
+        }
+    }
+    // a point inside the box is not part of the hull
+    obj.push_str("v 0 0 0\nf 1 2 9\nf 3 4 9\nf 5 6 9\nf 7 8 9\n");
+    let lod = import::from_obj(&obj, |_| None)
+        .unwrap()
+        .to_lod(1.0, &HashMap::new())
+        .unwrap();
+    let geometry = lod.convex_geometry(10.0).unwrap();
+    assert_eq!(geometry.type_name, "Geometry");
+    assert_eq!(geometry.points.len(), 8);
+    assert_eq!(geometry.faces.len(), 12);
+    let mass = &geometry
+        .taggs
+        .iter()
+        .find(|(name, _)| name == "#Mass#")
+        .unwrap()
+        .1;
+    assert_eq!(mass.len(), 8 * 4);
+    assert_eq!(
+        f32::from_le_bytes([mass[0], mass[1], mass[2], mass[3]]),
+        1.25
+    );
+    // every normal points into the box
+    for face in &geometry.faces {
+        let normal = geometry.face_normals[face.vertices[0].normal_index as usize];
+        let point = geometry.points[face.vertices[0].point_index as usize].coords;
+        assert!(
+            normal
+                .2
+                .mul_add(point.2, normal.0.mul_add(point.0, normal.1 * point.1))
+                < 0.0
+        );
+    }
+
+    let flat = import::from_obj("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n", |_| None)
+        .unwrap()
+        .to_lod(1.0, &HashMap::new())
+        .unwrap();
+    assert!(flat.convex_geometry(1.0).is_none());
+}
@@ -0,0 +1,56 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_common::config::{PDriveOption, ProjectConfig};
+use hemtt_p3d::{
+    P3D,
+    analyze::{LintData, ModelFile, lint_one},
+};
+use hemtt_workspace::{LayerType, reporting::WorkspaceFiles};
+
+const ROOT: &str = "tests/lints/";
+
+macro_rules! lint {
+    ($dir:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<model_ $dir>]() {
+                insta::assert_snapshot!(lint(stringify!($dir)));
+            }
+        }
+    };
+}
+
+lint!(m01_required_lods);
+lint!(m02_geometry_mass);
+lint!(m03_missing_proxy);
+lint!(m04_texture_prefix);
+lint!(m05_polycount);
+
+fn lint(file: &str) -> String {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&std::path::PathBuf::from(ROOT), LayerType::Source)
+        .finish(None, true, &PDriveOption::Disallow)
+        .unwrap();
+    let path = format!("addons/main/{file}.p3d");
+    let p3d = P3D::read(&mut fs_err::File::open(format!("{ROOT}{path}")).unwrap()).unwrap();
+    let config =
+        ProjectConfig::from_file(&std::path::PathBuf::from(ROOT).join("project_tests.toml"))
+            .unwrap();
+    let workspace_files = WorkspaceFiles::new();
+    let data = LintData::new(Some(workspace), &[config.prefix().to_string()]);
+    let mut codes = lint_one(&ModelFile::new(path, p3d), Some(&config), &data);
+
+    codes.retain(|e| {
+        e.ident().starts_with(&format!(
+            "L-{}",
+            file.split_once('_').unwrap().0.to_uppercase()
+        ))
+    });
+
+    codes
+        .iter()
+        .map(|e| e.diagnostic().unwrap().to_string(&workspace_files))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace('\r', "")
+}
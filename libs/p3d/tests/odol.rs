@@ -0,0 +1,290 @@
+#![allow(clippy::unwrap_used, clippy::float_cmp)]
+
+use hemtt_p3d::{Model, ODOL, odol::AnimationType};
+
+/// Builds ODOL data by hand, there are no binarized fixtures that can be redistributed
+#[derive(Default)]
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, value: u8) -> &mut Self {
+        self.0.push(value);
+        self
+    }
+
+    fn u32(&mut self, value: u32) -> &mut Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn i32(&mut self, value: i32) -> &mut Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn f32(&mut self, value: f32) -> &mut Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn zeros(&mut self, count: usize) -> &mut Self {
+        self.0.extend(std::iter::repeat_n(0, count));
+        self
+    }
+
+    fn cstring(&mut self, value: &str) -> &mut Self {
+        self.0.extend_from_slice(value.as_bytes());
+        self.0.push(0);
+        self
+    }
+
+    fn vertex_indices(&mut self, indices: &[u32], compress: bool) -> &mut Self {
+        self.u32(u32::try_from(indices.len()).unwrap());
+        if indices.is_empty() {
+            return self;
+        }
+        let data = indices
+            .iter()
+            .flat_map(|i| i.to_le_bytes())
+            .collect::<Vec<_>>();
+        self.u8(u8::from(compress));
+        if compress {
+            let mut compressed = Vec::with_capacity(hemtt_lzo::lzss::worst_compress(data.len()));
+            hemtt_lzo::lzss::compress(&data, &mut compressed).unwrap();
+            self.0.extend_from_slice(&compressed);
+        } else {
+            self.0.extend_from_slice(&data);
+        }
+        self
+    }
+
+    fn model_info(&mut self, lods: usize) -> &mut Self {
+        self.i32(0).f32(2.5).f32(2.0).zeros(12);
+        // aiming center
+        self.zeros(12).zeros(12);
+        // bounding box
+        self.f32(-1.0)
+            .f32(-1.0)
+            .f32(-1.0)
+            .f32(1.0)
+            .f32(1.0)
+            .f32(1.0);
+        self.zeros(8);
+        // visual bounding box, bounding center, geometry center
+        self.zeros(12 * 4);
+        // center of mass
+        self.f32(0.0).f32(0.5).f32(0.0);
+        self.zeros(36);
+        self.u8(1).u8(0).u8(1).u8(1).u8(0);
+        self.zeros(16 + 8 + 1 + 5 + 4 + 1);
+        self.cstring("TestSkeleton")
+            .u8(0)
+            .u32(2)
+            .cstring("door")
+            .cstring("")
+            .cstring("handle")
+            .cstring("door")
+            .cstring("");
+        // map type, empty mass array
+        self.u8(0).u32(0);
+        self.f32(120.0).zeros(4).f32(50.0).zeros(4).zeros(4);
+        // geometry simple, geometry physx, memory, geometry, fire, view, pilot, gunner, commander, cargo
+        self.u8(255).u8(255).u8(1).u8(255).u8(255).u8(255);
+        self.u8(255).u8(255).u8(255).u8(255);
+        // land contact, roadway, paths, hitpoints
+        self.u8(255).u8(255).u8(255).u8(255);
+        self.zeros(4).u8(0);
+        self.cstring("house").cstring("building").u8(0).zeros(4);
+        self.zeros(3 * 4 * lods)
+    }
+
+    fn animations(&mut self, lods: u32) -> &mut Self {
+        self.u8(1).u32(2);
+        self.u32(0)
+            .cstring("door_rot")
+            .cstring("door_source")
+            .zeros(8)
+            .f32(0.0)
+            .f32(1.0)
+            .zeros(8 + 4 + 8);
+        self.u32(9)
+            .cstring("handle_hide")
+            .cstring("damage")
+            .zeros(8)
+            .f32(0.0)
+            .f32(1.0)
+            .zeros(8 + 4 + 8);
+        self.u32(lods);
+        for _ in 0..lods {
+            self.u32(1).u32(1).u32(0);
+        }
+        for _ in 0..lods {
+            // the rotation has an axis, the hide does not
+            self.i32(0).zeros(24).i32(1);
+        }
+        self
+    }
+
+    fn lod(&mut self, texture: &str, selection: &str) -> &mut Self {
+        self.u32(1)
+            .cstring("\\a3\\data_f\\proxies\\dummy.p3d")
+            .zeros(12 * 4)
+            .i32(1)
+            .i32(0)
+            .i32(-1)
+            .i32(0);
+        // sub skeletons
+        self.u32(0).u32(0);
+        // vertex count, face area, hints
+        self.u32(3).zeros(4).zeros(8);
+        self.f32(-1.0)
+            .f32(-1.0)
+            .f32(-1.0)
+            .f32(1.0)
+            .f32(1.0)
+            .f32(1.0);
+        self.zeros(12).f32(1.7);
+        self.u32(1).cstring(texture);
+        // material
+        self.u32(1)
+            .cstring("test\\data\\box.rvmat")
+            .u32(11)
+            .zeros(6 * 16 + 4 + 16);
+        self.cstring("test\\data\\box.bisurf")
+            .zeros(8)
+            .u32(1)
+            .u32(1);
+        self.zeros(4)
+            .cstring("test\\data\\box_nohq.paa")
+            .zeros(4)
+            .u8(0);
+        self.zeros(4 + 48);
+        self.zeros(4).cstring("").zeros(4).u8(0);
+        // point to vertex, vertex to point
+        self.vertex_indices(&[0, 1, 2], false)
+            .vertex_indices(&[0, 1, 2], false);
+        // faces
+        self.u32(1).zeros(6).u8(3).u32(0).u32(1).u32(2);
+        // sections
+        self.u32(1).zeros(26).i32(0).u32(1).zeros(4).i32(0);
+        // named selections
+        let faces = (0..2000).map(|i| i % 7).collect::<Vec<_>>();
+        self.u32(1).cstring(selection).vertex_indices(&faces, true);
+        self.zeros(4).u8(0).u32(0);
+        self.vertex_indices(&[0, 1, 2], false).u32(3).u8(0).zeros(3);
+        // named properties
+        self.u32(1).cstring("class").cstring("house");
+        // geometry data that is not read
+        self.zeros(64)
+    }
+}
+
+fn build() -> Vec<u8> {
+    let mut writer = Writer::default();
+    writer.0.extend_from_slice(b"ODOL");
+    writer.u32(73).u32(0).cstring("").u32(2);
+    writer.f32(1.0).f32(1e15);
+    writer.model_info(2).animations(2);
+    let addresses = writer.0.len();
+    writer.zeros(2 * 4 * 2).u8(1).u8(1);
+
+    let mut ranges = Vec::new();
+    for (texture, selection) in [
+        ("test\\data\\box_co.paa", "door"),
+        ("#(argb,8,8,3)color(1,0,0,1)", "handle"),
+    ] {
+        let start = writer.0.len();
+        writer.lod(texture, selection);
+        ranges.push((start, writer.0.len()));
+    }
+    for (i, (start, end)) in ranges.into_iter().enumerate() {
+        let start_at = addresses + i * 4;
+        let end_at = addresses + 8 + i * 4;
+        writer.0[start_at..start_at + 4]
+            .copy_from_slice(&u32::try_from(start).unwrap().to_le_bytes());
+        writer.0[end_at..end_at + 4].copy_from_slice(&u32::try_from(end).unwrap().to_le_bytes());
+    }
+    writer.0
+}
+
+#[test]
+fn read() {
+    let odol = ODOL::read(&mut std::io::Cursor::new(build())).unwrap();
+    assert_eq!(odol.version, 73);
+    assert_eq!(odol.model_info.mass, 120.0);
+    assert_eq!(odol.model_info.armor, 50.0);
+    assert_eq!(odol.model_info.center_of_mass, (0.0, 0.5, 0.0));
+    assert!(odol.model_info.auto_center);
+    assert_eq!(odol.model_info.property_class, "house");
+    assert_eq!(odol.model_info.special_lods.memory, 1);
+    let skeleton = odol.model_info.skeleton.as_ref().unwrap();
+    assert_eq!(skeleton.name, "TestSkeleton");
+    assert_eq!(
+        skeleton.bones,
+        vec![
+            ("door".to_string(), String::new()),
+            ("handle".to_string(), "door".to_string())
+        ]
+    );
+
+    assert_eq!(odol.animations.len(), 2);
+    assert_eq!(odol.animations[0].source, "door_source");
+    assert_eq!(odol.animations[1].kind, AnimationType::Hide);
+
+    assert_eq!(odol.lods.len(), 2);
+    let lod = &odol.lods[0];
+    assert_eq!(lod.type_name, "Resolution 1");
+    assert_eq!(lod.vertex_count, 3);
+    assert_eq!(lod.face_count, 1);
+    assert_eq!(lod.bounds.radius, 1.7);
+    assert_eq!(lod.proxies[0].model, "\\a3\\data_f\\proxies\\dummy.p3d");
+    assert_eq!(lod.proxies[0].sequence_id, 1);
+    assert_eq!(lod.textures, vec!["test\\data\\box_co.paa"]);
+    assert_eq!(lod.materials[0].name, "test\\data\\box.rvmat");
+    assert_eq!(lod.materials[0].surface, "test\\data\\box.bisurf");
+    assert_eq!(
+        lod.materials[0].textures,
+        vec!["test\\data\\box_nohq.paa", ""]
+    );
+    assert_eq!(lod.named_selections[0].name, "door");
+    assert_eq!(lod.named_selections[0].faces, 2000);
+    assert_eq!(lod.named_selections[0].vertices, 3);
+    assert_eq!(
+        lod.named_properties,
+        vec![("class".to_string(), "house".to_string())]
+    );
+    assert_eq!(odol.lods[1].type_name, "Memory");
+    assert_eq!(odol.lods[1].named_selections[0].name, "handle");
+
+    let mut dependencies = odol.dependencies();
+    dependencies.sort();
+    assert_eq!(
+        dependencies,
+        vec!["test\\data\\box.rvmat", "test\\data\\box_co.paa"]
+    );
+}
+
+#[test]
+fn model() {
+    let model = Model::read(&mut std::io::Cursor::new(build())).unwrap();
+    assert!(matches!(model, Model::ODOL(_)));
+    let json = serde_json::to_value(&model).unwrap();
+    assert_eq!(json["lods"][1]["type_name"], "Memory");
+
+    let model = Model::read(&mut fs_err::File::open("tests/ace_gunbag.p3d").unwrap()).unwrap();
+    assert!(matches!(model, Model::MLOD(_)));
+}
+
+#[test]
+fn unsupported() {
+    let mut data = build();
+    data[4..8].copy_from_slice(&7u32.to_le_bytes());
+    assert!(matches!(
+        ODOL::read(&mut std::io::Cursor::new(data)),
+        Err(hemtt_p3d::Error::UnsupportedODOLVersion(7))
+    ));
+    assert!(matches!(
+        Model::read(&mut std::io::Cursor::new(b"NOPE".to_vec())),
+        Err(hemtt_p3d::Error::UnsupportedP3DType(_))
+    ));
+}
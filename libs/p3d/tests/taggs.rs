@@ -0,0 +1,134 @@
+#![allow(clippy::unwrap_used, clippy::float_cmp)]
+
+use hemtt_p3d::{AnimationFrame, LOD, NamedSelection, P3D};
+
+fn gunbag() -> P3D {
+    P3D::read(&mut fs_err::File::open("tests/ace_gunbag.p3d").unwrap()).unwrap()
+}
+
+fn tagg<'a>(lod: &'a LOD, name: &str) -> &'a [u8] {
+    &lod.taggs.iter().find(|(n, _)| n == name).unwrap().1
+}
+
+#[test]
+fn named_properties() {
+    let mut p3d = gunbag();
+    let lod = &mut p3d.lods[0];
+    let properties = lod.named_properties();
+    assert_eq!(properties.get("autocenter").unwrap(), "0");
+    assert_eq!(properties.get("lodnoshadow").unwrap(), "1");
+
+    let taggs = lod.taggs.len();
+    lod.set_named_property("AutoCenter", "1").unwrap();
+    assert_eq!(lod.taggs.len(), taggs);
+    assert_eq!(lod.named_properties().get("AutoCenter").unwrap(), "1");
+    lod.set_named_property("class", "house").unwrap();
+    assert_eq!(lod.named_properties().len(), 3);
+    assert!(lod.remove_named_property("lodnoshadow"));
+    assert!(!lod.remove_named_property("lodnoshadow"));
+    assert!(lod.set_named_property("class", &"x".repeat(64)).is_err());
+}
+
+#[test]
+fn named_selections() {
+    let mut p3d = gunbag();
+    let lod = &mut p3d.lods[3];
+    assert_eq!(lod.type_name, "Resolution 10");
+    let selections = lod.named_selections();
+    assert_eq!(
+        selections.keys().collect::<Vec<_>>(),
+        ["Spine2", "insignia", "Camo", "Camo1"]
+    );
+    let camo = lod.named_selection("camo").unwrap();
+    assert!(camo.points.values().any(|w| *w > 0.0 && *w < 1.0));
+    assert_eq!(selections["Spine2"].points.len(), lod.points.len());
+
+    // partial weights are written back unchanged
+    let original = tagg(lod, "Camo").to_vec();
+    lod.set_named_selection("Camo", &camo).unwrap();
+    assert_eq!(tagg(lod, "Camo"), original);
+
+    let selection = NamedSelection {
+        points: [(0, 1.0), (1, 0.5)].into(),
+        faces: [(0, 1.0)].into(),
+    };
+    lod.set_named_selection("proxy:test", &selection).unwrap();
+    let read = lod.named_selection("proxy:test").unwrap();
+    assert_eq!(read.faces, selection.faces);
+    assert!((read.points[&1] - 0.5).abs() < 0.01);
+    assert!(lod.remove_named_selection("proxy:test"));
+    assert!(lod.named_selection("proxy:test").is_none());
+
+    let missing = NamedSelection {
+        points: [(u32::MAX, 1.0)].into(),
+        ..Default::default()
+    };
+    assert!(lod.set_named_selection("missing", &missing).is_err());
+    assert!(lod.set_named_selection("#Mass#", &camo).is_err());
+}
+
+#[test]
+fn masses() {
+    let mut p3d = gunbag();
+    let geometry = p3d
+        .lods
+        .iter_mut()
+        .find(|l| l.type_name == "Geometry")
+        .unwrap();
+    let masses = geometry.masses().unwrap();
+    assert_eq!(masses.len(), geometry.points.len());
+    assert!(geometry.mass().unwrap() > 0.0);
+
+    geometry.set_masses(&vec![2.0; masses.len()]).unwrap();
+    assert_eq!(geometry.mass().unwrap(), 16.0);
+    assert!(geometry.set_masses(&[1.0]).is_err());
+    assert!(p3d.lods[0].masses().is_none());
+}
+
+#[test]
+fn uv_sets_and_edges() {
+    let mut p3d = gunbag();
+    let lod = &mut p3d.lods[0];
+    let uv_sets = lod.uv_sets();
+    assert_eq!(uv_sets.len(), 1);
+    assert_eq!(uv_sets[0].id, 0);
+    assert_eq!(
+        uv_sets[0].uvs[0],
+        lod.faces[0]
+            .vertices
+            .iter()
+            .map(|v| v.uv)
+            .collect::<Vec<_>>()
+    );
+    let original = tagg(lod, "#UVSet#").to_vec();
+    lod.set_uv_set(&uv_sets[0]).unwrap();
+    assert_eq!(tagg(lod, "#UVSet#"), original);
+
+    let edges = lod.sharp_edges();
+    assert_eq!(edges.len(), 7536 / 8);
+    let original = tagg(lod, "#SharpEdges#").to_vec();
+    lod.set_sharp_edges(&edges);
+    assert_eq!(tagg(lod, "#SharpEdges#"), original);
+
+    assert!(lod.animations().is_empty());
+    let frame = AnimationFrame {
+        time: 0.5,
+        points: vec![(1.0, 2.0, 3.0); lod.points.len()],
+    };
+    lod.set_animations(std::slice::from_ref(&frame)).unwrap();
+    assert_eq!(lod.animations(), vec![frame]);
+}
+
+#[test]
+fn json() {
+    let p3d = gunbag();
+    let json = serde_json::to_value(&p3d).unwrap();
+    let lod = &json["lods"][0];
+    assert_eq!(lod["named_properties"]["autocenter"], "0");
+    assert_eq!(lod["named_selections"]["Spine2"]["faces"]["0"], 1.0);
+    assert_eq!(lod["uv_sets"][0]["id"], 0);
+    assert!(lod["masses"].is_null());
+    // only TAGGs without a typed accessor are left raw
+    assert!(lod["taggs"].as_array().unwrap().is_empty());
+    assert!(json["lods"][7]["masses"].is_array());
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{LintData, Texture};
+
+crate::analyze::lint!(LintT01Dimensions);
+
+impl Lint<LintData> for LintT01Dimensions {
+    fn ident(&self) -> &'static str {
+        "dimensions"
+    }
+
+    fn sort(&self) -> u32 {
+        10
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on textures with dimensions that are not a power of two"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Example
+
+**Incorrect**
+A texture of `1000x1000`
+
+**Correct**
+A texture of `1024x1024`
+
+### Explanation
+
+Arma 3 requires the width and height of a texture to be powers of two, such as `256`, `512` or `1024`. The width and height do not need to be equal. Textures with other dimensions will not be displayed correctly in game."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Texture;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &Texture,
+        _data: &LintData,
+    ) -> Codes {
+        let Some((width, height)) = target.dimensions() else {
+            return vec![];
+        };
+        if width.is_power_of_two() && height.is_power_of_two() {
+            return vec![];
+        }
+        vec![Arc::new(CodeTextureDimensions::new(
+            target.path().to_string(),
+            (width, height),
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeTextureDimensions {
+    path: String,
+    dimensions: (u16, u16),
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeTextureDimensions {
+    fn ident(&self) -> &'static str {
+        "L-T01"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/textures.html#dimensions")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Texture `{}` is {}x{}, which is not a power of two",
+            self.path, self.dimensions.0, self.dimensions.1
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("resize the texture so both dimensions are powers of two".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeTextureDimensions {
+    #[must_use]
+    pub fn new(path: String, dimensions: (u16, u16), severity: Severity) -> Self {
+        Self {
+            path,
+            dimensions,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
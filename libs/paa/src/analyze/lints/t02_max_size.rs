@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{LintData, Texture};
+
+crate::analyze::lint!(LintT02MaxSize);
+
+/// The largest dimension allowed when `max` is not set
+const DEFAULT_MAX: i64 = 4096;
+
+impl Lint<LintData> for LintT02MaxSize {
+    fn ident(&self) -> &'static str {
+        "max_size"
+    }
+
+    fn sort(&self) -> u32 {
+        20
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on textures that are larger than a maximum size"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Configuration
+
+- **max**: The largest width or height allowed, default is `4096`.
+
+```toml
+[lints.textures.max_size]
+options.max = 2048
+```
+
+### Explanation
+
+Large textures use more memory and disk space, and take longer to load. Most textures do not need to be larger than `4096`, and smaller items rarely need more than `2048`."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Texture;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &Texture,
+        _data: &LintData,
+    ) -> Codes {
+        let Some((width, height)) = target.dimensions() else {
+            return vec![];
+        };
+        let max = if let Some(toml::Value::Integer(max)) = config.option("max") {
+            *max
+        } else {
+            DEFAULT_MAX
+        };
+        if i64::from(width.max(height)) <= max {
+            return vec![];
+        }
+        vec![Arc::new(CodeTextureMaxSize::new(
+            target.path().to_string(),
+            (width, height),
+            max,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeTextureMaxSize {
+    path: String,
+    dimensions: (u16, u16),
+    max: i64,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeTextureMaxSize {
+    fn ident(&self) -> &'static str {
+        "L-T02"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/textures.html#max_size")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Texture `{}` is {}x{}, larger than {}",
+            self.path, self.dimensions.0, self.dimensions.1, self.max
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "resize the texture to at most {}, or raise `max` if it is needed",
+            self.max
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeTextureMaxSize {
+    #[must_use]
+    pub fn new(path: String, dimensions: (u16, u16), max: i64, severity: Severity) -> Self {
+        Self {
+            path,
+            dimensions,
+            max,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
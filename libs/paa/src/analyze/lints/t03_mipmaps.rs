@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{LintData, Texture};
+
+crate::analyze::lint!(LintT03Mipmaps);
+
+impl Lint<LintData> for LintT03Mipmaps {
+    fn ident(&self) -> &'static str {
+        "mipmaps"
+    }
+
+    fn sort(&self) -> u32 {
+        30
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on DXT textures without a full mipmap chain"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Example
+
+**Incorrect**
+A `512x512` texture with only the `512x512` mipmap
+
+**Correct**
+A `512x512` texture with mipmaps of `512x512`, `256x256`, and so on down to `4x4`
+
+### Explanation
+
+Arma 3 uses smaller mipmaps for textures that are far away. Without them, the full texture is sampled at every distance, which uses more memory bandwidth and causes shimmering. HEMTT generates the full chain when converting images."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Texture;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &Texture,
+        _data: &LintData,
+    ) -> Codes {
+        if !target.paa().format().is_dxt() {
+            return vec![];
+        }
+        let Some((width, height)) = target.dimensions() else {
+            return vec![];
+        };
+        let expected = expected_mipmaps(width, height);
+        let found = target.paa().maps().len();
+        if found >= expected {
+            return vec![];
+        }
+        vec![Arc::new(CodeTextureMipmaps::new(
+            target.path().to_string(),
+            found,
+            expected,
+            config.severity(),
+        ))]
+    }
+}
+
+/// The number of mipmaps when halving until either side reaches 4
+const fn expected_mipmaps(mut width: u16, mut height: u16) -> usize {
+    let mut count = 1;
+    while width > 4 && height > 4 {
+        width /= 2;
+        height /= 2;
+        count += 1;
+    }
+    count
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeTextureMipmaps {
+    path: String,
+    found: usize,
+    expected: usize,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeTextureMipmaps {
+    fn ident(&self) -> &'static str {
+        "L-T03"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/textures.html#mipmaps")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Texture `{}` has {} of {} mipmaps",
+            self.path, self.found, self.expected
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("regenerate the texture with mipmaps".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeTextureMipmaps {
+    #[must_use]
+    pub fn new(path: String, found: usize, expected: usize, severity: Severity) -> Self {
+        Self {
+            path,
+            found,
+            expected,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{
+    PaXType, TextureSuffix,
+    analyze::{LintData, Texture},
+};
+
+crate::analyze::lint!(LintT04SuffixFormat);
+
+impl Lint<LintData> for LintT04SuffixFormat {
+    fn ident(&self) -> &'static str {
+        "suffix_format"
+    }
+
+    fn sort(&self) -> u32 {
+        40
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on textures stored in a format that can not hold what their suffix requires"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Example
+
+**Incorrect**
+`data\glass_ca.paa` stored as DXT1
+
+**Correct**
+`data\glass_ca.paa` stored as DXT5
+
+### Explanation
+
+DXT1 has at most one bit of alpha. Textures with the `_ca` suffix need a full alpha channel for transparency, and `_nohq` and `_smdi` textures store data in their alpha channel. Storing them as DXT1 loses that information."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Texture;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &Texture,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(suffix) = target.suffix() else {
+            return vec![];
+        };
+        if *target.paa().format() != PaXType::DXT1
+            || !matches!(
+                suffix,
+                TextureSuffix::ColorAlpha | TextureSuffix::NormalMap | TextureSuffix::Specular
+            )
+        {
+            return vec![];
+        }
+        vec![Arc::new(CodeTextureSuffixFormat::new(
+            target.path().to_string(),
+            suffix,
+            *target.paa().format(),
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeTextureSuffixFormat {
+    path: String,
+    suffix: TextureSuffix,
+    format: PaXType,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeTextureSuffixFormat {
+    fn ident(&self) -> &'static str {
+        "L-T04"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/textures.html#suffix_format")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Texture `{}` is {}, which does not suit `{}`",
+            self.path, self.format, self.suffix
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "`{}` textures need an alpha channel, use DXT5",
+            self.suffix
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeTextureSuffixFormat {
+    #[must_use]
+    pub fn new(path: String, suffix: TextureSuffix, format: PaXType, severity: Severity) -> Self {
+        Self {
+            path,
+            suffix,
+            format,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
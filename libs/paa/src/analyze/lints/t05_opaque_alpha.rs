@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{
+    PaXType, TextureSuffix,
+    analyze::{LintData, Texture},
+};
+
+crate::analyze::lint!(LintT05OpaqueAlpha);
+
+impl Lint<LintData> for LintT05OpaqueAlpha {
+    fn ident(&self) -> &'static str {
+        "opaque_alpha"
+    }
+
+    fn sort(&self) -> u32 {
+        50
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on DXT5 color textures with a fully opaque alpha channel"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Example
+
+**Incorrect**
+`data\body_co.paa` stored as DXT5, with every pixel fully opaque
+
+**Correct**
+`data\body_co.paa` stored as DXT1
+
+### Explanation
+
+DXT5 uses twice the memory of DXT1 to store an alpha channel. When every pixel of a color texture is fully opaque, the alpha channel is wasted, and DXT1 gives the same result at half the size.
+
+Textures whose suffix stores data in the alpha channel, such as `_nohq` and `_smdi`, are not checked."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::help()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Texture;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &Texture,
+        _data: &LintData,
+    ) -> Codes {
+        if *target.paa().format() != PaXType::DXT5 {
+            return vec![];
+        }
+        let suffix = target.suffix();
+        if suffix.is_some_and(|suffix| !suffix.is_color()) {
+            return vec![];
+        }
+        if !target.alpha().is_some_and(|alpha| alpha.opaque) {
+            return vec![];
+        }
+        vec![Arc::new(CodeTextureOpaqueAlpha::new(
+            target.path().to_string(),
+            suffix,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeTextureOpaqueAlpha {
+    path: String,
+    suffix: Option<TextureSuffix>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeTextureOpaqueAlpha {
+    fn ident(&self) -> &'static str {
+        "L-T05"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/textures.html#opaque_alpha")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Texture `{}` is DXT5 but its alpha channel is fully opaque",
+            self.path
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(if self.suffix == Some(TextureSuffix::ColorAlpha) {
+            "rename the texture to `_co` and store it as DXT1".to_string()
+        } else {
+            "store the texture as DXT1".to_string()
+        })
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeTextureOpaqueAlpha {
+    #[must_use]
+    pub fn new(path: String, suffix: Option<TextureSuffix>, severity: Severity) -> Self {
+        Self {
+            path,
+            suffix,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{LintData, Texture};
+
+crate::analyze::lint!(LintT06Taggs);
+
+/// How far the stored average alpha may be from the decoded texture
+///
+/// DXT compression is lossy, and tools round differently
+const ALPHA_TOLERANCE: u8 = 8;
+
+impl Lint<LintData> for LintT06Taggs {
+    fn ident(&self) -> &'static str {
+        "taggs"
+    }
+
+    fn sort(&self) -> u32 {
+        60
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on textures with a missing or incorrect AVGCTAGG or MAXCTAGG"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Explanation
+
+The AVGCTAGG stores the average color of a texture, and the MAXCTAGG its maximum color. Arma 3 uses them for distant objects and to decide how a texture is blended. A texture without them, or with an average alpha that does not match the texture, can be drawn with the wrong transparency.
+
+Regenerating the texture with HEMTT or the Arma 3 Tools will write the correct TAGGs."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Texture;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &Texture,
+        _data: &LintData,
+    ) -> Codes {
+        let mut codes: Codes = Vec::new();
+        let mut report = |reason: String| {
+            codes.push(Arc::new(CodeTextureTaggs::new(
+                target.path().to_string(),
+                reason,
+                config.severity(),
+            )));
+        };
+        for (key, name) in [("CGVA", "AVGCTAGG"), ("CXAM", "MAXCTAGG")] {
+            match target.paa().taggs().get(key) {
+                None => report(format!("{name} is missing")),
+                Some(value) if value.len() != 4 => {
+                    report(format!("{name} is {} bytes, expected 4", value.len()));
+                }
+                Some(_) => {}
+            }
+        }
+        if let Some(average) = target.paa().taggs().get("CGVA").filter(|v| v.len() == 4)
+            && let Some(alpha) = target.alpha()
+            && average[3].abs_diff(alpha.average) > ALPHA_TOLERANCE
+        {
+            report(format!(
+                "AVGCTAGG has an average alpha of {}, the texture has {}",
+                average[3], alpha.average
+            ));
+        }
+        codes
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeTextureTaggs {
+    path: String,
+    reason: String,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeTextureTaggs {
+    fn ident(&self) -> &'static str {
+        "L-T06"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/textures.html#taggs")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("Texture `{}` has incorrect TAGGs", self.path)
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(self.reason.clone())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("regenerate the texture to write the correct TAGGs".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeTextureTaggs {
+    #[must_use]
+    pub fn new(path: String, reason: String, severity: Severity) -> Self {
+        Self {
+            path,
+            reason,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,112 @@
+use std::{path::Path, sync::OnceLock};
+
+use hemtt_common::config::{ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{lint::LintManager, lint_manager, reporting::Codes};
+
+use crate::{Paa, TextureSuffix};
+
+pub mod lints {
+    automod::dir!(pub "src/analyze/lints");
+}
+
+lint_manager!(texture, vec![]);
+
+pub struct LintData {}
+
+/// A PAA to be linted, with the path it was read from
+pub struct Texture {
+    path: String,
+    paa: Paa,
+    alpha: OnceLock<Option<Alpha>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Statistics of the alpha channel of the largest mipmap
+pub struct Alpha {
+    /// The average alpha value
+    pub average: u8,
+    /// Is every pixel fully opaque
+    pub opaque: bool,
+}
+
+impl Texture {
+    #[must_use]
+    pub const fn new(path: String, paa: Paa) -> Self {
+        Self {
+            path,
+            paa,
+            alpha: OnceLock::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn paa(&self) -> &Paa {
+        &self.paa
+    }
+
+    #[must_use]
+    /// The texture suffix of the file name, such as `_co`
+    pub fn suffix(&self) -> Option<TextureSuffix> {
+        TextureSuffix::from_path(Path::new(&self.path))
+    }
+
+    #[must_use]
+    /// The dimensions of the largest mipmap
+    pub fn dimensions(&self) -> Option<(u16, u16)> {
+        self.paa
+            .maps()
+            .first()
+            .map(|(mipmap, _)| (mipmap.width(), mipmap.height()))
+    }
+
+    #[must_use]
+    /// The alpha channel of the largest mipmap, decoded once and shared between lints
+    ///
+    /// Only DXT textures are decoded
+    pub fn alpha(&self) -> Option<Alpha> {
+        *self.alpha.get_or_init(|| {
+            if !self.paa.format().is_dxt() {
+                return None;
+            }
+            let (mipmap, _) = self.paa.maps().first()?;
+            let image = mipmap.get_image().ok()?.to_rgba8();
+            let pixels = u64::from(image.width()) * u64::from(image.height());
+            if pixels == 0 {
+                return None;
+            }
+            let mut total = 0u64;
+            let mut opaque = true;
+            for pixel in image.pixels() {
+                total += u64::from(pixel.0[3]);
+                opaque &= pixel.0[3] == 255;
+            }
+            Some(Alpha {
+                average: u8::try_from(total / pixels).unwrap_or(u8::MAX),
+                opaque,
+            })
+        })
+    }
+}
+
+pub fn lint_one(texture: &Texture, project_config: Option<&ProjectConfig>) -> Codes {
+    let mut manager = LintManager::new(
+        project_config.map_or_else(Default::default, |project| {
+            project.lints().textures().clone()
+        }),
+        project_config.map_or_else(RuntimeArguments::default, |p| p.runtime().clone()),
+    );
+    if let Err(e) = manager.extend(
+        TEXTURE_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return e;
+    }
+    manager.run(&LintData {}, project_config, None, texture)
+}
@@ -2,11 +2,20 @@ mod headers;
 mod mipmap;
 mod paa;
 mod pax;
+mod quality;
+mod suffix;
+mod tagg;
 
 pub use self::headers::{Headers, TextureHeader};
 pub use self::mipmap::{COMPRESS_THRESHOLD, MipMap};
 pub use self::paa::Paa;
 pub use self::pax::PaXType;
+pub use self::quality::Quality;
+pub use self::suffix::TextureSuffix;
+pub use self::tagg::{AlphaFlag, Swizzle, SwizzleSource, Tagg, TaggColor, key_from_name};
+
+#[cfg(feature = "lints")]
+pub mod analyze;
 
 #[cfg(feature = "wasm")]
 mod wasm;
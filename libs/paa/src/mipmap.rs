@@ -3,6 +3,8 @@ use std::io::{Read, Seek};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::PaXType;
+#[cfg(feature = "generate")]
+use crate::Quality;
 
 pub const COMPRESS_THRESHOLD: u16 = 64;
 
@@ -65,18 +67,22 @@ impl MipMap {
     /// [`std::io::Error`] if the image cannot be converted to the specified format
     /// [`std::io::Error`] if the width or height exceed u16 limits
     /// [`std::io::Error`] if the width or height are not powers of two
-    ///
-    /// # Panics
-    /// If the `PaXType` is not a valid DXT format
     #[cfg(feature = "generate")]
     pub fn from_rgba_image(
         image: &image::RgbaImage,
         format: PaXType,
+        quality: Quality,
     ) -> Result<Self, std::io::Error> {
         use image::EncodableLayout;
         let (width, height) = image.dimensions();
         let mut data = vec![0u8; format.image_size(width as usize, height as usize)];
-        format.compress(image.as_bytes(), width as usize, height as usize, &mut data);
+        format.compress(
+            image.as_bytes(),
+            width as usize,
+            height as usize,
+            &mut data,
+            quality,
+        )?;
         let dxt_compress = format.is_dxt()
             && (width >= u32::from(COMPRESS_THRESHOLD) || height >= u32::from(COMPRESS_THRESHOLD));
         let stored_width = u16::try_from(width).map_err(|_| {
@@ -149,12 +155,11 @@ impl MipMap {
         format!("{:?}", self.format)
     }
 
-    #[must_use]
     /// Get the image from the `MipMap`
     ///
-    /// # Panics
-    /// Panics if the `MipMap` is invalid
-    pub fn get_image(&self) -> image::DynamicImage {
+    /// # Errors
+    /// [`std::io::Error`] if the `MipMap` data can not be decompressed
+    pub fn get_image(&self) -> Result<image::DynamicImage, std::io::Error> {
         #[derive(Debug, PartialEq, Eq)]
         pub enum Compression {
             None,
@@ -197,7 +202,7 @@ impl MipMap {
                         usize::from(actual_width),
                         usize::from(self.height),
                         &mut out_buffer,
-                    );
+                    )?;
                 }
                 Err(e) => {
                     eprintln!(
@@ -209,43 +214,51 @@ impl MipMap {
                         usize::from(actual_width),
                         usize::from(self.height),
                         &mut out_buffer,
-                    );
+                    )?;
                 }
             }
         } else if decompression == Compression::Lz77 {
-            hemtt_lzo::lz77::decompress(data, &mut buffer).expect("Failed to decompress LZ77 data");
+            hemtt_lzo::lz77::decompress(data, &mut buffer).map_err(|e| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Failed to decompress LZ77 data: {e}"),
+                )
+            })?;
             self.format.decompress(
                 &buffer,
                 usize::from(actual_width),
                 usize::from(self.height),
                 &mut out_buffer,
-            );
+            )?;
         } else {
             self.format.decompress(
                 data,
                 usize::from(actual_width),
                 usize::from(self.height),
                 &mut out_buffer,
-            );
+            )?;
         }
-        image::DynamicImage::ImageRgba8(
-            image::RgbaImage::from_raw(u32::from(actual_width), u32::from(self.height), out_buffer)
-                .expect("paa should contain valid image data"),
-        )
+        image::RgbaImage::from_raw(u32::from(actual_width), u32::from(self.height), out_buffer)
+            .map(image::DynamicImage::ImageRgba8)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "paa should contain valid image data",
+                )
+            })
     }
 
     #[cfg(feature = "json")]
-    #[must_use]
     /// Returns the image as a base64 encoded string
     ///
-    /// # Panics
-    /// Panics if the image cannot be encoded
-    pub fn json(&self) -> String {
+    /// # Errors
+    /// [`String`] if the image cannot be decoded or encoded
+    pub fn json(&self) -> Result<String, String> {
         use base64::Engine as _;
-        let img = self.get_image();
+        let img = self.get_image().map_err(|e| e.to_string())?;
         let mut buffer = std::io::Cursor::new(Vec::new());
         img.write_to(&mut buffer, image::ImageFormat::Png)
-            .expect("Failed to write PNG");
-        base64::prelude::BASE64_STANDARD.encode(buffer.get_ref())
+            .map_err(|e| e.to_string())?;
+        Ok(base64::prelude::BASE64_STANDARD.encode(buffer.get_ref()))
     }
 }
@@ -8,7 +8,9 @@ use std::{
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use indexmap::IndexMap;
 
-use crate::{MipMap, PaXType};
+#[cfg(feature = "generate")]
+use crate::{AlphaFlag, Quality, TaggColor, TextureSuffix};
+use crate::{MipMap, PaXType, Tagg};
 
 #[derive(Debug)]
 pub struct Paa {
@@ -45,6 +47,38 @@ impl Paa {
         &mut self.taggs
     }
 
+    #[must_use]
+    /// Get a typed TAGG by its stored key, such as `CGVA`
+    ///
+    /// Returns `None` if the TAGG is not present,
+    /// or `Some(Err)` if its data is invalid
+    pub fn tagg(&self, key: &str) -> Option<Result<Tagg, Error>> {
+        self.taggs.get(key).map(|data| Tagg::read(key, data))
+    }
+
+    /// Get all TAGGs of the Paa, typed
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the data of a TAGG is invalid
+    pub fn typed_taggs(&self) -> Result<Vec<Tagg>, Error> {
+        self.taggs
+            .iter()
+            .map(|(key, data)| Tagg::read(key, data))
+            .collect()
+    }
+
+    /// Set a TAGG, replacing any existing TAGG with the same key
+    ///
+    /// `OFFSTAGG` is regenerated when writing, setting it has no effect on the output
+    pub fn set_tagg(&mut self, tagg: &Tagg) {
+        self.taggs.insert(tagg.key().to_string(), tagg.to_bytes());
+    }
+
+    /// Remove a TAGG by its stored key, returning true if it was present
+    pub fn remove_tagg(&mut self, key: &str) -> bool {
+        self.taggs.shift_remove(key).is_some()
+    }
+
     #[must_use]
     /// Get the maps of the Paa
     pub const fn maps(&self) -> &Vec<(MipMap, u64)> {
@@ -153,83 +187,114 @@ impl Paa {
     pub fn from_dynamic(
         image: &image::DynamicImage,
         format: PaXType,
+        quality: Quality,
+    ) -> Result<Self, std::io::Error> {
+        Self::generate(&image.to_rgba8(), format, None, quality)
+    }
+
+    /// Create a Paa from a `DynamicImage`, using the conventions of a texture suffix
+    ///
+    /// The format is chosen from the suffix and the image's alpha channel,
+    /// mipmaps and TAGGs are generated for the suffix
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the image cannot be converted
+    #[cfg(feature = "generate")]
+    pub fn from_dynamic_with_suffix(
+        image: &image::DynamicImage,
+        suffix: TextureSuffix,
+        quality: Quality,
     ) -> Result<Self, std::io::Error> {
         let rgba_image = image.to_rgba8();
-        let mipmap = MipMap::from_rgba_image(&rgba_image, format)?;
+        let format = suffix.format(&rgba_image);
+        Self::generate(&rgba_image, format, Some(suffix), quality)
+    }
+
+    #[cfg(feature = "generate")]
+    /// Generate a Paa, compressing the mipmaps in parallel
+    fn generate(
+        rgba_image: &image::RgbaImage,
+        format: PaXType,
+        suffix: Option<TextureSuffix>,
+        quality: Quality,
+    ) -> Result<Self, std::io::Error> {
+        use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
         let mut paa = Self::new(format);
-        paa.maps.push((mipmap, 0));
-        // Generate tags
-        // - Average
-        let mut has_transparency = false;
-        {
-            let avg_color = rgba_image
-                .pixels()
-                .map(|p| {
-                    [
-                        u32::from(p.0[0]),
-                        u32::from(p.0[1]),
-                        u32::from(p.0[2]),
-                        u32::from(p.0[3]),
-                    ]
-                })
-                .fold([0, 0, 0, 0], |mut acc, p| {
-                    acc[0] += p[0];
-                    acc[1] += p[1];
-                    acc[2] += p[2];
-                    acc[3] += p[3];
-                    acc
-                });
-            let avg_color = [
-                (avg_color[0] / (rgba_image.width() * rgba_image.height())) as u8,
-                (avg_color[1] / (rgba_image.width() * rgba_image.height())) as u8,
-                (avg_color[2] / (rgba_image.width() * rgba_image.height())) as u8,
-                (avg_color[3] / (rgba_image.width() * rgba_image.height())) as u8,
-            ];
-            if avg_color[3] < 255 {
-                has_transparency = true;
-            }
-            paa.taggs.insert(
-                "CGVA".to_string(),
-                vec![avg_color[0], avg_color[1], avg_color[2], avg_color[3]],
-            );
-        }
-        // - Max
-        {
-            let max_color: [u8; 4] = [255, 255, 255, 255]; // always this value for some reason
-            paa.taggs.insert(
-                "CXAM".to_string(),
-                vec![max_color[0], max_color[1], max_color[2], max_color[3]],
-            );
-        }
-        if has_transparency {
-            // - Alpha flag
-            paa.taggs.insert("GALF".to_string(), vec![1, 0, 0, 0]);
-        }
+        paa.generate_taggs(rgba_image, suffix);
+        let mut levels = vec![rgba_image.clone()];
         // Generate mipmaps for DXT formats
         if format.is_dxt() {
-            let mut width = rgba_image.width();
-            let mut height = rgba_image.height();
-            while width > 4 && height > 4 {
-                width = (width / 2).max(1);
-                height = (height / 2).max(1);
-                let mipmap = MipMap::from_rgba_image(
-                    &image::imageops::resize(
-                        &rgba_image,
-                        width,
-                        height,
-                        image::imageops::FilterType::Lanczos3,
-                    ),
-                    format,
-                )?;
-                paa.maps.push((mipmap, 0));
+            let mut previous = rgba_image.clone();
+            while previous.width() > 4 && previous.height() > 4 {
+                let width = (previous.width() / 2).max(1);
+                let height = (previous.height() / 2).max(1);
+                previous = suffix.map_or_else(
+                    || {
+                        image::imageops::resize(
+                            rgba_image,
+                            width,
+                            height,
+                            image::imageops::FilterType::Lanczos3,
+                        )
+                    },
+                    |suffix| suffix.mipmap(&previous, width, height),
+                );
+                levels.push(previous.clone());
             }
         }
+        paa.maps = levels
+            .par_iter()
+            .map(|level| MipMap::from_rgba_image(level, format, quality).map(|mipmap| (mipmap, 0)))
+            .collect::<Result<_, _>>()?;
         Ok(paa)
     }
 
+    #[cfg(feature = "generate")]
+    /// Generate the average color, max color and alpha flag TAGGs
+    ///
+    /// Without a suffix, the alpha flag is set if the average alpha is below 255.
+    /// With a suffix, it is only set for color textures that use their alpha channel.
+    fn generate_taggs(&mut self, rgba_image: &image::RgbaImage, suffix: Option<TextureSuffix>) {
+        // - Average
+        let pixels = rgba_image.width() * rgba_image.height();
+        let avg_color = rgba_image
+            .pixels()
+            .map(|p| {
+                [
+                    u32::from(p.0[0]),
+                    u32::from(p.0[1]),
+                    u32::from(p.0[2]),
+                    u32::from(p.0[3]),
+                ]
+            })
+            .fold([0, 0, 0, 0], |mut acc, p| {
+                acc[0] += p[0];
+                acc[1] += p[1];
+                acc[2] += p[2];
+                acc[3] += p[3];
+                acc
+            })
+            .map(|c| (c / pixels) as u8);
+        self.set_tagg(&Tagg::AverageColor(TaggColor::new(
+            avg_color[0],
+            avg_color[1],
+            avg_color[2],
+            avg_color[3],
+        )));
+        // - Max
+        self.fix_cxam_tagg();
+        // - Alpha flag
+        let has_transparency = suffix.map_or(avg_color[3] < 255, |suffix| {
+            suffix.is_color() && rgba_image.pixels().any(|p| p.0[3] < 255)
+        });
+        if has_transparency {
+            self.set_tagg(&Tagg::Flag(AlphaFlag::Interpolated));
+        }
+    }
+
     pub fn fix_cxam_tagg(&mut self) {
-        self.taggs
-            .insert("CXAM".to_string(), vec![255, 255, 255, 255]);
+        self.set_tagg(&Tagg::MaxColor(TaggColor::new(255, 255, 255, 255)));
     }
 
     /// Add a mipmap to this PAA
@@ -248,7 +313,11 @@ impl Paa {
     pub fn json(&self) -> Result<String, String> {
         serde_json::to_string(&PaaJson {
             format: self.format.to_string(),
-            maps: self.maps.iter().map(|(mipmap, _)| mipmap.json()).collect(),
+            maps: self
+                .maps
+                .iter()
+                .map(|(mipmap, _)| mipmap.json())
+                .collect::<Result<_, _>>()?,
         })
         .map_err(|e| e.to_string())
     }
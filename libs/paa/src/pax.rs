@@ -2,6 +2,8 @@ use std::{fmt::Display, io::Read};
 
 use texpresso::{COLOUR_WEIGHTS_PERCEPTUAL, Format, Params};
 
+use crate::Quality;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum PaXType {
     DXT1,
@@ -97,6 +99,12 @@ impl PaXType {
         )
     }
 
+    #[must_use]
+    /// Is the color stored premultiplied by alpha
+    pub const fn is_premultiplied(&self) -> bool {
+        matches!(self, Self::DXT2 | Self::DXT4)
+    }
+
     #[must_use]
     #[allow(clippy::missing_panics_doc)]
     pub fn image_size(&self, width: usize, height: usize) -> usize {
@@ -111,26 +119,54 @@ impl PaXType {
         }
     }
 
-    #[allow(clippy::missing_panics_doc)]
-    pub fn compress(&self, data: &[u8], width: usize, height: usize, output: &mut [u8]) {
+    /// Compress RGBA8 data into this format
+    ///
+    /// DXT2 and DXT4 premultiply the color by alpha before compressing.
+    /// `quality` chooses the DXT block fitting algorithm, other formats ignore it
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the data is too short for the dimensions
+    pub fn compress(
+        &self,
+        data: &[u8],
+        width: usize,
+        height: usize,
+        output: &mut [u8],
+        quality: Quality,
+    ) -> Result<(), std::io::Error> {
+        if data.len() < width * height * 4 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{width}x{height} image data is too short"),
+            ));
+        }
         match *self {
-            Self::DXT1 | Self::DXT3 | Self::DXT5 => {
-                let format: Format = (*self).try_into().expect("DXT formats are valid");
+            Self::DXT1 | Self::DXT2 | Self::DXT3 | Self::DXT4 | Self::DXT5 => {
+                let format: Format = (*self).try_into().map_err(|()| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("{self} can not be compressed"),
+                    )
+                })?;
+                let premultiplied;
+                let data = if self.is_premultiplied() {
+                    premultiplied = premultiply(data);
+                    &premultiplied
+                } else {
+                    data
+                };
                 format.compress(
                     data,
                     width,
                     height,
                     Params {
-                        algorithm: texpresso::Algorithm::IterativeClusterFit,
+                        algorithm: quality.algorithm(),
                         weights: COLOUR_WEIGHTS_PERCEPTUAL,
                         weigh_colour_by_alpha: true,
                     },
                     output,
                 );
             }
-            Self::DXT2 | Self::DXT4 => {
-                unimplemented!()
-            }
             Self::ARGBA5 => {
                 // convert from RGBA8 to ARGB1555
                 let max_pixels = std::cmp::min(
@@ -199,17 +235,46 @@ impl PaXType {
                 }
             }
         }
+        Ok(())
     }
 
-    #[allow(clippy::missing_panics_doc)]
-    pub fn decompress(&self, data: &[u8], width: usize, height: usize, output: &mut [u8]) {
+    /// Decompress data in this format into RGBA8
+    ///
+    /// DXT2 and DXT4 are converted back to straight alpha
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the data or output is too short for the dimensions
+    pub fn decompress(
+        &self,
+        data: &[u8],
+        width: usize,
+        height: usize,
+        output: &mut [u8],
+    ) -> Result<(), std::io::Error> {
+        if output.len() < width * height * 4 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("output is too short for a {width}x{height} image"),
+            ));
+        }
         match *self {
-            Self::DXT1 | Self::DXT3 | Self::DXT5 => {
-                let format: Format = (*self).try_into().expect("DXT formats are valid");
+            Self::DXT1 | Self::DXT2 | Self::DXT3 | Self::DXT4 | Self::DXT5 => {
+                let format: Format = (*self).try_into().map_err(|()| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("{self} can not be decompressed"),
+                    )
+                })?;
+                if data.len() < format.compressed_size(width, height) {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("{self} data is too short for a {width}x{height} image"),
+                    ));
+                }
                 format.decompress(data, width, height, output);
-            }
-            Self::DXT2 | Self::DXT4 => {
-                unimplemented!()
+                if self.is_premultiplied() {
+                    unpremultiply(output);
+                }
             }
             #[allow(clippy::cast_possible_truncation)]
             Self::ARGBA5 => {
@@ -265,6 +330,31 @@ impl PaXType {
                 }
             }
         }
+        Ok(())
+    }
+}
+
+/// Multiply the color of RGBA8 data by its alpha
+fn premultiply(data: &[u8]) -> Vec<u8> {
+    let mut output = data.to_vec();
+    for pixel in output.chunks_exact_mut(4) {
+        let alpha = u16::from(pixel[3]);
+        for channel in &mut pixel[..3] {
+            *channel = u8::try_from((u16::from(*channel) * alpha + 127) / 255).unwrap_or(u8::MAX);
+        }
+    }
+    output
+}
+
+/// Divide the color of premultiplied RGBA8 data by its alpha
+fn unpremultiply(data: &mut [u8]) {
+    for pixel in data.chunks_exact_mut(4) {
+        let alpha = u16::from(pixel[3]);
+        for channel in &mut pixel[..3] {
+            *channel = (u16::from(*channel) * 255 + alpha / 2)
+                .checked_div(alpha)
+                .map_or(0, |value| u8::try_from(value).unwrap_or(u8::MAX));
+        }
     }
 }
 
@@ -273,20 +363,21 @@ impl TryFrom<PaXType> for Format {
     fn try_from(pax: PaXType) -> Result<Self, Self::Error> {
         match pax {
             PaXType::DXT1 => Ok(Self::Bc1),
-            PaXType::DXT3 => Ok(Self::Bc2),
-            PaXType::DXT5 => Ok(Self::Bc3),
+            PaXType::DXT2 | PaXType::DXT3 => Ok(Self::Bc2),
+            PaXType::DXT4 | PaXType::DXT5 => Ok(Self::Bc3),
             _ => Err(()),
         }
     }
 }
 
-impl From<Format> for PaXType {
-    fn from(pax: Format) -> Self {
-        match pax {
-            Format::Bc1 => Self::DXT1,
-            Format::Bc2 => Self::DXT3,
-            Format::Bc3 => Self::DXT5,
-            _ => unimplemented!(),
+impl TryFrom<Format> for PaXType {
+    type Error = ();
+    fn try_from(format: Format) -> Result<Self, Self::Error> {
+        match format {
+            Format::Bc1 => Ok(Self::DXT1),
+            Format::Bc2 => Ok(Self::DXT3),
+            Format::Bc3 => Ok(Self::DXT5),
+            _ => Err(()),
         }
     }
 }
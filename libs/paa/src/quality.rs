@@ -0,0 +1,57 @@
+use std::{fmt::Display, str::FromStr};
+
+use texpresso::Algorithm;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// DXT compression quality, trading speed for accuracy
+pub enum Quality {
+    /// Range fit, fast and low quality
+    Fast,
+    /// Cluster fit
+    Normal,
+    #[default]
+    /// Iterative cluster fit, slow and high quality
+    Best,
+}
+
+impl Quality {
+    #[must_use]
+    /// The texpresso algorithm for this quality
+    pub const fn algorithm(self) -> Algorithm {
+        match self {
+            Self::Fast => Algorithm::RangeFit,
+            Self::Normal => Algorithm::ClusterFit,
+            Self::Best => Algorithm::IterativeClusterFit,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Fast => "fast",
+            Self::Normal => "normal",
+            Self::Best => "best",
+        }
+    }
+}
+
+impl FromStr for Quality {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "fast" => Ok(Self::Fast),
+            "normal" => Ok(Self::Normal),
+            "best" => Ok(Self::Best),
+            _ => Err(format!(
+                "unknown quality `{s}`, expected `fast`, `normal` or `best`"
+            )),
+        }
+    }
+}
+
+impl Display for Quality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
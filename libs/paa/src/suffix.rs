@@ -0,0 +1,156 @@
+use std::{fmt::Display, path::Path};
+
+#[cfg(feature = "generate")]
+use crate::PaXType;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The purpose of a texture, from the suffix of its file name
+///
+/// `data\rifle_co.paa` is a color texture, `data\rifle_nohq.paa` is a normal map
+pub enum TextureSuffix {
+    /// `_co`, color
+    Color,
+    /// `_ca`, color with alpha
+    ColorAlpha,
+    /// `_nohq`, normal map
+    NormalMap,
+    /// `_smdi`, specular and diffuse inverse
+    Specular,
+    /// `_as`, ambient shadow
+    AmbientShadow,
+    /// `_mc`, macro
+    Macro,
+    /// `_dt`, detail
+    Detail,
+}
+
+impl TextureSuffix {
+    #[must_use]
+    /// Find the suffix of a file name, such as `rifle_co.png`
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let stem = path.file_stem()?.to_str()?.to_lowercase();
+        let (_, suffix) = stem.rsplit_once('_')?;
+        match suffix {
+            "co" => Some(Self::Color),
+            "ca" => Some(Self::ColorAlpha),
+            "nohq" => Some(Self::NormalMap),
+            "smdi" => Some(Self::Specular),
+            "as" => Some(Self::AmbientShadow),
+            "mc" => Some(Self::Macro),
+            "dt" => Some(Self::Detail),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    /// The suffix, without the leading `_`
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Color => "co",
+            Self::ColorAlpha => "ca",
+            Self::NormalMap => "nohq",
+            Self::Specular => "smdi",
+            Self::AmbientShadow => "as",
+            Self::Macro => "mc",
+            Self::Detail => "dt",
+        }
+    }
+
+    #[must_use]
+    /// Is the alpha channel used for transparency
+    ///
+    /// Normal and specular maps store data in the alpha channel instead
+    pub const fn is_color(&self) -> bool {
+        matches!(
+            self,
+            Self::Color | Self::ColorAlpha | Self::Macro | Self::Detail
+        )
+    }
+
+    #[cfg(feature = "generate")]
+    #[must_use]
+    /// The format to use for an image with this suffix
+    ///
+    /// Color textures use DXT1 unless the alpha channel is used, data textures always use DXT5
+    pub fn format(&self, image: &image::RgbaImage) -> PaXType {
+        if !self.is_color() && *self != Self::AmbientShadow {
+            return PaXType::DXT5;
+        }
+        if image.pixels().any(|p| p.0[3] < 255) {
+            PaXType::DXT5
+        } else {
+            PaXType::DXT1
+        }
+    }
+
+    #[cfg(feature = "generate")]
+    #[must_use]
+    /// Create the next mipmap level of an image
+    ///
+    /// - Normal maps are averaged, then each normal is renormalised
+    /// - Specular maps average each channel on its own, so constant channels stay exact
+    /// - Other textures are resized with Lanczos3
+    pub fn mipmap(&self, image: &image::RgbaImage, width: u32, height: u32) -> image::RgbaImage {
+        match self {
+            Self::NormalMap => {
+                let mut mipmap = box_filter(image, width, height);
+                for pixel in mipmap.pixels_mut() {
+                    renormalise(&mut pixel.0);
+                }
+                mipmap
+            }
+            Self::Specular => box_filter(image, width, height),
+            _ => {
+                image::imageops::resize(image, width, height, image::imageops::FilterType::Lanczos3)
+            }
+        }
+    }
+}
+
+impl Display for TextureSuffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "_{}", self.as_str())
+    }
+}
+
+#[cfg(feature = "generate")]
+#[allow(clippy::cast_possible_truncation)]
+/// Average each channel over the source pixels covered by each destination pixel
+fn box_filter(image: &image::RgbaImage, width: u32, height: u32) -> image::RgbaImage {
+    let scale_x = (image.width() / width).max(1);
+    let scale_y = (image.height() / height).max(1);
+    image::RgbaImage::from_fn(width, height, |x, y| {
+        let mut sum = [0u32; 4];
+        for sy in 0..scale_y {
+            for sx in 0..scale_x {
+                let source = image.get_pixel(
+                    (x * scale_x + sx).min(image.width() - 1),
+                    (y * scale_y + sy).min(image.height() - 1),
+                );
+                for (total, value) in sum.iter_mut().zip(source.0) {
+                    *total += u32::from(value);
+                }
+            }
+        }
+        let count = scale_x * scale_y;
+        image::Rgba(sum.map(|total| ((total + count / 2) / count) as u8))
+    })
+}
+
+#[cfg(feature = "generate")]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::suboptimal_flops
+)]
+/// Renormalise the normal stored in the RGB channels of a pixel
+fn renormalise(pixel: &mut [u8; 4]) {
+    let vector = [pixel[0], pixel[1], pixel[2]].map(|c| f32::from(c) / 127.5 - 1.0);
+    let length = vector.iter().map(|c| c * c).sum::<f32>().sqrt();
+    if length <= f32::EPSILON {
+        return;
+    }
+    for (channel, value) in pixel.iter_mut().zip(vector) {
+        *channel = ((value / length + 1.0) * 127.5).round().clamp(0.0, 255.0) as u8;
+    }
+}
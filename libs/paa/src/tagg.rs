@@ -0,0 +1,407 @@
+use std::{fmt::Display, io::Error};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A TAGG, the tagged metadata at the start of a PAA
+///
+/// TAGG names are stored reversed, `AVGCTAGG` is stored as `GGATCGVA`.
+/// The key of a TAGG is the stored name without `GGAT`, such as `CGVA`.
+pub enum Tagg {
+    /// `AVGCTAGG`, the average color of the texture
+    AverageColor(TaggColor),
+    /// `MAXCTAGG`, the maximum color of the texture
+    MaxColor(TaggColor),
+    /// `FLAGTAGG`, how the alpha channel is used
+    Flag(AlphaFlag),
+    /// `SWIZTAGG`, where each channel is read from
+    Swizzle(Swizzle),
+    /// `PROCTAGG`, a procedural texture expression
+    Procedural(String),
+    /// `OFFSTAGG`, the offset of each mipmap, generated when writing
+    Offsets(Vec<u32>),
+    /// Any other TAGG, kept as raw data
+    Unknown {
+        /// The stored key, such as `CGVA`
+        key: String,
+        /// The raw data
+        data: Vec<u8>,
+    },
+}
+
+impl Tagg {
+    /// Read a TAGG from its stored key and data
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the data does not match the TAGG
+    pub fn read(key: &str, data: &[u8]) -> Result<Self, Error> {
+        Ok(match key {
+            "CGVA" => Self::AverageColor(TaggColor::read(key, data)?),
+            "CXAM" => Self::MaxColor(TaggColor::read(key, data)?),
+            "GALF" => Self::Flag(AlphaFlag::from_u32(read_u32(key, data)?)),
+            "ZIWS" => Self::Swizzle(Swizzle::read(data)?),
+            "CORP" => Self::Procedural(
+                String::from_utf8(data.to_vec())
+                    .map_err(|_| invalid("PROCTAGG is not valid UTF-8"))?,
+            ),
+            "SFFO" => Self::Offsets(
+                data.chunks_exact(4)
+                    .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                    .collect(),
+            ),
+            _ => Self::Unknown {
+                key: key.to_string(),
+                data: data.to_vec(),
+            },
+        })
+    }
+
+    /// Parse a TAGG from its name and a value, as shown by [`Display`]
+    ///
+    /// The name may be given with or without `TAGG`, such as `AVGC` or `avgctagg`
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the name is unknown, or the value is invalid
+    pub fn parse(name: &str, value: &str) -> Result<Self, Error> {
+        let key = key_from_name(name)?;
+        match key.as_str() {
+            "CGVA" => Ok(Self::AverageColor(TaggColor::parse(value)?)),
+            "CXAM" => Ok(Self::MaxColor(TaggColor::parse(value)?)),
+            "GALF" => Ok(Self::Flag(AlphaFlag::parse(value)?)),
+            "ZIWS" => Ok(Self::Swizzle(Swizzle::parse(value)?)),
+            "CORP" => Ok(Self::Procedural(value.to_string())),
+            "SFFO" => Err(invalid("OFFSTAGG is generated when the PAA is written")),
+            _ => Err(invalid(&format!("unknown TAGG `{name}`"))),
+        }
+    }
+
+    #[must_use]
+    /// The stored key, such as `CGVA`
+    pub fn key(&self) -> &str {
+        match self {
+            Self::AverageColor(_) => "CGVA",
+            Self::MaxColor(_) => "CXAM",
+            Self::Flag(_) => "GALF",
+            Self::Swizzle(_) => "ZIWS",
+            Self::Procedural(_) => "CORP",
+            Self::Offsets(_) => "SFFO",
+            Self::Unknown { key, .. } => key,
+        }
+    }
+
+    #[must_use]
+    /// The readable name, such as `AVGCTAGG`
+    pub fn name(&self) -> String {
+        format!("{}TAGG", self.key().chars().rev().collect::<String>())
+    }
+
+    #[must_use]
+    /// The data as stored in the PAA
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::AverageColor(color) | Self::MaxColor(color) => color.to_bytes().to_vec(),
+            Self::Flag(flag) => flag.as_u32().to_le_bytes().to_vec(),
+            Self::Swizzle(swizzle) => swizzle.to_bytes().to_vec(),
+            Self::Procedural(text) => text.as_bytes().to_vec(),
+            Self::Offsets(offsets) => offsets.iter().flat_map(|o| o.to_le_bytes()).collect(),
+            Self::Unknown { data, .. } => data.clone(),
+        }
+    }
+}
+
+impl Display for Tagg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AverageColor(color) | Self::MaxColor(color) => write!(f, "{color}"),
+            Self::Flag(flag) => write!(f, "{flag}"),
+            Self::Swizzle(swizzle) => write!(f, "{swizzle}"),
+            Self::Procedural(text) => write!(f, "{text}"),
+            Self::Offsets(offsets) => write!(
+                f,
+                "{}",
+                offsets
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            Self::Unknown { data, .. } => data.iter().try_for_each(|b| write!(f, "{b:02x}")),
+        }
+    }
+}
+
+/// The stored key of a TAGG name, `AVGC` or `AVGCTAGG` becomes `CGVA`
+///
+/// # Errors
+/// [`std::io::Error`] if the name is not four ASCII letters, with or without `TAGG`
+pub fn key_from_name(name: &str) -> Result<String, Error> {
+    let upper = name.to_uppercase();
+    let name = upper.strip_suffix("TAGG").unwrap_or(&upper);
+    if name.len() != 4 || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(invalid(&format!("invalid TAGG name `{name}`")));
+    }
+    Ok(name.chars().rev().collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A color stored in a TAGG, as BGRA bytes
+pub struct TaggColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl TaggColor {
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    fn read(key: &str, data: &[u8]) -> Result<Self, Error> {
+        let [b, g, r, a] = <[u8; 4]>::try_from(data)
+            .map_err(|_| invalid(&format!("{key} is {} bytes, expected 4", data.len())))?;
+        Ok(Self { r, g, b, a })
+    }
+
+    #[must_use]
+    pub const fn to_bytes(self) -> [u8; 4] {
+        [self.b, self.g, self.r, self.a]
+    }
+
+    fn parse(value: &str) -> Result<Self, Error> {
+        let hex = value.trim_start_matches('#');
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| {
+                hex.get(i..i + 2)
+                    .and_then(|b| u8::from_str_radix(b, 16).ok())
+            })
+            .collect::<Option<Vec<_>>>();
+        match bytes.as_deref() {
+            Some(&[r, g, b, a]) if hex.len() == 8 => Ok(Self { r, g, b, a }),
+            _ => Err(invalid(&format!(
+                "invalid color `{value}`, expected `#rrggbbaa`"
+            ))),
+        }
+    }
+}
+
+impl Display for TaggColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{:02x}{:02x}{:02x}{:02x}",
+            self.r, self.g, self.b, self.a
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How the alpha channel of a texture is used
+pub enum AlphaFlag {
+    /// `1`, the alpha channel is interpolated, for smooth transparency
+    Interpolated,
+    /// `2`, the alpha channel is not interpolated, for hard edged transparency
+    NotInterpolated,
+    /// Any other value
+    Other(u32),
+}
+
+impl AlphaFlag {
+    #[must_use]
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => Self::Interpolated,
+            2 => Self::NotInterpolated,
+            _ => Self::Other(value),
+        }
+    }
+
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
+        match self {
+            Self::Interpolated => 1,
+            Self::NotInterpolated => 2,
+            Self::Other(value) => value,
+        }
+    }
+
+    fn parse(value: &str) -> Result<Self, Error> {
+        match value {
+            "interpolated" => Ok(Self::Interpolated),
+            "not-interpolated" => Ok(Self::NotInterpolated),
+            _ => value.parse().map(Self::from_u32).map_err(|_| {
+                invalid(&format!(
+                    "invalid flag `{value}`, expected `interpolated`, `not-interpolated` or a number"
+                ))
+            }),
+        }
+    }
+}
+
+impl Display for AlphaFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Interpolated => write!(f, "interpolated"),
+            Self::NotInterpolated => write!(f, "not-interpolated"),
+            Self::Other(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Where the alpha, red, green and blue channels are read from
+pub struct Swizzle {
+    pub a: SwizzleSource,
+    pub r: SwizzleSource,
+    pub g: SwizzleSource,
+    pub b: SwizzleSource,
+}
+
+impl Swizzle {
+    #[must_use]
+    /// Each channel is read from itself
+    pub const fn identity() -> Self {
+        Self {
+            a: SwizzleSource::Alpha,
+            r: SwizzleSource::Red,
+            g: SwizzleSource::Green,
+            b: SwizzleSource::Blue,
+        }
+    }
+
+    fn read(data: &[u8]) -> Result<Self, Error> {
+        let [a, r, g, b] = <[u8; 4]>::try_from(data)
+            .map_err(|_| invalid(&format!("ZIWS is {} bytes, expected 4", data.len())))?;
+        Ok(Self {
+            a: SwizzleSource::from_u8(a)?,
+            r: SwizzleSource::from_u8(r)?,
+            g: SwizzleSource::from_u8(g)?,
+            b: SwizzleSource::from_u8(b)?,
+        })
+    }
+
+    #[must_use]
+    pub const fn to_bytes(self) -> [u8; 4] {
+        [
+            self.a.as_u8(),
+            self.r.as_u8(),
+            self.g.as_u8(),
+            self.b.as_u8(),
+        ]
+    }
+
+    fn parse(value: &str) -> Result<Self, Error> {
+        let sources = value
+            .split(',')
+            .map(|s| SwizzleSource::parse(s.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let [a, r, g, b] = <[SwizzleSource; 4]>::try_from(sources).map_err(|_| {
+            invalid(&format!(
+                "invalid swizzle `{value}`, expected four sources for A,R,G,B"
+            ))
+        })?;
+        Ok(Self { a, r, g, b })
+    }
+}
+
+impl Display for Swizzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{},{}", self.a, self.r, self.g, self.b)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The source of a swizzled channel
+pub enum SwizzleSource {
+    Alpha,
+    Red,
+    Green,
+    Blue,
+    InverseAlpha,
+    InverseRed,
+    InverseGreen,
+    InverseBlue,
+    /// Always fully on
+    One,
+}
+
+impl SwizzleSource {
+    fn from_u8(value: u8) -> Result<Self, Error> {
+        Ok(match value {
+            0 => Self::Alpha,
+            1 => Self::Red,
+            2 => Self::Green,
+            3 => Self::Blue,
+            4 => Self::InverseAlpha,
+            5 => Self::InverseRed,
+            6 => Self::InverseGreen,
+            7 => Self::InverseBlue,
+            8 => Self::One,
+            _ => return Err(invalid(&format!("invalid swizzle source {value}"))),
+        })
+    }
+
+    #[must_use]
+    pub const fn as_u8(self) -> u8 {
+        match self {
+            Self::Alpha => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Blue => 3,
+            Self::InverseAlpha => 4,
+            Self::InverseRed => 5,
+            Self::InverseGreen => 6,
+            Self::InverseBlue => 7,
+            Self::One => 8,
+        }
+    }
+
+    fn parse(value: &str) -> Result<Self, Error> {
+        Ok(match value.to_uppercase().as_str() {
+            "A" => Self::Alpha,
+            "R" => Self::Red,
+            "G" => Self::Green,
+            "B" => Self::Blue,
+            "1-A" => Self::InverseAlpha,
+            "1-R" => Self::InverseRed,
+            "1-G" => Self::InverseGreen,
+            "1-B" => Self::InverseBlue,
+            "1" => Self::One,
+            _ => {
+                return Err(invalid(&format!(
+                    "invalid swizzle source `{value}`, expected A, R, G, B, 1-A, 1-R, 1-G, 1-B or 1"
+                )));
+            }
+        })
+    }
+}
+
+impl Display for SwizzleSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Alpha => "A",
+                Self::Red => "R",
+                Self::Green => "G",
+                Self::Blue => "B",
+                Self::InverseAlpha => "1-A",
+                Self::InverseRed => "1-R",
+                Self::InverseGreen => "1-G",
+                Self::InverseBlue => "1-B",
+                Self::One => "1",
+            }
+        )
+    }
+}
+
+fn read_u32(key: &str, data: &[u8]) -> Result<u32, Error> {
+    <[u8; 4]>::try_from(data)
+        .map(u32::from_le_bytes)
+        .map_err(|_| invalid(&format!("{key} is {} bytes, expected 4", data.len())))
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(std::io::ErrorKind::InvalidData, message)
+}
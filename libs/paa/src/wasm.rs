@@ -18,6 +18,7 @@ impl FromPaaResult {
         paa.maps()[0]
             .0
             .get_image()
+            .expect("Failed to decode PAA")
             .write_to(&mut buffer, image::ImageFormat::Png)
             .expect("Failed to write PNG");
 
@@ -64,7 +65,8 @@ impl ToPaaResult {
                 crate::PaXType::DXT1
             }
         };
-        let paa = crate::Paa::from_dynamic(&img, format).expect("Failed to create PAA from image");
+        let paa = crate::Paa::from_dynamic(&img, format, crate::Quality::default())
+            .expect("Failed to create PAA from image");
         let mut buffer = Cursor::new(Vec::new());
         paa.write(&mut buffer).expect("Failed to write PAA");
         Self {
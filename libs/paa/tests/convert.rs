@@ -2,8 +2,12 @@
 #[cfg(feature = "generate")]
 fn baer_to_paa() {
     let baer_image = image::open("tests/baer.png").expect("Failed to open baer.png");
-    let paa_image = hemtt_paa::Paa::from_dynamic(&baer_image, hemtt_paa::PaXType::DXT5)
-        .expect("Failed to convert image to PAA");
+    let paa_image = hemtt_paa::Paa::from_dynamic(
+        &baer_image,
+        hemtt_paa::PaXType::DXT5,
+        hemtt_paa::Quality::Best,
+    )
+    .expect("Failed to convert image to PAA");
     assert_eq!(paa_image.format(), &hemtt_paa::PaXType::DXT5);
     assert_eq!(paa_image.maps().len(), 6);
     let mipmap = &paa_image.maps()[0].0;
@@ -29,7 +33,8 @@ fn baer_to_paa() {
 #[cfg(feature = "generate")]
 fn non_power_of_two() {
     let image = image::open("tests/mountain.png").expect("Failed to open mountain.png");
-    let paa_result = hemtt_paa::Paa::from_dynamic(&image, hemtt_paa::PaXType::ARGB8);
+    let paa_result =
+        hemtt_paa::Paa::from_dynamic(&image, hemtt_paa::PaXType::ARGB8, hemtt_paa::Quality::Best);
 
     let output_file_path = "tests/mountain_converted.paa";
     let output_file = fs_err::File::create(output_file_path);
@@ -38,3 +43,42 @@ fn non_power_of_two() {
         .write(&mut output_file.expect("Failed to create output PAA file"))
         .expect("Failed to write PAA file");
 }
+
+#[test]
+#[cfg(feature = "generate")]
+fn premultiplied() {
+    use std::io::Cursor;
+
+    // half transparent red, fully transparent, and fully opaque blue
+    let image =
+        image::DynamicImage::ImageRgba8(image::RgbaImage::from_fn(8, 8, |x, _| match x / 3 {
+            0 => image::Rgba([255, 0, 0, 128]),
+            1 => image::Rgba([0, 255, 0, 0]),
+            _ => image::Rgba([0, 0, 255, 255]),
+        }));
+    for format in [hemtt_paa::PaXType::DXT2, hemtt_paa::PaXType::DXT4] {
+        let paa = hemtt_paa::Paa::from_dynamic(&image, format, hemtt_paa::Quality::Fast)
+            .expect("Failed to convert image");
+        let mut buffer = Cursor::new(Vec::new());
+        paa.write(&mut buffer).expect("Failed to write PAA");
+        buffer.set_position(0);
+        let read_back = hemtt_paa::Paa::read(buffer).expect("Failed to read back PAA");
+        assert_eq!(read_back.format(), &format);
+        let decoded = read_back.maps()[0]
+            .0
+            .get_image()
+            .expect("Failed to decode PAA")
+            .to_rgba8();
+        // straight alpha is restored, within DXT precision
+        let red = decoded.get_pixel(0, 0).0;
+        assert!(
+            red[0] > 220 && red[1] < 16 && red[2] < 16,
+            "{format}: {red:?}"
+        );
+        assert!(red[3].abs_diff(128) <= 24, "{format}: {red:?}");
+        let blue = decoded.get_pixel(7, 0).0;
+        assert!(blue[2] > 240 && blue[3] == 255, "{format}: {blue:?}");
+        // fully transparent pixels have no color left to restore
+        assert_eq!(decoded.get_pixel(4, 0).0[3], 0, "{format}");
+    }
+}
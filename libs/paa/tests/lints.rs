@@ -0,0 +1,52 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_common::config::ProjectConfig;
+use hemtt_paa::{
+    Paa,
+    analyze::{Texture, lint_one},
+};
+use hemtt_workspace::reporting::WorkspaceFiles;
+
+const ROOT: &str = "tests/lints/";
+
+macro_rules! lint {
+    ($dir:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<texture_ $dir>]() {
+                insta::assert_snapshot!(lint(stringify!($dir)));
+            }
+        }
+    };
+}
+
+lint!(t01_dimensions);
+lint!(t02_max_size);
+lint!(t03_mipmaps);
+lint!(t04_suffix_format_ca);
+lint!(t05_opaque_alpha_co);
+lint!(t06_taggs);
+
+fn lint(file: &str) -> String {
+    let path = format!("{file}.paa");
+    let paa = Paa::read(fs_err::File::open(format!("{ROOT}{path}")).unwrap()).unwrap();
+    let config =
+        ProjectConfig::from_file(&std::path::PathBuf::from(ROOT).join("project_tests.toml"))
+            .unwrap();
+    let workspace_files = WorkspaceFiles::new();
+    let mut codes = lint_one(&Texture::new(path, paa), Some(&config));
+
+    codes.retain(|e| {
+        e.ident().starts_with(&format!(
+            "L-{}",
+            file.split_once('_').unwrap().0.to_uppercase()
+        ))
+    });
+
+    codes
+        .iter()
+        .map(|e| e.diagnostic().unwrap().to_string(&workspace_files))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace('\r', "")
+}
@@ -0,0 +1,116 @@
+#![allow(clippy::unwrap_used)]
+
+use std::path::Path;
+
+use hemtt_paa::{Quality, TextureSuffix};
+
+#[test]
+fn from_path() {
+    assert_eq!(
+        TextureSuffix::from_path(Path::new("data/rifle_co.png")),
+        Some(TextureSuffix::Color)
+    );
+    assert_eq!(
+        TextureSuffix::from_path(Path::new("data/Rifle_NOHQ.tga")),
+        Some(TextureSuffix::NormalMap)
+    );
+    assert_eq!(
+        TextureSuffix::from_path(Path::new("data/rifle_smdi.paa")),
+        Some(TextureSuffix::Specular)
+    );
+    assert_eq!(TextureSuffix::from_path(Path::new("data/rifle.png")), None);
+    assert_eq!(
+        TextureSuffix::from_path(Path::new("data/rifle_x.png")),
+        None
+    );
+}
+
+#[test]
+#[cfg(feature = "generate")]
+fn format() {
+    let opaque = image::RgbaImage::from_pixel(16, 16, image::Rgba([10, 20, 30, 255]));
+    let mut alpha = opaque.clone();
+    alpha.put_pixel(3, 3, image::Rgba([10, 20, 30, 0]));
+
+    assert_eq!(
+        TextureSuffix::Color.format(&opaque),
+        hemtt_paa::PaXType::DXT1
+    );
+    assert_eq!(
+        TextureSuffix::Color.format(&alpha),
+        hemtt_paa::PaXType::DXT5
+    );
+    assert_eq!(
+        TextureSuffix::ColorAlpha.format(&opaque),
+        hemtt_paa::PaXType::DXT1
+    );
+    assert_eq!(
+        TextureSuffix::NormalMap.format(&opaque),
+        hemtt_paa::PaXType::DXT5
+    );
+    assert_eq!(
+        TextureSuffix::Specular.format(&opaque),
+        hemtt_paa::PaXType::DXT5
+    );
+}
+
+#[test]
+#[cfg(feature = "generate")]
+fn taggs() {
+    let mut alpha = image::RgbaImage::from_pixel(16, 16, image::Rgba([128, 128, 255, 255]));
+    alpha.put_pixel(3, 3, image::Rgba([128, 128, 255, 0]));
+    let alpha = image::DynamicImage::ImageRgba8(alpha);
+
+    let ca = hemtt_paa::Paa::from_dynamic_with_suffix(
+        &alpha,
+        TextureSuffix::ColorAlpha,
+        Quality::default(),
+    )
+    .unwrap();
+    assert_eq!(ca.format(), &hemtt_paa::PaXType::DXT5);
+    assert_eq!(ca.taggs().get("CXAM"), Some(&vec![255, 255, 255, 255]));
+    assert_eq!(ca.taggs().get("GALF"), Some(&vec![1, 0, 0, 0]));
+
+    // the alpha channel of a normal map is not transparency
+    let nohq = hemtt_paa::Paa::from_dynamic_with_suffix(
+        &alpha,
+        TextureSuffix::NormalMap,
+        Quality::default(),
+    )
+    .unwrap();
+    assert!(nohq.taggs().contains_key("CGVA"));
+    assert!(!nohq.taggs().contains_key("GALF"));
+}
+
+#[test]
+#[cfg(feature = "generate")]
+fn mipmaps() {
+    // a checkerboard of two normals, averaging them gives a vector shorter than 1
+    let normals = image::RgbaImage::from_fn(8, 8, |x, y| {
+        if (x + y) % 2 == 0 {
+            image::Rgba([255, 128, 128, 255])
+        } else {
+            image::Rgba([128, 255, 128, 255])
+        }
+    });
+    let mipmap = TextureSuffix::NormalMap.mipmap(&normals, 4, 4);
+    for pixel in mipmap.pixels() {
+        let length = pixel.0[..3]
+            .iter()
+            .map(|c| (f32::from(*c) / 127.5 - 1.0).powi(2))
+            .sum::<f32>()
+            .sqrt();
+        assert!((length - 1.0).abs() < 0.02, "length {length}");
+    }
+
+    // specular maps keep constant channels exact
+    let smdi = image::RgbaImage::from_fn(8, 8, |x, _| {
+        image::Rgba([255, u8::try_from(x * 30).unwrap(), 0, 255])
+    });
+    let mipmap = TextureSuffix::Specular.mipmap(&smdi, 4, 4);
+    for pixel in mipmap.pixels() {
+        assert_eq!(pixel.0[0], 255);
+        assert_eq!(pixel.0[2], 0);
+        assert_eq!(pixel.0[3], 255);
+    }
+}
@@ -0,0 +1,98 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_paa::{AlphaFlag, Paa, Swizzle, SwizzleSource, Tagg, TaggColor, key_from_name};
+
+#[test]
+fn read_existing() {
+    let paa = Paa::read(std::fs::File::open("tests/dxt5.paa").unwrap()).unwrap();
+    let taggs = paa.typed_taggs().unwrap();
+    assert!(taggs.iter().any(|t| matches!(t, Tagg::AverageColor(_))));
+    assert!(taggs.iter().any(|t| matches!(t, Tagg::Offsets(_))));
+    assert!(
+        taggs
+            .iter()
+            .any(|t| matches!(t, Tagg::Flag(AlphaFlag::Interpolated)))
+    );
+    assert_eq!(
+        paa.tagg("CXAM").unwrap().unwrap(),
+        Tagg::MaxColor(TaggColor::new(255, 255, 255, 255))
+    );
+}
+
+#[test]
+fn round_trip() {
+    for tagg in [
+        Tagg::AverageColor(TaggColor::new(10, 20, 30, 40)),
+        Tagg::MaxColor(TaggColor::new(255, 255, 255, 255)),
+        Tagg::Flag(AlphaFlag::NotInterpolated),
+        Tagg::Flag(AlphaFlag::Other(7)),
+        Tagg::Swizzle(Swizzle {
+            a: SwizzleSource::InverseAlpha,
+            r: SwizzleSource::Red,
+            g: SwizzleSource::One,
+            b: SwizzleSource::Blue,
+        }),
+        Tagg::Procedural("color(1,0,0,1)".to_string()),
+        Tagg::Offsets(vec![128, 4096]),
+        Tagg::Unknown {
+            key: "EZIS".to_string(),
+            data: vec![1, 2, 3],
+        },
+    ] {
+        assert_eq!(Tagg::read(tagg.key(), &tagg.to_bytes()).unwrap(), tagg);
+    }
+}
+
+#[test]
+fn color_is_bgra() {
+    let tagg = Tagg::AverageColor(TaggColor::new(1, 2, 3, 4));
+    assert_eq!(tagg.to_bytes(), vec![3, 2, 1, 4]);
+    assert_eq!(tagg.name(), "AVGCTAGG");
+    assert_eq!(tagg.to_string(), "#01020304");
+}
+
+#[test]
+fn parse() {
+    assert_eq!(key_from_name("avgc").unwrap(), "CGVA");
+    assert_eq!(key_from_name("SWIZTAGG").unwrap(), "ZIWS");
+    assert!(key_from_name("AVG").is_err());
+
+    assert_eq!(
+        Tagg::parse("avgc", "#80808080").unwrap(),
+        Tagg::AverageColor(TaggColor::new(128, 128, 128, 128))
+    );
+    assert_eq!(
+        Tagg::parse("FLAGTAGG", "not-interpolated").unwrap(),
+        Tagg::Flag(AlphaFlag::NotInterpolated)
+    );
+    let swizzle = Tagg::parse("swiz", "1-A,R,G,1").unwrap();
+    assert_eq!(swizzle.to_bytes(), vec![4, 1, 2, 8]);
+    assert_eq!(Tagg::parse("swiz", &swizzle.to_string()).unwrap(), swizzle);
+    assert_eq!(
+        Tagg::parse("swiz", "A,R,G,B").unwrap(),
+        Tagg::Swizzle(Swizzle::identity())
+    );
+
+    assert!(Tagg::parse("avgc", "#808080").is_err());
+    assert!(Tagg::parse("swiz", "A,R,G").is_err());
+    assert!(Tagg::parse("offs", "0").is_err());
+    assert!(Tagg::parse("abcd", "0").is_err());
+}
+
+#[test]
+fn set_and_remove() {
+    let mut paa = Paa::read(std::fs::File::open("tests/dxt1.paa").unwrap()).unwrap();
+    paa.set_tagg(&Tagg::Swizzle(Swizzle::identity()));
+    assert!(paa.remove_tagg("CXAM"));
+    assert!(!paa.remove_tagg("CXAM"));
+
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    paa.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+    let paa = Paa::read(buffer).unwrap();
+    assert_eq!(
+        paa.tagg("ZIWS").unwrap().unwrap(),
+        Tagg::Swizzle(Swizzle::identity())
+    );
+    assert!(paa.tagg("CXAM").is_none());
+}
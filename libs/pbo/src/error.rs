@@ -14,6 +14,12 @@ pub enum Error {
     #[error("File is too large for PBO format")]
     /// File is too large for PBO format
     FileTooLarge,
+    #[error("Failed to compress file: {0}")]
+    /// Failed to compress file
+    Compression(String),
+    #[error("Failed to decompress file: {0}")]
+    /// Failed to decompress file
+    Decompression(String),
     #[error("HEMTT does not support signing PBOs with no files")]
     /// HEMTT does not support signing PBOs with no files
     NoFiles,
@@ -42,6 +42,17 @@ impl<'a, I: Read> File<'a, I> {
     }
 }
 
+impl<I: Read> File<'_, I> {
+    /// Number of bytes of this file that have not been read from the input
+    pub(crate) const fn unread(&self) -> u32 {
+        if self.decompressed.is_some() {
+            0
+        } else {
+            self.size - self.read
+        }
+    }
+}
+
 impl<I: Read> Read for File<'_, I> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         // read up to the size of the file
@@ -28,6 +28,21 @@ impl Header {
         }
     }
 
+    #[must_use]
+    /// Create a new header for a file that will be compressed when written
+    ///
+    /// The packed size is only known once the file is written, until then
+    /// the size is the same as the original size
+    pub fn new_for_compressed_file(filename: String, size: u32) -> Self {
+        Self {
+            filename,
+            mime: Mime::Cprs,
+            original: size,
+            size,
+            ..Default::default()
+        }
+    }
+
     #[must_use]
     /// Create a new header for an property
     pub fn property() -> Self {
@@ -77,6 +92,17 @@ impl Header {
         self.size
     }
 
+    pub(crate) fn as_packed(&self, size: u32) -> Self {
+        Self {
+            filename: self.filename.clone(),
+            mime: Mime::Cprs,
+            original: self.original,
+            reserved: self.reserved,
+            timestamp: self.timestamp,
+            size,
+        }
+    }
+
     pub(crate) fn as_blank(&self) -> Self {
         Self {
             filename: self.filename.clone(),
@@ -1,4 +1,7 @@
-use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::{
+    collections::HashMap,
+    io::{Cursor, Read, Seek, SeekFrom, Write},
+};
 
 use byteorder::ReadBytesExt;
 use hemtt_common::io::{ReadExt, WriteExt};
@@ -17,7 +20,13 @@ pub struct ReadablePbo<I: Seek + Read> {
     properties: IndexMap<String, String>,
     vers_header: Option<Header>,
     headers: Vec<Header>,
+    /// Offset of each file's data, in the same order as `headers`
+    offsets: Vec<u64>,
+    /// Lowercase file name to index in `headers`
+    index: HashMap<String, usize>,
     checksum: Checksum,
+    /// Number of bytes after the checksum
+    trailing: u64,
     input: I,
     blob_start: u64,
 }
@@ -27,7 +36,21 @@ impl<I: Seek + Read> ReadablePbo<I> {
     ///
     /// # Errors
     /// if the file cannot be read
-    pub fn from(mut input: I) -> Result<Self, Error> {
+    pub fn from(input: I) -> Result<Self, Error> {
+        let pbo = Self::from_lenient(input)?;
+        if pbo.trailing > 0 {
+            return Err(Error::UnexpectedDataAfterChecksum);
+        }
+        Ok(pbo)
+    }
+
+    /// Read a PBO from a file, allowing data after the checksum
+    ///
+    /// The amount of unexpected data is available from [`Self::trailing_data`]
+    ///
+    /// # Errors
+    /// if the file cannot be read
+    pub fn from_lenient(mut input: I) -> Result<Self, Error> {
         let mut properties = IndexMap::new();
         let mut headers = Vec::new();
         let mut blob_start = 0;
@@ -54,30 +77,40 @@ impl<I: Seek + Read> ReadablePbo<I> {
             }
         }
 
-        for header in &headers {
-            input.seek(SeekFrom::Current(i64::from(header.size())))?;
+        let mut offsets = Vec::with_capacity(headers.len());
+        let mut index = HashMap::with_capacity(headers.len());
+        let mut offset = blob_start;
+        for (i, header) in headers.iter().enumerate() {
+            offsets.push(offset);
+            index.entry(Self::key(header.filename())).or_insert(i);
+            offset += u64::from(header.size());
         }
 
-        input.seek(SeekFrom::Current(1))?;
+        input.seek(SeekFrom::Start(offset + 1))?;
         let checksum = Checksum::read_pbo(&mut input)?.0;
-        if input.read_u8().is_ok() {
-            return Err(Error::UnexpectedDataAfterChecksum);
-        }
+        let end = input.stream_position()?;
+        let trailing = input.seek(SeekFrom::End(0))?.saturating_sub(end);
         Ok(Self {
             properties,
             vers_header,
             headers,
+            offsets,
+            index,
             checksum,
+            trailing,
             input,
             blob_start,
         })
     }
 
+    /// Normalize a file name for lookups in the index
+    fn key(name: &str) -> String {
+        name.replace('/', "\\").to_ascii_lowercase()
+    }
+
     /// Find a header by name
     pub fn header(&self, name: &str) -> Option<&Header> {
-        self.headers
-            .iter()
-            .find(|h| h.filename().eq_ignore_ascii_case(&name.replace('/', "\\")))
+        self.index.get(&Self::key(name)).map(|i| &self.headers[*i])
     }
 
     /// Get the PBO's properties
@@ -90,6 +123,11 @@ impl<I: Seek + Read> ReadablePbo<I> {
         &self.checksum
     }
 
+    /// Get the number of bytes found after the checksum
+    pub const fn trailing_data(&self) -> u64 {
+        self.trailing
+    }
+
     /// Get the PBO's headers
     pub fn files(&self) -> Vec<Header> {
         self.headers.clone()
@@ -111,18 +149,11 @@ impl<I: Seek + Read> ReadablePbo<I> {
     /// # Errors
     /// if the file cannot be read
     pub fn file(&'_ mut self, name: &str) -> Result<Option<File<'_, I>>, Error> {
-        self.input.seek(SeekFrom::Start(self.blob_start))?;
-        for header in &self.headers {
-            if header
-                .filename()
-                .eq_ignore_ascii_case(&name.replace('/', "\\"))
-            {
-                return Ok(Some(File::new(header, &mut self.input)));
-            }
-            self.input
-                .seek(SeekFrom::Current(i64::from(header.size())))?;
-        }
-        Ok(None)
+        let Some(&index) = self.index.get(&Self::key(name)) else {
+            return Ok(None);
+        };
+        self.input.seek(SeekFrom::Start(self.offsets[index]))?;
+        Ok(Some(File::new(&self.headers[index], &mut self.input)))
     }
 
     /// Read a file from the PBO, without decompressing it if it's compressed
@@ -130,20 +161,41 @@ impl<I: Seek + Read> ReadablePbo<I> {
     /// # Errors
     /// if the file cannot be read
     pub fn file_raw(&'_ mut self, name: &str) -> Result<Option<File<'_, I>>, Error> {
-        self.input.seek(SeekFrom::Start(self.blob_start))?;
-        for header in &self.headers {
-            if header
-                .filename()
-                .eq_ignore_ascii_case(&name.replace('/', "\\"))
-            {
-                // Lie that the file is uncompressed, so it won't be decompressed when read
-                let header = header.as_blank();
-                return Ok(Some(File::new(&header, &mut self.input)));
-            }
-            self.input
-                .seek(SeekFrom::Current(i64::from(header.size())))?;
+        let Some(&index) = self.index.get(&Self::key(name)) else {
+            return Ok(None);
+        };
+        self.input.seek(SeekFrom::Start(self.offsets[index]))?;
+        // Lie that the file is uncompressed, so it won't be decompressed when read
+        let header = self.headers[index].as_blank();
+        Ok(Some(File::new(&header, &mut self.input)))
+    }
+
+    /// Read a file from the PBO into memory,
+    /// checking that compressed files decompress cleanly
+    ///
+    /// # Errors
+    /// [`Error::Decompression`] if the file fails to decompress
+    pub fn file_checked(&mut self, name: &str) -> Result<Option<Vec<u8>>, Error> {
+        let Some(&index) = self.index.get(&Self::key(name)) else {
+            return Ok(None);
+        };
+        let header = &self.headers[index];
+        self.input.seek(SeekFrom::Start(self.offsets[index]))?;
+        let mut raw = vec![0; header.size() as usize];
+        self.input.read_exact(&mut raw)?;
+        if !header.mime().is_compressed() {
+            return Ok(Some(raw));
+        }
+        let mut data = vec![0; header.original() as usize];
+        let read = hemtt_lzo::lz77::decompress(&raw, &mut data)
+            .map_err(|e| Error::Decompression(e.to_string()))?;
+        if read != raw.len() {
+            return Err(Error::Decompression(format!(
+                "{} bytes left after decompressing",
+                raw.len() - read
+            )));
         }
-        Ok(None)
+        Ok(Some(data))
     }
 
     /// Find the offset of a file
@@ -151,17 +203,38 @@ impl<I: Seek + Read> ReadablePbo<I> {
     /// # Errors
     /// if the file cannot be read
     pub fn file_offset(&self, name: &str) -> Result<Option<u64>, Error> {
-        let mut offset = self.blob_start;
+        Ok(self
+            .index
+            .get(&Self::key(name))
+            .map(|index| self.offsets[*index]))
+    }
+
+    /// Visit every file in the PBO in the order they are stored,
+    /// reading the PBO in a single forward pass
+    ///
+    /// Any part of a file not read by `f` is skipped
+    ///
+    /// # Errors
+    /// if the files cannot be read, or `f` returns an error
+    pub fn for_each_file<E, F>(&mut self, mut f: F) -> Result<(), E>
+    where
+        E: From<Error>,
+        F: FnMut(&Header, &mut File<'_, I>) -> Result<(), E>,
+    {
+        self.input
+            .seek(SeekFrom::Start(self.blob_start))
+            .map_err(Error::from)?;
         for header in &self.headers {
-            if header
-                .filename()
-                .eq_ignore_ascii_case(&name.replace('/', "\\"))
-            {
-                return Ok(Some(offset));
+            let mut file = File::new(header, &mut self.input);
+            f(header, &mut file)?;
+            let unread = file.unread();
+            if unread > 0 {
+                self.input
+                    .seek(SeekFrom::Current(i64::from(unread)))
+                    .map_err(Error::from)?;
             }
-            offset += header.size() as u64;
         }
-        Ok(None)
+        Ok(())
     }
 
     /// Check if the files are sorted correctly
@@ -294,3 +367,18 @@ impl<I: Seek + Read> ReadablePbo<I> {
         Ok(hasher.finalize().to_vec().into())
     }
 }
+
+#[cfg(feature = "mmap")]
+impl ReadablePbo<Cursor<memmap2::Mmap>> {
+    /// Read a PBO from a memory-mapped file
+    ///
+    /// The file must not be modified while the PBO is being read
+    ///
+    /// # Errors
+    /// if the file cannot be mapped or read
+    pub fn from_mmap(file: &std::fs::File) -> Result<Self, Error> {
+        // SAFETY: HEMTT does not modify PBOs that are being read
+        let mmap = unsafe { memmap2::Mmap::map(file)? };
+        Self::from(Cursor::new(mmap))
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     io::{BufWriter, Cursor, Read, Seek, SeekFrom, Write},
 };
 
@@ -13,7 +13,7 @@ use crate::{Checksum, WritePbo, error::Error, model::Header};
 /// A PBO file that can be written to
 pub struct WritablePbo<I: Seek + Read> {
     properties: IndexMap<String, String>,
-    files: HashMap<String, (I, Header)>,
+    files: BTreeMap<String, (I, Header)>,
 }
 
 impl<I: Seek + Read> WritablePbo<I> {
@@ -22,7 +22,7 @@ impl<I: Seek + Read> WritablePbo<I> {
     pub fn new() -> Self {
         Self {
             properties: IndexMap::new(),
-            files: HashMap::new(),
+            files: BTreeMap::new(),
         }
     }
 
@@ -46,6 +46,28 @@ impl<I: Seek + Read> WritablePbo<I> {
         ))
     }
 
+    /// Add a file to the PBO that will be compressed when written
+    ///
+    /// If compressing the file does not reduce its size, it is stored uncompressed
+    ///
+    /// # Errors
+    /// if the file cannot be read
+    pub fn add_file_compressed<S: Into<String>>(
+        &mut self,
+        name: S,
+        mut input: I,
+    ) -> Result<Option<(I, Header)>, Error> {
+        let name = name.into().replace('/', "\\");
+        let size = input.seek(SeekFrom::End(0))?;
+        if size > u32::MAX as u64 {
+            return Err(Error::FileTooLarge);
+        }
+        Ok(self.files.insert(
+            name.clone(),
+            (input, Header::new_for_compressed_file(name, size as u32)),
+        ))
+    }
+
     /// Add a file with a custom header
     ///
     /// # Errors
@@ -73,7 +95,7 @@ impl<I: Seek + Read> WritablePbo<I> {
         }
     }
 
-    /// Get a list of all files in the PBO
+    /// Get a list of all files in the PBO, ordered by name
     #[must_use]
     pub fn files(&self) -> Vec<Header> {
         let mut filenames = Vec::new();
@@ -84,6 +106,9 @@ impl<I: Seek + Read> WritablePbo<I> {
     }
 
     /// Get a list of all files in the PBO sorted by name
+    ///
+    /// Names that only differ by case are ordered by their original name,
+    /// so the order does not depend on the order files were added
     #[must_use]
     pub fn files_sorted(&self) -> Vec<Header> {
         let mut sorted = self.files();
@@ -91,6 +116,7 @@ impl<I: Seek + Read> WritablePbo<I> {
             a.filename()
                 .to_lowercase()
                 .cmp(&b.filename().to_lowercase())
+                .then_with(|| a.filename().cmp(b.filename()))
         });
         sorted
     }
@@ -154,7 +180,26 @@ impl<I: Seek + Read> WritablePbo<I> {
             headers.write_all(&[0])?;
         }
 
-        let files_sorted = self.files_sorted();
+        let mut packed = HashMap::new();
+        let mut files_sorted = Vec::new();
+        for header in self.files_sorted() {
+            if !header.mime().is_compressed() {
+                files_sorted.push(header);
+                continue;
+            }
+            let file = self
+                .file(header.filename())?
+                .expect("file with header should exist");
+            let mut buffer = Vec::with_capacity(header.original() as usize);
+            file.read_to_end(&mut buffer)?;
+            let compressed = compress(&buffer)?;
+            if compressed.len() < buffer.len() {
+                files_sorted.push(header.as_packed(compressed.len() as u32));
+                packed.insert(header.filename().to_string(), compressed);
+            } else {
+                files_sorted.push(header.as_blank());
+            }
+        }
 
         for header in &files_sorted {
             header.write_pbo(&mut headers)?;
@@ -170,11 +215,16 @@ impl<I: Seek + Read> WritablePbo<I> {
         hasher.update(headers.get_ref());
 
         for header in &files_sorted {
-            let file = self
-                .file(header.filename())?
-                .expect("file with header should exist");
-            let mut buffer = Vec::with_capacity(header.size() as usize);
-            file.read_to_end(&mut buffer)?;
+            let buffer = if let Some(compressed) = packed.remove(header.filename()) {
+                compressed
+            } else {
+                let file = self
+                    .file(header.filename())?
+                    .expect("file with header should exist");
+                let mut buffer = Vec::with_capacity(header.size() as usize);
+                file.read_to_end(&mut buffer)?;
+                buffer
+            };
 
             if header.size() > 1_000_000 {
                 // pay the paralellization cost for large files
@@ -202,3 +252,13 @@ impl<I: Seek + Read> WritablePbo<I> {
         Ok(checksum.to_vec().into())
     }
 }
+
+/// Compress a file's data for storage in a PBO
+fn compress(data: &[u8]) -> Result<Vec<u8>, Error> {
+    // worst case is a flag byte for every 8 literal bytes, plus the checksum
+    let mut output = vec![0; data.len() + data.len() / 8 + 5];
+    let size = hemtt_lzo::lz77::compress(data, &mut output)
+        .map_err(|e| Error::Compression(e.to_string()))?;
+    output.truncate(size);
+    Ok(output)
+}
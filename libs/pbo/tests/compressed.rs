@@ -0,0 +1,77 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::{Cursor, Read};
+
+use hemtt_pbo::{Error, Mime, ReadablePbo, WritablePbo};
+
+#[test]
+fn compressed_round_trip() {
+    let config =
+        "class CfgPatches {\n    class test {\n        units[] = {};\n    };\n};\n".repeat(100);
+    let mut pbo = WritablePbo::new();
+    pbo.add_property("prefix", "z\\test\\addons\\main");
+    pbo.add_file_compressed("config.cpp", Cursor::new(config.as_bytes().to_vec()))
+        .unwrap();
+    pbo.add_file_compressed("tiny.txt", Cursor::new(b"abc".to_vec()))
+        .unwrap();
+    pbo.add_file("script.sqf", Cursor::new(b"hint 'hello';".to_vec()))
+        .unwrap();
+
+    let mut output = Cursor::new(Vec::new());
+    let checksum = pbo.write(&mut output, true).unwrap();
+    output.set_position(0);
+
+    let mut pbo = ReadablePbo::from(output).unwrap();
+    assert_eq!(pbo.checksum(), &checksum);
+    assert_eq!(pbo.gen_checksum().unwrap(), checksum);
+    assert!(pbo.is_sorted().is_ok());
+
+    let header = pbo.header("config.cpp").unwrap();
+    assert_eq!(header.mime(), &Mime::Cprs);
+    assert_eq!(header.original() as usize, config.len());
+    assert!((header.size() as usize) < config.len());
+
+    // not worth compressing, stored as is
+    let header = pbo.header("tiny.txt").unwrap();
+    assert_eq!(header.mime(), &Mime::Blank);
+    assert_eq!(header.size(), 3);
+
+    let mut data = String::new();
+    pbo.file("config.cpp")
+        .unwrap()
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert_eq!(data, config);
+    assert_eq!(
+        pbo.file_checked("config.cpp").unwrap().unwrap(),
+        config.as_bytes()
+    );
+
+    let mut data = String::new();
+    pbo.file("script.sqf")
+        .unwrap()
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert_eq!(data, "hint 'hello';");
+}
+
+#[test]
+fn trailing_data() {
+    let mut pbo = WritablePbo::new();
+    pbo.add_property("prefix", "z\\test\\addons\\main");
+    pbo.add_file("script.sqf", Cursor::new(b"hint 'hello';".to_vec()))
+        .unwrap();
+    let mut output = Cursor::new(Vec::new());
+    pbo.write(&mut output, true).unwrap();
+    output.get_mut().extend_from_slice(b"extra");
+
+    output.set_position(0);
+    assert!(matches!(
+        ReadablePbo::from(output.clone()),
+        Err(Error::UnexpectedDataAfterChecksum)
+    ));
+    let pbo = ReadablePbo::from_lenient(output).unwrap();
+    assert_eq!(pbo.trailing_data(), 5);
+}
@@ -0,0 +1,48 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+
+use hemtt_pbo::{ReadablePbo, WritablePbo};
+
+fn write(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut pbo = WritablePbo::new();
+    pbo.add_property("prefix", "z\\test\\addons\\main");
+    for (name, data) in files {
+        pbo.add_file(*name, Cursor::new(data.to_vec())).unwrap();
+    }
+    let mut output = Vec::new();
+    pbo.write(&mut output, true).unwrap();
+    output
+}
+
+#[test]
+fn order_independent() {
+    let files: [(&str, &[u8]); 4] = [
+        ("config.cpp", b"class CfgPatches {};"),
+        ("Data/Logo.paa", b"logo"),
+        ("data/logo.paa", b"other logo"),
+        ("functions/fnc_test.sqf", b"hint 'test';"),
+    ];
+    let expected = write(&files);
+    let mut reversed = files;
+    reversed.reverse();
+    assert_eq!(write(&reversed), expected);
+    let mut rotated = files;
+    rotated.rotate_left(2);
+    assert_eq!(write(&rotated), expected);
+
+    let pbo = ReadablePbo::from(Cursor::new(expected)).unwrap();
+    assert!(pbo.files().iter().all(|h| h.timestamp() == 0));
+    assert_eq!(
+        pbo.files()
+            .iter()
+            .map(|h| h.filename().to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "config.cpp",
+            "Data\\Logo.paa",
+            "data\\logo.paa",
+            "functions\\fnc_test.sqf"
+        ]
+    );
+}
@@ -0,0 +1,59 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{fs::File, io::Read};
+
+use hemtt_pbo::{Error, ReadablePbo};
+
+#[test]
+fn for_each_file_matches_file() {
+    let mut pbo = ReadablePbo::from(File::open("tests/ace_weather.pbo_cba6f72c").unwrap()).unwrap();
+    let mut streamed = Vec::new();
+    pbo.for_each_file(|header, file| {
+        // only read part of some files, the rest should be skipped
+        let mut data = Vec::new();
+        if header.filename().starts_with("functions") {
+            file.take(4).read_to_end(&mut data)?;
+        } else {
+            file.read_to_end(&mut data)?;
+        }
+        streamed.push((header.filename().to_string(), data));
+        Ok::<(), Error>(())
+    })
+    .unwrap();
+    assert_eq!(streamed.len(), pbo.files().len());
+
+    for (name, data) in streamed {
+        let mut expected = Vec::new();
+        pbo.file(&name)
+            .unwrap()
+            .unwrap()
+            .read_to_end(&mut expected)
+            .unwrap();
+        if name.starts_with("functions") {
+            expected.truncate(4);
+        }
+        assert_eq!(data, expected, "{name}");
+    }
+}
+
+#[test]
+fn lookup_is_case_insensitive() {
+    let pbo = ReadablePbo::from(File::open("tests/ace_weather.pbo_cba6f72c").unwrap()).unwrap();
+    let header = pbo.header("FUNCTIONS/script_component.hpp").unwrap();
+    assert_eq!(header.filename(), "functions\\script_component.hpp");
+    assert_eq!(
+        pbo.file_offset("functions/SCRIPT_COMPONENT.hpp").unwrap(),
+        pbo.file_offset("functions\\script_component.hpp").unwrap()
+    );
+    assert!(pbo.file_offset("not_real").unwrap().is_none());
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn mmap() {
+    let file = File::open("tests/ace_weather.pbo_cba6f72c").unwrap();
+    let mut mapped = ReadablePbo::from_mmap(&file).unwrap();
+    let mut pbo = ReadablePbo::from(File::open("tests/ace_weather.pbo_cba6f72c").unwrap()).unwrap();
+    assert_eq!(mapped.files().len(), pbo.files().len());
+    assert_eq!(mapped.gen_checksum().unwrap(), pbo.gen_checksum().unwrap());
+}
@@ -132,6 +132,17 @@ pub fn encrypt(data: &[u8], password: &str, kdf_params: KDFParams) -> Result<Vec
     })
 }
 
+/// Reads the KDF parameters of encrypted data, without decrypting it.
+pub fn kdf_params(data: &[u8]) -> Result<KDFParams, Error> {
+    let blob: EncryptedBlob = bincode::deserialize(data).map_err(|e| {
+        Error::Io(Box::new(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            e,
+        )))
+    })?;
+    Ok(blob.kdf_params)
+}
+
 /// Decrypts the data with the given password.
 pub fn decrypt(data: &[u8], password: &str) -> Result<Vec<u8>, Error> {
     let blob: EncryptedBlob = bincode::deserialize(data).map_err(|e| {
@@ -179,6 +190,10 @@ mod tests {
         let encrypted = encrypt(data, password, KDFParams::default()).expect("encryption failed");
         let decrypted = decrypt(&encrypted, password).expect("decryption failed");
         assert_eq!(data.to_vec(), decrypted);
+        assert_eq!(
+            kdf_params(&encrypted).expect("valid blob"),
+            KDFParams::default()
+        );
     }
 
     #[test]
@@ -45,6 +45,10 @@ pub enum Error {
         key: String,
     },
 
+    #[error("Invalid response from signer: {0}")]
+    /// A remote signer returned an error, or an invalid response
+    InvalidResponse(String),
+
     #[error("Unknown signature version {0}")]
     /// Unknown signature version
     UknownBISignVersion(u32),
@@ -22,12 +22,14 @@ mod encrypted;
 mod error;
 mod private;
 mod public;
+mod remote;
 mod signature;
 
 pub use encrypted::KDFParams;
 pub use error::Error;
 pub use private::{BIPrivateKey, HEMTTPrivateKey};
 pub use public::BIPublicKey;
+pub use remote::{SigningRequest, SigningResponse};
 pub use signature::BISign;
 
 /// Writes a [`BoxedUint`] to the given output.
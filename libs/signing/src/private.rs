@@ -10,7 +10,11 @@ use rsa::{
 use zeroize::Zeroize;
 
 use crate::{
-    encrypted::KDFParams, error::Error, generate_hashes, modpow, public::BIPublicKey,
+    encrypted::KDFParams,
+    error::Error,
+    generate_hashes, modpow,
+    public::BIPublicKey,
+    remote::{SigningRequest, SigningResponse},
     signature::BISign,
 };
 
@@ -192,6 +196,27 @@ impl BIPrivateKey {
         })
     }
 
+    /// Sign the hashes of a [`SigningRequest`], as a remote signer would
+    ///
+    /// # Errors
+    /// If the request is for a different key, or has invalid hashes
+    pub fn sign_request(&self, request: &SigningRequest) -> Result<SigningResponse, Error> {
+        if request.authority != self.authority {
+            return Err(Error::AuthorityMismatch {
+                sig: request.authority.clone(),
+                key: self.authority.clone(),
+            });
+        }
+        Ok(SigningResponse {
+            signatures: request
+                .hashes()?
+                .iter()
+                .map(|hash| modpow(hash, &self.d, &self.n).to_string_radix_vartime(16))
+                .collect(),
+            error: None,
+        })
+    }
+
     /// Write private key to output.
     ///
     /// # Errors
@@ -257,6 +282,16 @@ impl HEMTTPrivateKey {
         })
     }
 
+    /// Reads the KDF parameters of an encrypted private key, without decrypting it.
+    ///
+    /// # Errors
+    /// If the input fails to read.
+    pub fn read_kdf_params<I: Read>(input: &mut I) -> Result<KDFParams, Error> {
+        let mut data = Vec::with_capacity(2048);
+        input.read_to_end(&mut data)?;
+        crate::encrypted::kdf_params(&data)
+    }
+
     /// Write encrypted private key to output.
     ///
     /// # Errors
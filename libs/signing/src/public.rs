@@ -8,7 +8,7 @@ use sha1::Digest as _;
 
 use crate::{BISign, Error, generate_hashes};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// A public key
 pub struct BIPublicKey {
     pub(crate) authority: String,
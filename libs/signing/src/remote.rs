@@ -0,0 +1,99 @@
+use std::io::{Read, Seek};
+
+use hemtt_pbo::{BISignVersion, ReadablePbo};
+use rsa::BoxedUint;
+use serde::{Deserialize, Serialize};
+
+use crate::{BIPublicKey, BISign, Error, generate_hashes};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Hashes of a PBO, to be signed by a signer that holds the private key elsewhere
+pub struct SigningRequest {
+    /// The authority of the key that should sign
+    pub authority: String,
+    /// The length of the key, in bits
+    pub length: u32,
+    /// The three padded hashes of the PBO, as hex
+    pub hashes: Vec<String>,
+}
+
+impl SigningRequest {
+    /// Generate the hashes of a PBO for the given public key
+    ///
+    /// # Errors
+    /// If the PBO cannot be read
+    pub fn new<I: Seek + Read>(
+        pbo: &mut ReadablePbo<I>,
+        version: BISignVersion,
+        key: &BIPublicKey,
+    ) -> Result<Self, Error> {
+        let hashes: [BoxedUint; 3] = generate_hashes(pbo, version, key.length)?.into();
+        Ok(Self {
+            authority: key.authority.clone(),
+            length: key.length,
+            hashes: hashes
+                .iter()
+                .map(|h| h.to_string_radix_vartime(16))
+                .collect(),
+        })
+    }
+
+    /// The hashes of the request
+    ///
+    /// # Errors
+    /// If there are not exactly three valid hex hashes
+    pub fn hashes(&self) -> Result<[BoxedUint; 3], Error> {
+        parse(&self.hashes)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// The answer to a [`SigningRequest`]
+pub struct SigningResponse {
+    #[serde(default)]
+    /// The three signatures, as hex, in the same order as the hashes
+    pub signatures: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Why the request was not signed
+    pub error: Option<String>,
+}
+
+impl BISign {
+    /// Assemble a signature from the response of a remote signer
+    ///
+    /// # Errors
+    /// If the signer returned an error, or an invalid response
+    pub fn from_response(
+        version: BISignVersion,
+        key: &BIPublicKey,
+        response: &SigningResponse,
+    ) -> Result<Self, Error> {
+        if let Some(error) = &response.error {
+            return Err(Error::InvalidResponse(error.clone()));
+        }
+        let [sig1, sig2, sig3] = parse(&response.signatures)?;
+        Ok(Self {
+            version,
+            authority: key.authority.clone(),
+            length: key.length,
+            exponent: key.exponent.clone(),
+            n: key.n.clone(),
+            sig1,
+            sig2,
+            sig3,
+        })
+    }
+}
+
+fn parse(values: &[String]) -> Result<[BoxedUint; 3], Error> {
+    let values = values
+        .iter()
+        .map(|v| {
+            BoxedUint::from_str_radix_vartime(v, 16)
+                .map_err(|_| Error::InvalidResponse(format!("`{v}` is not valid hex")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    values.try_into().map_err(|values: Vec<_>| {
+        Error::InvalidResponse(format!("expected 3 values, got {}", values.len()))
+    })
+}
@@ -0,0 +1,53 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{fs::File, path::PathBuf};
+
+use hemtt_pbo::{BISignVersion, ReadablePbo};
+use hemtt_signing::{BIPrivateKey, BISign, SigningRequest, SigningResponse};
+
+#[test]
+fn round_trip() {
+    let file = PathBuf::from("tests/ace_ai_3.15.2.69");
+    let private =
+        BIPrivateKey::read(&mut File::open(file.join("test.biprivatekey")).unwrap()).unwrap();
+    let public = private.to_public_key();
+    let mut pbo = ReadablePbo::from(File::open(file.join("source.pbo")).unwrap()).unwrap();
+
+    let request = SigningRequest::new(&mut pbo, BISignVersion::V3, &public).unwrap();
+    assert_eq!(request.hashes.len(), 3);
+    let response = private.sign_request(&request).unwrap();
+    let signature = BISign::from_response(BISignVersion::V3, &public, &response).unwrap();
+    public.verify(&mut pbo, &signature).unwrap();
+
+    // The same as signing locally
+    let local = private.sign(&mut pbo, BISignVersion::V3).unwrap();
+    let mut remote_bytes = Vec::new();
+    signature.write(&mut remote_bytes).unwrap();
+    let mut local_bytes = Vec::new();
+    local.write(&mut local_bytes).unwrap();
+    assert_eq!(remote_bytes, local_bytes);
+}
+
+#[test]
+fn invalid_response() {
+    let file = PathBuf::from("tests/ace_ai_3.15.2.69");
+    let private =
+        BIPrivateKey::read(&mut File::open(file.join("test.biprivatekey")).unwrap()).unwrap();
+    let public = private.to_public_key();
+    let error = SigningResponse {
+        signatures: Vec::new(),
+        error: Some("denied".to_string()),
+    };
+    assert!(BISign::from_response(BISignVersion::V3, &public, &error).is_err());
+    let short = SigningResponse {
+        signatures: vec!["ff".to_string()],
+        error: None,
+    };
+    assert!(BISign::from_response(BISignVersion::V3, &public, &short).is_err());
+    let wrong = SigningRequest {
+        authority: "other".to_string(),
+        length: 1024,
+        hashes: Vec::new(),
+    };
+    assert!(private.sign_request(&wrong).is_err());
+}
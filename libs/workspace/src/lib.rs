@@ -1,11 +1,16 @@
 //! A workspace (directory) containing addons and / or missions
 
-use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use hemtt_common::{
     config::{PDriveOption, ProjectConfig},
     prefix::{FILES, Prefix},
 };
+use pbo::PboFS;
 use pdrive::PDrive;
 use tracing::trace;
 use vfs::{AltrootFS, MemoryFS, OverlayFS, PhysicalFS, VfsPath};
@@ -19,6 +24,7 @@ pub mod error;
 pub mod lint;
 pub mod missing;
 pub mod path;
+pub mod pbo;
 pub mod pdrive;
 pub mod position;
 pub mod reporting;
@@ -173,6 +179,18 @@ impl WorkspaceBuilder {
         self
     }
 
+    /// Add a read-only layer containing the PBOs of an `@mod` folder
+    ///
+    /// Files are placed under each PBO's prefix, allowing them to be included
+    ///
+    /// # Errors
+    /// [`Error::Io`] if the folder cannot be read
+    pub fn pbos(mut self, folder: &Path) -> Result<Self, Error> {
+        self.layers
+            .push((PboFS::from_mod(folder)?.into(), LayerType::Include));
+        Ok(self)
+    }
+
     #[must_use]
     /// Add a memory layer to the virtual filesystem
     pub fn memory(mut self) -> Self {
@@ -0,0 +1,232 @@
+//! Read-only virtual filesystem backed by PBOs
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{Cursor, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use hemtt_pbo::ReadablePbo;
+use tracing::{trace, warn};
+use vfs::{
+    FileSystem, SeekAndRead, SeekAndWrite, VfsFileType, VfsMetadata, VfsResult, error::VfsErrorKind,
+};
+
+use crate::Error;
+
+/// A read-only filesystem containing the files of one or more PBOs
+///
+/// Each PBO's files are placed under its `prefix` property,
+/// so `\x\cba\addons\main\script_macros_common.hpp` is available at
+/// `/x/cba/addons/main/script_macros_common.hpp`
+///
+/// Paths are case-insensitive, matching how Arma resolves them
+pub struct PboFS {
+    pbos: Vec<(PathBuf, Mutex<ReadablePbo<File>>)>,
+    /// Lowercase path => (index in `pbos`, name in the PBO, size)
+    files: HashMap<String, (usize, String, u64)>,
+    /// Lowercase path => lowercase name => name of the direct children
+    directories: HashMap<String, HashMap<String, String>>,
+}
+
+impl PboFS {
+    /// Create a filesystem from every PBO in an `@mod` folder
+    ///
+    /// PBOs are read from the `addons` folder if it exists, otherwise from the folder itself
+    ///
+    /// # Errors
+    /// [`Error::Io`] if the folder cannot be read
+    pub fn from_mod(folder: &Path) -> Result<Self, Error> {
+        let addons = folder.join("addons");
+        let folder = if addons.is_dir() {
+            addons
+        } else {
+            folder.to_path_buf()
+        };
+        let mut paths = Vec::new();
+        for entry in fs_err::read_dir(&folder)? {
+            let path = entry?.path();
+            if path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("pbo"))
+            {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        Self::from_pbos(paths)
+    }
+
+    /// Create a filesystem from a list of PBOs
+    ///
+    /// PBOs that cannot be read, or have no `prefix` property, are skipped
+    ///
+    /// # Errors
+    /// [`Error::Io`] if a PBO cannot be opened
+    pub fn from_pbos(paths: Vec<PathBuf>) -> Result<Self, Error> {
+        let mut fs = Self {
+            pbos: Vec::with_capacity(paths.len()),
+            files: HashMap::new(),
+            directories: HashMap::new(),
+        };
+        fs.directories.insert(String::new(), HashMap::new());
+        for path in paths {
+            let pbo = match ReadablePbo::from(File::open(&path)?) {
+                Ok(pbo) => pbo,
+                Err(e) => {
+                    warn!("Skipping unreadable PBO {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+            let Some(prefix) = pbo.properties().get("prefix") else {
+                warn!("Skipping PBO without a prefix {}", path.display());
+                continue;
+            };
+            trace!("mounting {} at {}", path.display(), prefix);
+            let prefix = prefix.trim_matches('\\').replace('\\', "/");
+            let index = fs.pbos.len();
+            for header in pbo.files() {
+                let full = format!("{prefix}/{}", header.filename().replace('\\', "/"));
+                let size = if header.mime().is_compressed() {
+                    header.original()
+                } else {
+                    header.size()
+                };
+                if fs.insert(&full) {
+                    fs.files.insert(
+                        format!("/{}", full.to_lowercase()),
+                        (index, header.filename().to_string(), u64::from(size)),
+                    );
+                }
+            }
+            fs.pbos.push((path, Mutex::new(pbo)));
+        }
+        Ok(fs)
+    }
+
+    /// Register a file and its parent directories, returns false if the file already exists
+    fn insert(&mut self, full: &str) -> bool {
+        let key = format!("/{}", full.to_lowercase());
+        if self.files.contains_key(&key) {
+            return false;
+        }
+        let mut parent = String::new();
+        for part in full.split('/').filter(|p| !p.is_empty()) {
+            let lower = part.to_lowercase();
+            self.directories
+                .entry(parent.clone())
+                .or_default()
+                .entry(lower.clone())
+                .or_insert_with(|| part.to_string());
+            parent = format!("{parent}/{lower}");
+        }
+        true
+    }
+
+    #[must_use]
+    /// The PBOs mounted in this filesystem
+    pub fn pbos(&self) -> Vec<&Path> {
+        self.pbos.iter().map(|(p, _)| p.as_path()).collect()
+    }
+}
+
+impl std::fmt::Debug for PboFS {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PboFS")
+            .field("pbos", &self.pbos())
+            .field("files", &self.files.len())
+            .field("directories", &self.directories.len())
+            .finish()
+    }
+}
+
+impl FileSystem for PboFS {
+    fn read_dir(&self, path: &str) -> VfsResult<Box<dyn Iterator<Item = String> + Send>> {
+        let key = path.to_lowercase();
+        if let Some(children) = self.directories.get(&key) {
+            return Ok(Box::new(
+                children.values().cloned().collect::<Vec<_>>().into_iter(),
+            ));
+        }
+        if self.files.contains_key(&key) {
+            return Err(VfsErrorKind::Other("Not a directory".into()).into());
+        }
+        Err(VfsErrorKind::FileNotFound.into())
+    }
+
+    fn create_dir(&self, _path: &str) -> VfsResult<()> {
+        Err(VfsErrorKind::NotSupported.into())
+    }
+
+    fn open_file(&self, path: &str) -> VfsResult<Box<dyn SeekAndRead + Send>> {
+        let Some((index, name, size)) = self.files.get(&path.to_lowercase()) else {
+            return Err(VfsErrorKind::FileNotFound.into());
+        };
+        let buffer = read_file(
+            &mut *self.pbos[*index]
+                .1
+                .lock()
+                .map_err(|_| VfsErrorKind::Other("PBO lock poisoned".into()))?,
+            name,
+            *size,
+        )?;
+        Ok(Box::new(Cursor::new(buffer)))
+    }
+
+    fn create_file(&self, _path: &str) -> VfsResult<Box<dyn SeekAndWrite + Send>> {
+        Err(VfsErrorKind::NotSupported.into())
+    }
+
+    fn append_file(&self, _path: &str) -> VfsResult<Box<dyn SeekAndWrite + Send>> {
+        Err(VfsErrorKind::NotSupported.into())
+    }
+
+    fn metadata(&self, path: &str) -> VfsResult<VfsMetadata> {
+        let key = path.to_lowercase();
+        if let Some((_, _, size)) = self.files.get(&key) {
+            return Ok(VfsMetadata {
+                file_type: VfsFileType::File,
+                len: *size,
+                created: None,
+                modified: None,
+                accessed: None,
+            });
+        }
+        if self.directories.contains_key(&key) {
+            return Ok(VfsMetadata {
+                file_type: VfsFileType::Directory,
+                len: 0,
+                created: None,
+                modified: None,
+                accessed: None,
+            });
+        }
+        Err(VfsErrorKind::FileNotFound.into())
+    }
+
+    fn exists(&self, path: &str) -> VfsResult<bool> {
+        let key = path.to_lowercase();
+        Ok(self.files.contains_key(&key) || self.directories.contains_key(&key))
+    }
+
+    fn remove_file(&self, _path: &str) -> VfsResult<()> {
+        Err(VfsErrorKind::NotSupported.into())
+    }
+
+    fn remove_dir(&self, _path: &str) -> VfsResult<()> {
+        Err(VfsErrorKind::NotSupported.into())
+    }
+}
+
+fn read_file(pbo: &mut ReadablePbo<File>, name: &str, size: u64) -> VfsResult<Vec<u8>> {
+    let mut file = pbo
+        .file(name)
+        .map_err(|e| VfsErrorKind::Other(e.to_string()))?
+        .ok_or(VfsErrorKind::FileNotFound)?;
+    let mut buffer = Vec::with_capacity(usize::try_from(size).unwrap_or_default());
+    file.read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
@@ -0,0 +1,70 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+
+use hemtt_pbo::WritablePbo;
+use hemtt_workspace::{LayerType, Workspace};
+
+const ROOT: &str = "tests/config/";
+
+#[test]
+fn mounted_pbo() {
+    let folder = std::env::temp_dir().join("hemtt_workspace_mounted_pbo");
+    let addons = folder.join("@test").join("addons");
+    fs_err::create_dir_all(&addons).unwrap();
+
+    let mut pbo = WritablePbo::new();
+    pbo.add_property("prefix", "z\\test\\addons\\main");
+    pbo.add_file("script_macros.hpp", Cursor::new(b"#define TEST 1".to_vec()))
+        .unwrap();
+    pbo.add_file("data\\Logo_CA.paa", Cursor::new(vec![0; 16]))
+        .unwrap();
+    pbo.write(
+        &mut fs_err::File::create(addons.join("test_main.pbo")).unwrap(),
+        true,
+    )
+    .unwrap();
+
+    let workspace = Workspace::builder()
+        .physical(&std::path::PathBuf::from(ROOT), LayerType::Source)
+        .pbos(&folder.join("@test"))
+        .unwrap()
+        .memory()
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+
+    let macros = workspace
+        .join("z/test/addons/main/script_macros.hpp")
+        .unwrap();
+    assert!(macros.exists().unwrap());
+    assert!(macros.is_include());
+    assert_eq!(macros.read_to_string().unwrap(), "#define TEST 1");
+
+    // Arma paths are case-insensitive
+    let located = workspace
+        .locate("\\Z\\Test\\addons\\main\\data\\logo_ca.paa")
+        .unwrap()
+        .unwrap();
+    assert_eq!(located.path.metadata().unwrap().len, 16);
+
+    let listed = workspace
+        .join("z/test/addons/main")
+        .unwrap()
+        .read_dir()
+        .unwrap()
+        .iter()
+        .map(hemtt_workspace::WorkspacePath::filename)
+        .collect::<Vec<_>>();
+    assert!(listed.contains(&"script_macros.hpp".to_string()));
+    assert!(listed.contains(&"data".to_string()));
+
+    assert!(
+        workspace
+            .join("z/test/addons/main/missing.hpp")
+            .unwrap()
+            .exists()
+            .is_ok_and(|e| !e)
+    );
+
+    fs_err::remove_dir_all(folder).unwrap();
+}
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{AudioFile, LintData};
+
+crate::analyze::lint!(LintA01SampleRate);
+
+/// Sample rates allowed when `rates` is not set
+const DEFAULT_RATES: [u32; 2] = [44_100, 48_000];
+
+impl Lint<LintData> for LintA01SampleRate {
+    fn ident(&self) -> &'static str {
+        "sample_rate"
+    }
+
+    fn sort(&self) -> u32 {
+        10
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on audio with an unusual sample rate"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Configuration
+
+- **rates**: The sample rates allowed, in Hz, default is `[44100, 48000]`.
+
+```toml
+[lints.audio.sample_rate]
+options.rates = [44100]
+```
+
+### Explanation
+
+Arma 3 mixes audio at 44.1 kHz or 48 kHz. Audio at other rates is resampled when it is played, which costs performance and can reduce quality. Lower rates also sound muffled."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = AudioFile;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &AudioFile,
+        _data: &LintData,
+    ) -> Codes {
+        let rates = if let Some(toml::Value::Array(rates)) = config.option("rates") {
+            rates
+                .iter()
+                .filter_map(toml::Value::as_integer)
+                .collect::<Vec<_>>()
+        } else {
+            DEFAULT_RATES.iter().map(|rate| i64::from(*rate)).collect()
+        };
+        let rate = target.wss().sample_rate();
+        if rates.contains(&i64::from(rate)) {
+            return vec![];
+        }
+        vec![Arc::new(CodeAudioSampleRate::new(
+            target.path().to_string(),
+            rate,
+            rates,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeAudioSampleRate {
+    path: String,
+    rate: u32,
+    allowed: Vec<i64>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeAudioSampleRate {
+    fn ident(&self) -> &'static str {
+        "L-A01"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/audio.html#sample_rate")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Audio `{}` has a sample rate of {} Hz",
+            self.path, self.rate
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "resample the audio to {} Hz",
+            self.allowed
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" Hz or ")
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeAudioSampleRate {
+    #[must_use]
+    pub fn new(path: String, rate: u32, allowed: Vec<i64>, severity: Severity) -> Self {
+        Self {
+            path,
+            rate,
+            allowed,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,155 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{AudioFile, LintData, SoundReference};
+
+crate::analyze::lint!(LintA02Stereo3d);
+
+impl Lint<LintData> for LintA02Stereo3d {
+    fn ident(&self) -> &'static str {
+        "stereo_3d"
+    }
+
+    fn sort(&self) -> u32 {
+        20
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on stereo audio used as 3D sounds"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"### Example
+
+**Incorrect**, when `engine.wss` has two channels
+```hpp
+class CfgSFX {
+    class my_engine {
+        sounds[] = {"engine"};
+        engine[] = {"\x\mod\addons\main\engine.wss", 1, 1, 100, 1, 0, 0, 0};
+        empty[] = {"", 0, 0, 0, 0, 0, 0, 0};
+    };
+};
+```
+
+### Explanation
+
+Sounds in `CfgSFX`, and samples of `CfgSoundShaders` used by a sound set with `spatial = 1`, are played at a position in the world. Only mono audio can be positioned, stereo audio is heard the same from every direction. Convert the audio to mono."#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = SoundReference;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &SoundReference,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        if !target.spatial() || !data.in_prefixes(target.path()) {
+            return vec![];
+        }
+        let Some(Ok(Some(file))) = data.locate(target.path()).map(|path| AudioFile::read(&path))
+        else {
+            return vec![];
+        };
+        let channels = file.wss().channels();
+        if channels <= 1 {
+            return vec![];
+        }
+        vec![Arc::new(CodeAudioStereo3d::new(
+            target.span().clone(),
+            target.usage().config_name(),
+            channels,
+            processed,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeAudioStereo3d {
+    span: Range<usize>,
+    config: &'static str,
+    channels: u16,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeAudioStereo3d {
+    fn ident(&self) -> &'static str {
+        "L-A02"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/audio.html#stereo_3d")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "audio with {} channels is used as a 3D sound in `{}`",
+            self.channels, self.config
+        )
+    }
+
+    fn label_message(&self) -> String {
+        "not mono".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("convert the audio to mono, stereo sounds can not be positioned".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeAudioStereo3d {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        config: &'static str,
+        channels: u16,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            config,
+            channels,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
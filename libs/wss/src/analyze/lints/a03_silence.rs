@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{AudioFile, LintData};
+
+crate::analyze::lint!(LintA03Silence);
+
+/// Silence allowed at the start or end when `max` is not set, in milliseconds
+const DEFAULT_MAX: i64 = 500;
+
+/// Samples at or below this level are silent, about -60 dBFS
+const SILENCE_LEVEL: u16 = 32;
+
+impl Lint<LintData> for LintA03Silence {
+    fn ident(&self) -> &'static str {
+        "silence"
+    }
+
+    fn sort(&self) -> u32 {
+        30
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on audio that starts or ends with silence"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Configuration
+
+- **max**: The silence allowed at the start or the end, in milliseconds, default is `500`.
+
+```toml
+[lints.audio.silence]
+options.max = 200
+```
+
+### Explanation
+
+Silence at the start of a sound delays it from the action that plays it, such as a shot or a door opening. Silence at the end takes up space in the PBO and keeps a sound source busy after it can no longer be heard. Trim the silence from the audio."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = AudioFile;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &AudioFile,
+        _data: &LintData,
+    ) -> Codes {
+        let max = if let Some(toml::Value::Integer(max)) = config.option("max") {
+            u64::try_from(*max).unwrap_or_default()
+        } else {
+            DEFAULT_MAX.unsigned_abs()
+        };
+        let (start, end) = target.silence(SILENCE_LEVEL);
+        let (start, end) = (target.millis(start), target.millis(end));
+        if start <= max && end <= max {
+            return vec![];
+        }
+        vec![Arc::new(CodeAudioSilence::new(
+            target.path().to_string(),
+            (start, end),
+            max,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeAudioSilence {
+    path: String,
+    /// Silence at the start and the end, in milliseconds
+    silence: (u64, u64),
+    max: u64,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeAudioSilence {
+    fn ident(&self) -> &'static str {
+        "L-A03"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/audio.html#silence")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        let (start, end) = self.silence;
+        match (start > self.max, end > self.max) {
+            (true, true) => format!(
+                "Audio `{}` starts with {start} ms and ends with {end} ms of silence",
+                self.path
+            ),
+            (true, false) => format!("Audio `{}` starts with {start} ms of silence", self.path),
+            _ => format!("Audio `{}` ends with {end} ms of silence", self.path),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "trim the silence to at most {} ms, or raise `max` if it is needed",
+            self.max
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeAudioSilence {
+    #[must_use]
+    pub fn new(path: String, silence: (u64, u64), max: u64, severity: Severity) -> Self {
+        Self {
+            path,
+            silence,
+            max,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{AudioFile, LintData};
+
+crate::analyze::lint!(LintA04Clipping);
+
+/// Consecutive samples at full scale that count as clipping when `samples` is not set
+const DEFAULT_SAMPLES: i64 = 3;
+
+impl Lint<LintData> for LintA04Clipping {
+    fn ident(&self) -> &'static str {
+        "clipping"
+    }
+
+    fn sort(&self) -> u32 {
+        40
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on audio that clips"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Configuration
+
+- **samples**: The consecutive samples at full scale that count as clipping, default is `3`.
+
+```toml
+[lints.audio.clipping]
+options.samples = 5
+```
+
+### Explanation
+
+Audio clips when it is louder than the largest sample value, and the peaks of the waveform are cut off. Clipping is heard as distortion, and the game can not undo it. Lower the volume of the source before exporting it, and raise the volume in the config instead."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = AudioFile;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &AudioFile,
+        _data: &LintData,
+    ) -> Codes {
+        let samples = if let Some(toml::Value::Integer(samples)) = config.option("samples") {
+            *samples
+        } else {
+            DEFAULT_SAMPLES
+        };
+        let runs = target.clipping(usize::try_from(samples.max(1)).unwrap_or(usize::MAX));
+        if runs == 0 {
+            return vec![];
+        }
+        vec![Arc::new(CodeAudioClipping::new(
+            target.path().to_string(),
+            runs,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeAudioClipping {
+    path: String,
+    runs: usize,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeAudioClipping {
+    fn ident(&self) -> &'static str {
+        "L-A04"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/audio.html#clipping")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Audio `{}` clips {} time{}",
+            self.path,
+            self.runs,
+            if self.runs == 1 { "" } else { "s" }
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("lower the volume of the source audio and export it again".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeAudioClipping {
+    #[must_use]
+    pub fn new(path: String, runs: usize, severity: Severity) -> Self {
+        Self {
+            path,
+            runs,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{AudioFile, LintData};
+
+crate::analyze::lint!(LintA05WssSize);
+
+/// The largest uncompressed WSS allowed when `max` is not set, in kilobytes
+const DEFAULT_MAX: i64 = 1024;
+
+impl Lint<LintData> for LintA05WssSize {
+    fn ident(&self) -> &'static str {
+        "wss_size"
+    }
+
+    fn sort(&self) -> u32 {
+        50
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on uncompressed WSS files that are larger than a maximum size"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Configuration
+
+- **max**: The largest size allowed for an uncompressed WSS, in kilobytes, default is `1024`.
+
+```toml
+[lints.audio.wss_size]
+options.max = 512
+```
+
+### Explanation
+
+Uncompressed WSS files are as large as WAV files. Byte compression makes them half the size, with little loss in quality, and longer sounds such as music are much smaller as OGG. `hemtt utils audio compress` compresses the WSS files of a project, and `hemtt utils audio convert` converts audio to OGG."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = AudioFile;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &AudioFile,
+        _data: &LintData,
+    ) -> Codes {
+        if !target.is_uncompressed_wss() {
+            return vec![];
+        }
+        let max = if let Some(toml::Value::Integer(max)) = config.option("max") {
+            u64::try_from(*max).unwrap_or_default()
+        } else {
+            DEFAULT_MAX.unsigned_abs()
+        };
+        if target.size() <= max * 1024 {
+            return vec![];
+        }
+        vec![Arc::new(CodeAudioWssSize::new(
+            target.path().to_string(),
+            target.size(),
+            max,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeAudioWssSize {
+    path: String,
+    size: u64,
+    max: u64,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeAudioWssSize {
+    fn ident(&self) -> &'static str {
+        "L-A05"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/audio.html#wss_size")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Uncompressed WSS `{}` is {} KB, larger than {} KB",
+            self.path,
+            self.size / 1024,
+            self.max
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(
+            "compress it with `hemtt utils audio compress`, or convert it to OGG with `hemtt utils audio convert`"
+                .to_string(),
+        )
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeAudioWssSize {
+    #[must_use]
+    pub fn new(path: String, size: u64, max: u64, severity: Severity) -> Self {
+        Self {
+            path,
+            size,
+            max,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
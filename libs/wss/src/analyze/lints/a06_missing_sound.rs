@@ -0,0 +1,223 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig, RuntimeArguments};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{LintData, SUPPORTED_EXTENSIONS, SoundReference, SoundUsage};
+
+crate::analyze::lint!(LintA06MissingSound);
+
+impl Lint<LintData> for LintA06MissingSound {
+    fn ident(&self) -> &'static str {
+        "missing_sound"
+    }
+
+    fn sort(&self) -> u32 {
+        60
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on sounds in `CfgSounds`, `CfgMusic` and `CfgRadio` that do not exist"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"### Example
+
+**Incorrect**, when only `beep.ogg` exists
+```hpp
+class CfgSounds {
+    class my_beep {
+        sound[] = {"\x\mod\addons\main\sounds\beep.wss", 1, 1};
+    };
+};
+```
+
+**Correct**
+```hpp
+class CfgSounds {
+    class my_beep {
+        sound[] = {"\x\mod\addons\main\sounds\beep.ogg", 1, 1};
+    };
+};
+```
+
+### Explanation
+
+The game only reports a missing sound when it is played. Paths without an extension are played as `.wss`. Only `.wss`, `.ogg` and `.wav` files can be played, other formats must be converted. Only paths inside the project are checked."#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = SoundReference;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        _runtime: &RuntimeArguments,
+        target: &SoundReference,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        if !matches!(
+            target.usage(),
+            SoundUsage::Sounds | SoundUsage::Music | SoundUsage::Radio
+        ) || data.workspace().is_none()
+            || !data.in_prefixes(target.path())
+        {
+            return vec![];
+        }
+        let path = target.path();
+        let filename = path.rsplit(['\\', '/']).next().unwrap_or_default();
+        let (stem, extension) = filename.rsplit_once('.').map_or_else(
+            || (path, None),
+            |(_, extension)| {
+                (
+                    &path[..path.len() - extension.len() - 1],
+                    Some(extension.to_lowercase()),
+                )
+            },
+        );
+        let problem = match extension {
+            Some(extension) if !SUPPORTED_EXTENSIONS.contains(&extension.as_str()) => {
+                Problem::Unsupported(extension)
+            }
+            extension => {
+                let expected = extension.unwrap_or_else(|| String::from("wss"));
+                if data.locate(&format!("{stem}.{expected}")).is_some() {
+                    return vec![];
+                }
+                SUPPORTED_EXTENSIONS
+                    .iter()
+                    .find(|other| data.locate(&format!("{stem}.{other}")).is_some())
+                    .map_or(Problem::Missing, |other| {
+                        Problem::Extension((*other).to_string())
+                    })
+            }
+        };
+        vec![Arc::new(CodeAudioMissingSound::new(
+            target.span().clone(),
+            target.usage().config_name(),
+            target.class().to_string(),
+            problem,
+            processed,
+            config.severity(),
+        ))]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// No file exists with a supported extension
+    Missing,
+    /// The file exists with this extension instead
+    Extension(String),
+    /// The extension is not a format the game can play
+    Unsupported(String),
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeAudioMissingSound {
+    span: Range<usize>,
+    config: &'static str,
+    class: String,
+    problem: Problem,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeAudioMissingSound {
+    fn ident(&self) -> &'static str {
+        "L-A06"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/lints/audio.html#missing_sound")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        match &self.problem {
+            Problem::Missing => format!(
+                "sound of `{}` in `{}` does not exist",
+                self.class, self.config
+            ),
+            Problem::Extension(_) => format!(
+                "sound of `{}` in `{}` has the wrong extension",
+                self.class, self.config
+            ),
+            Problem::Unsupported(extension) => format!(
+                "sound of `{}` in `{}` is a `.{extension}`, which can not be played",
+                self.class, self.config
+            ),
+        }
+    }
+
+    fn label_message(&self) -> String {
+        match &self.problem {
+            Problem::Missing => "file not found".to_string(),
+            Problem::Extension(_) => "wrong extension".to_string(),
+            Problem::Unsupported(_) => "unsupported format".to_string(),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        match &self.problem {
+            Problem::Missing => None,
+            Problem::Extension(extension) => Some(format!(
+                "the file exists as `.{extension}`, use that extension in the path"
+            )),
+            Problem::Unsupported(_) => Some(
+                "convert the audio to `.ogg` with `hemtt utils audio convert`".to_string(),
+            ),
+        }
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeAudioMissingSound {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        config: &'static str,
+        class: String,
+        problem: Problem,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            config,
+            class,
+            problem,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
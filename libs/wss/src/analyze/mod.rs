@@ -0,0 +1,445 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::{Cursor, Read},
+    ops::Range,
+};
+
+use hemtt_common::config::{ProjectConfig, RuntimeArguments};
+use hemtt_config::{Class, Config, Ident, Item, Property, Value};
+use hemtt_workspace::{
+    WorkspacePath,
+    lint::LintManager,
+    lint_manager,
+    reporting::{Codes, Processed},
+};
+
+use crate::{Compression, Error, Wss};
+
+pub mod lints {
+    automod::dir!(pub "src/analyze/lints");
+}
+
+lint_manager!(audio, vec![]);
+
+/// Extensions of the audio formats Arma 3 can play
+pub const SUPPORTED_EXTENSIONS: [&str; 3] = ["wss", "ogg", "wav"];
+
+/// Deepest class inheritance followed before giving up
+const MAX_DEPTH: usize = 64;
+
+pub struct LintData {
+    workspace: Option<WorkspacePath>,
+    prefixes: Vec<String>,
+}
+
+impl LintData {
+    #[must_use]
+    /// Sounds named by configs are located in the workspace, they are not checked without one
+    ///
+    /// Prefixes are the project and addon prefixes, such as `z\ace` and `z\ace\addons\main`
+    pub fn new(workspace: Option<WorkspacePath>, prefixes: &[String]) -> Self {
+        Self {
+            workspace,
+            prefixes: prefixes.iter().map(|p| normalize_path(p)).collect(),
+        }
+    }
+
+    #[must_use]
+    pub const fn workspace(&self) -> Option<&WorkspacePath> {
+        self.workspace.as_ref()
+    }
+
+    #[must_use]
+    /// Is the path inside one of the prefixes
+    pub fn in_prefixes(&self, path: &str) -> bool {
+        let path = normalize_path(path);
+        self.prefixes
+            .iter()
+            .any(|prefix| path.starts_with(&format!("{prefix}\\")))
+    }
+
+    #[must_use]
+    /// Find a file by the path a config uses for it
+    pub fn locate(&self, path: &str) -> Option<WorkspacePath> {
+        self.workspace
+            .as_ref()?
+            .locate(&format!("\\{}", normalize_path(path)))
+            .ok()
+            .flatten()
+            .map(|located| located.path)
+    }
+}
+
+/// An audio file to be linted, with the path it was read from
+pub struct AudioFile {
+    path: String,
+    wss: Wss,
+    size: u64,
+}
+
+impl AudioFile {
+    #[must_use]
+    /// `size` is the size of the file on disk, in bytes
+    pub const fn new(path: String, wss: Wss, size: u64) -> Self {
+        Self { path, wss, size }
+    }
+
+    /// Read a WSS, WAV or OGG from the workspace, by its extension
+    ///
+    /// Returns `None` for other files
+    ///
+    /// # Errors
+    /// [`Error`] if the file can not be read or decoded
+    pub fn read(path: &WorkspacePath) -> Result<Option<Self>, Error> {
+        let Some(extension) = path.extension().map(|ext| ext.to_lowercase()) else {
+            return Ok(None);
+        };
+        if !SUPPORTED_EXTENSIONS.contains(&extension.as_str()) {
+            return Ok(None);
+        }
+        let mut buffer = Vec::new();
+        path.open_file()?.read_to_end(&mut buffer)?;
+        let size = buffer.len() as u64;
+        let wss = match extension.as_str() {
+            "wss" => Wss::read(Cursor::new(buffer))?,
+            "wav" => Wss::from_wav(Cursor::new(buffer))?,
+            _ => Wss::from_ogg(Cursor::new(buffer))?,
+        };
+        Ok(Some(Self::new(path.as_str().to_string(), wss, size)))
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn wss(&self) -> &Wss {
+        &self.wss
+    }
+
+    #[must_use]
+    /// The size of the file on disk, in bytes
+    pub const fn size(&self) -> u64 {
+        self.size
+    }
+
+    #[must_use]
+    /// Is the file an uncompressed WSS
+    pub fn is_uncompressed_wss(&self) -> bool {
+        std::path::Path::new(&self.path)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("wss"))
+            && self.wss.compression() == &Compression::None
+    }
+
+    #[must_use]
+    /// The length of a number of samples in each channel, in milliseconds
+    pub fn millis(&self, samples: usize) -> u64 {
+        if self.wss.sample_rate() == 0 {
+            return 0;
+        }
+        samples as u64 * 1000 / u64::from(self.wss.sample_rate())
+    }
+
+    #[must_use]
+    /// Samples in each channel at the start and the end where every channel is at or below `level`
+    pub fn silence(&self, level: u16) -> (usize, usize) {
+        let channels = self.wss.channel_data();
+        let frames = channels.iter().map(Vec::len).min().unwrap_or_default();
+        let silent = |frame: usize| {
+            channels
+                .iter()
+                .all(|channel| channel[frame].unsigned_abs() <= level)
+        };
+        let start = (0..frames).take_while(|frame| silent(*frame)).count();
+        if start == frames {
+            return (frames, 0);
+        }
+        let end = (0..frames).rev().take_while(|frame| silent(*frame)).count();
+        (start, end)
+    }
+
+    #[must_use]
+    /// Runs of at least `length` consecutive samples at full scale, in any channel
+    pub fn clipping(&self, length: usize) -> usize {
+        let mut runs = 0;
+        for channel in self.wss.channel_data() {
+            let mut run = 0;
+            for sample in channel {
+                if sample.unsigned_abs() >= i16::MAX.unsigned_abs() {
+                    run += 1;
+                    if run == length {
+                        runs += 1;
+                    }
+                } else {
+                    run = 0;
+                }
+            }
+        }
+        runs
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The config class a sound is named in
+pub enum SoundUsage {
+    /// `CfgSounds`, played with `playSound` and `say`
+    Sounds,
+    /// `CfgMusic`, played with `playMusic`
+    Music,
+    /// `CfgRadio`, played with `sideRadio` and similar commands
+    Radio,
+    /// `CfgSFX`, sounds of objects in the world
+    Sfx,
+    /// `CfgSoundShaders`, samples of sound sets
+    SoundShaders,
+}
+
+impl SoundUsage {
+    #[must_use]
+    pub const fn config_name(&self) -> &'static str {
+        match self {
+            Self::Sounds => "CfgSounds",
+            Self::Music => "CfgMusic",
+            Self::Radio => "CfgRadio",
+            Self::Sfx => "CfgSFX",
+            Self::SoundShaders => "CfgSoundShaders",
+        }
+    }
+
+    fn from_config_name(name: &str) -> Option<Self> {
+        [
+            Self::Sounds,
+            Self::Music,
+            Self::Radio,
+            Self::Sfx,
+            Self::SoundShaders,
+        ]
+        .into_iter()
+        .find(|usage| usage.config_name().eq_ignore_ascii_case(name))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A sound file named by a config
+pub struct SoundReference {
+    usage: SoundUsage,
+    class: String,
+    path: String,
+    span: Range<usize>,
+    spatial: bool,
+}
+
+impl SoundReference {
+    #[must_use]
+    pub const fn usage(&self) -> SoundUsage {
+        self.usage
+    }
+
+    #[must_use]
+    /// The class the sound is named in
+    pub fn class(&self) -> &str {
+        &self.class
+    }
+
+    #[must_use]
+    /// The path as written in the config
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn span(&self) -> &Range<usize> {
+        &self.span
+    }
+
+    #[must_use]
+    /// Is the sound played at a position in the world
+    pub const fn spatial(&self) -> bool {
+        self.spatial
+    }
+}
+
+#[must_use]
+/// The sounds named in `CfgSounds`, `CfgMusic`, `CfgRadio`, `CfgSFX` and `CfgSoundShaders`
+///
+/// Samples of sound shaders are spatial when a sound set with `spatial = 1` uses the shader
+pub fn sound_references(config: &Config) -> Vec<SoundReference> {
+    let spatial_shaders = spatial_shaders(config);
+    let mut references = Vec::new();
+    for (name, _, properties) in classes(&config.0) {
+        let Some(usage) = SoundUsage::from_config_name(name) else {
+            continue;
+        };
+        for (class, _, properties) in classes(properties) {
+            let mut push = |item: &Item, spatial: bool| {
+                if let Item::Str(path) = item
+                    && !path.value().trim().is_empty()
+                {
+                    references.push(SoundReference {
+                        usage,
+                        class: class.to_string(),
+                        path: path.value().to_string(),
+                        span: path.span().clone(),
+                        spatial,
+                    });
+                }
+            };
+            for (key, items) in arrays(properties) {
+                match usage {
+                    SoundUsage::Sounds | SoundUsage::Music | SoundUsage::Radio
+                        if key.eq_ignore_ascii_case("sound") =>
+                    {
+                        if let Some(item) = items.first() {
+                            push(item, false);
+                        }
+                    }
+                    // `sounds[]` lists the names of the other arrays
+                    SoundUsage::Sfx if !key.eq_ignore_ascii_case("sounds") => {
+                        if let Some(item) = items.first() {
+                            push(item, true);
+                        }
+                    }
+                    SoundUsage::SoundShaders if key.eq_ignore_ascii_case("samples") => {
+                        let spatial = spatial_shaders.contains(&class.to_lowercase());
+                        for item in items {
+                            if let Item::Array(sample) = item
+                                && let Some(item) = sample.first()
+                            {
+                                push(item, spatial);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    references
+}
+
+/// Shaders used by sound sets with `spatial = 1`, in lowercase
+fn spatial_shaders(config: &Config) -> HashSet<String> {
+    let sets = classes(&config.0)
+        .filter(|(name, _, _)| name.eq_ignore_ascii_case("CfgSoundSets"))
+        .flat_map(|(_, _, properties)| classes(properties))
+        .map(|(name, parent, properties)| (name.to_lowercase(), (parent, properties)))
+        .collect::<HashMap<_, _>>();
+    // a property of a sound set, or the nearest parent class that sets it
+    let property = |name: &str, key: &str| {
+        let mut current = sets.get(name)?;
+        for _ in 0..MAX_DEPTH {
+            if let Some(value) = entry(current.1, key) {
+                return Some(value);
+            }
+            current = sets.get(&current.0?.to_lowercase())?;
+        }
+        None
+    };
+    let mut shaders = HashSet::new();
+    for name in sets.keys() {
+        let spatial = match property(name, "spatial") {
+            Some(Value::Number(number)) => number.to_string() != "0",
+            _ => false,
+        };
+        if !spatial {
+            continue;
+        }
+        if let Some(Value::Array(array) | Value::UnexpectedArray(array)) =
+            property(name, "soundShaders")
+        {
+            shaders.extend(array.items().iter().filter_map(|item| match item {
+                Item::Str(shader) => Some(shader.value().to_lowercase()),
+                _ => None,
+            }));
+        }
+    }
+    shaders
+}
+
+/// The classes declared in a class, with their parent and properties
+fn classes(properties: &[Property]) -> impl Iterator<Item = (&str, Option<&str>, &[Property])> {
+    properties.iter().filter_map(|property| match property {
+        Property::Class(Class::Local {
+            name,
+            parent,
+            properties,
+            ..
+        }) => Some((
+            name.as_str(),
+            parent.as_ref().map(Ident::as_str),
+            properties.as_slice(),
+        )),
+        _ => None,
+    })
+}
+
+/// The array properties of a class, with their items
+fn arrays(properties: &[Property]) -> impl Iterator<Item = (&str, &[Item])> {
+    properties.iter().filter_map(|property| match property {
+        Property::Entry {
+            name,
+            value: Value::Array(array) | Value::UnexpectedArray(array),
+            ..
+        } => Some((name.as_str(), array.items())),
+        _ => None,
+    })
+}
+
+fn entry<'a>(properties: &'a [Property], key: &str) -> Option<&'a Value> {
+    properties.iter().rev().find_map(|property| match property {
+        Property::Entry { name, value, .. } if name.as_str().eq_ignore_ascii_case(key) => {
+            Some(value)
+        }
+        _ => None,
+    })
+}
+
+/// Lowercase with backslashes and no leading separator or `@`
+fn normalize_path(path: &str) -> String {
+    path.replace('/', "\\")
+        .trim_start_matches('@')
+        .trim_start_matches('\\')
+        .trim_end_matches('\\')
+        .to_lowercase()
+}
+
+#[must_use]
+pub fn lint_file(
+    file: &AudioFile,
+    project_config: Option<&ProjectConfig>,
+    data: &LintData,
+) -> Codes {
+    manager(project_config).map_or_else(
+        |e| e,
+        |manager| manager.run(data, project_config, None, file),
+    )
+}
+
+#[must_use]
+pub fn lint_reference(
+    reference: &SoundReference,
+    project_config: Option<&ProjectConfig>,
+    processed: &Processed,
+    data: &LintData,
+) -> Codes {
+    manager(project_config).map_or_else(
+        |e| e,
+        |manager| manager.run(data, project_config, Some(processed), reference),
+    )
+}
+
+fn manager(project_config: Option<&ProjectConfig>) -> Result<LintManager<LintData>, Codes> {
+    let mut manager = LintManager::new(
+        project_config.map_or_else(Default::default, |project| project.lints().audio().clone()),
+        project_config.map_or_else(RuntimeArguments::default, |p| p.runtime().clone()),
+    );
+    manager.extend(
+        AUDIO_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(manager)
+}
@@ -21,4 +21,9 @@ pub enum Error {
     #[error("MP3 Error: {0}")]
     /// Error while reading an MP3 file
     Mp3(#[from] puremp3::Error),
+
+    #[cfg(feature = "lints")]
+    #[error("Workspace Error: {0}")]
+    /// Error while reading a file from the workspace
+    Workspace(#[from] hemtt_workspace::Error),
 }
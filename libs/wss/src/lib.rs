@@ -2,6 +2,8 @@ use std::io::{BufReader, Read, Write};
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
+#[cfg(feature = "lints")]
+pub mod analyze;
 mod compression;
 mod error;
 mod mp3;
@@ -135,4 +137,10 @@ impl Wss {
     pub fn size(&self) -> usize {
         self.channel_data.iter().map(std::vec::Vec::len).sum()
     }
+
+    #[must_use]
+    /// The decoded samples of each channel
+    pub fn channel_data(&self) -> &[Vec<i16>] {
+        &self.channel_data
+    }
 }
@@ -0,0 +1,91 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_common::config::{PDriveOption, PreprocessorOptions, ProjectConfig};
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::{LayerType, WorkspacePath, reporting::WorkspaceFiles};
+use hemtt_wss::analyze::{
+    AudioFile, LintData, lint_file, lint_reference, sound_references,
+};
+
+const ROOT: &str = "tests/lints/";
+
+macro_rules! lint {
+    ($dir:ident, $lint:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<audio_ $dir>]() {
+                insta::assert_snapshot!($lint(stringify!($dir)));
+            }
+        }
+    };
+}
+
+lint!(a01_sample_rate, file);
+lint!(a02_stereo_3d, config);
+lint!(a03_silence, file);
+lint!(a04_clipping, file);
+lint!(a05_wss_size, file);
+lint!(a06_missing_sound, config);
+
+fn workspace() -> WorkspacePath {
+    hemtt_workspace::Workspace::builder()
+        .physical(&std::path::PathBuf::from(ROOT), LayerType::Source)
+        .finish(None, true, &PDriveOption::Disallow)
+        .unwrap()
+}
+
+fn project() -> ProjectConfig {
+    ProjectConfig::from_file(&std::path::PathBuf::from(ROOT).join("project_tests.toml")).unwrap()
+}
+
+/// Lints an audio file named after the lint
+fn file(name: &str) -> String {
+    let workspace = workspace();
+    let config = project();
+    let data = LintData::new(Some(workspace.clone()), &[config.prefix().to_string()]);
+    let path = ["wav", "wss"]
+        .into_iter()
+        .map(|ext| workspace.join(format!("addons/main/{name}.{ext}")).unwrap())
+        .find(|path| path.exists().unwrap())
+        .unwrap();
+    let file = AudioFile::read(&path).unwrap().unwrap();
+    let codes = lint_file(&file, Some(&config), &data);
+    render(name, &codes, &WorkspaceFiles::new())
+}
+
+/// Lints the sounds of `config.cpp`
+fn config(name: &str) -> String {
+    let workspace = workspace();
+    let config = project();
+    let data = LintData::new(Some(workspace.clone()), &[config.prefix().to_string()]);
+    let processed = Processor::run(
+        &workspace.join("addons/main/config.cpp").unwrap(),
+        &PreprocessorOptions::default(),
+    )
+    .unwrap();
+    let report = hemtt_config::parse(None, &processed).unwrap();
+    let codes = sound_references(report.config())
+        .iter()
+        .flat_map(|reference| lint_reference(reference, Some(&config), &processed, &data))
+        .collect::<Vec<_>>();
+    render(name, &codes, &WorkspaceFiles::new())
+}
+
+fn render(
+    name: &str,
+    codes: &[std::sync::Arc<dyn hemtt_workspace::reporting::Code>],
+    workspace_files: &WorkspaceFiles,
+) -> String {
+    codes
+        .iter()
+        .filter(|e| {
+            e.ident().starts_with(&format!(
+                "L-{}",
+                name.split_once('_').unwrap().0.to_uppercase()
+            ))
+        })
+        .map(|e| e.diagnostic().unwrap().to_string(workspace_files))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace('\r', "")
+}
@@ -0,0 +1,55 @@
+class CfgSFX {
+    class abe_stereo {
+        sounds[] = {"hum"};
+        hum[] = {"\abe\addons\main\stereo.wav", 1, 1, 100, 1, 0, 0, 0};
+        empty[] = {"", 0, 0, 0, 0, 0, 0, 0};
+    };
+    class abe_mono {
+        sounds[] = {"hum"};
+        hum[] = {"\abe\addons\main\mono.wav", 1, 1, 100, 1, 0, 0, 0};
+    };
+};
+class CfgSoundShaders {
+    class abe_shader_3d {
+        samples[] = {{"\abe\addons\main\stereo.wav", 1}};
+    };
+    class abe_shader_2d {
+        samples[] = {{"\abe\addons\main\stereo.wav", 1}};
+    };
+};
+class CfgSoundSets {
+    class abe_base {
+        spatial = 1;
+    };
+    class abe_set_3d: abe_base {
+        soundShaders[] = {"abe_shader_3d"};
+    };
+    class abe_set_2d {
+        soundShaders[] = {"abe_shader_2d"};
+        spatial = 0;
+    };
+};
+class CfgSounds {
+    class abe_beep {
+        sound[] = {"\abe\addons\main\beep.wss", 1, 1};
+    };
+    class abe_beep_no_extension {
+        sound[] = {"\abe\addons\main\beep", 1, 1};
+    };
+    class abe_mono {
+        sound[] = {"\abe\addons\main\mono.wav", 1, 1};
+    };
+    class abe_vanilla {
+        sound[] = {"\a3\sounds_f\missing.wss", 1, 1};
+    };
+};
+class CfgMusic {
+    class abe_song {
+        sound[] = {"\abe\addons\main\song.ogg", 1};
+    };
+};
+class CfgRadio {
+    class abe_message {
+        sound[] = {"@abe\addons\main\message.mp3", 1, 1};
+    };
+};